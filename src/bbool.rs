@@ -1,8 +1,12 @@
 use crate::error::BBoolError;
-use crate::traits::{BitwiseOpsClone, BitwiseOpsCopy, Nums};
+#[cfg(feature = "inf_bools")]
+use crate::inf_bbool::BetterBoolInf;
+use crate::traits::{BitwiseOpsClone, BitwiseOpsCopy, BoolCollection, Nums};
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::marker::PhantomData;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr};
 
 /// Type alias for a 128-bit `BetterBool`
 pub type B128 = BetterBool<u128>;
@@ -46,6 +50,40 @@ impl<T: Nums> BetterBool<T> {
     // Able to allow because size_of::<T> will never exceed 16 with built in types, and thus 128 after the times 8, half the limit of u8.
     #[allow(clippy::cast_possible_truncation)]
     pub const CAP: u8 = (size_of::<T>() * 8) as u8;
+
+    /// Returns whether `n` bools fit within the capacity of this `BetterBool`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// assert!(B8::fits(8));
+    /// assert!(!B8::fits(9));
+    /// ```
+    #[must_use]
+    pub const fn fits(n: usize) -> bool {
+        n <= Self::CAP as usize
+    }
+
+    /// Asserts that `N` bools fit within the capacity of this `BetterBool`.
+    ///
+    /// When evaluated in a `const` item, a capacity that is too small fails the build instead of erroring at runtime.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B16;
+    /// const _: () = B16::assert_capacity::<12>();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use btypes::bbool::B8;
+    /// const _: () = B8::assert_capacity::<12>();
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `N` exceeds the capacity (at compile time when used in a `const` item).
+    pub const fn assert_capacity<const N: usize>() {
+        assert!(Self::fits(N), "BetterBool capacity is too small");
+    }
 }
 
 impl<T: BitwiseOpsCopy> BetterBool<T> {
@@ -90,7 +128,7 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
     /// use btypes::bbool::B8;
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
-    /// let mut bools = B8::from_num(5);
+    /// let bools = B8::from_num(5);
     /// let all_bools = bools.all()?;
     /// Ok(())
     /// }
@@ -106,6 +144,27 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
         Ok(out)
     }
 
+    /// Folds over every position from 0 to `CAP`, passing each position and its value to `f`.
+    ///
+    /// Unlike `all`, this doesn't allocate, so it suits counting and summing reductions.
+    ///
+    /// # Arguments
+    /// * `init` - The initial accumulator value
+    /// * `f` - Combines the accumulator with a position and its value
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// let bools = B8::from_num(0b0000_1010);
+    /// let weighted = bools.fold_bits(0u32, |acc, pos, value| if value { acc + u32::from(pos) } else { acc });
+    /// assert_eq!(weighted, 4);
+    /// ```
+    pub fn fold_bits<B, F: FnMut(B, u8, bool) -> B>(&self, init: B, mut f: F) -> B {
+        (0..Self::CAP).fold(init, |acc, pos| {
+            f(acc, pos, (self.store & (T::one() << pos)) != T::zero())
+        })
+    }
+
     /// Returns a new `BetterBool`<T> that has been sorted.
     ///
     /// # Examples
@@ -113,7 +172,7 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
     /// use btypes::bbool::B8;
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
-    /// let mut bools = B8::from_num(5);
+    /// let bools = B8::from_num(5);
     /// let sorted = bools.sorted()?;
     /// Ok(())
     /// }
@@ -134,6 +193,37 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
         Ok(sorted)
     }
 
+    /// Sorts the bools in place, moving all `false` values to the low positions and all `true` values to the high positions.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = B8::from_num(0b0000_0101);
+    /// bools.sort()?;
+    /// assert_eq!(*bools.get_raw(), 0b1100_0000);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if accessing or setting any position fails
+    pub fn sort(&mut self) -> Result<(), BBoolError> {
+        let mut set_count = 0;
+        for i in 0..Self::CAP {
+            if self.get_at_pos(i)? {
+                set_count += 1;
+            }
+        }
+
+        self.clear();
+        for i in (Self::CAP - set_count)..Self::CAP {
+            self.set_at_pos(i, true)?;
+        }
+        Ok(())
+    }
+
     /// Returns a Vec of boolean values within the specified range [start, end).
     ///
     /// # Arguments
@@ -159,10 +249,10 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
     /// * accessing any position in range fails
     pub fn range(&self, start: u8, end: u8) -> Result<Vec<bool>, BBoolError> {
         if start >= Self::CAP {
-            return Err(BBoolError::InvalidPos(start));
+            return Err(BBoolError::InvalidPos(start, Self::CAP));
         }
         if end > Self::CAP {
-            return Err(BBoolError::InvalidPos(end));
+            return Err(BBoolError::InvalidPos(end, Self::CAP));
         }
         if end < start {
             return Err(BBoolError::InvalidRange(start as usize, end as usize));
@@ -175,6 +265,150 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
         Ok(result)
     }
 
+    /// Returns the bools at the given positions, in the order listed.
+    ///
+    /// # Arguments
+    /// * `positions` - The positions to read, which may repeat
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = B8::from_num(0b0000_0110);
+    /// assert_eq!(bools.gather(&[2, 0, 1])?, vec![true, false, true]);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if any position is invalid
+    pub fn gather(&self, positions: &[u8]) -> Result<Vec<bool>, BBoolError> {
+        positions.iter().map(|&pos| self.get_at_pos(pos)).collect()
+    }
+
+    /// Writes `values[i]` to `positions[i]` for each `i`, the inverse of `gather`.
+    ///
+    /// Nothing is written unless every position is valid. If a position repeats, its last value wins.
+    ///
+    /// # Arguments
+    /// * `positions` - The positions to write
+    /// * `values` - The values to write, one per position
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = B8::new();
+    /// bools.scatter(&[7, 1], &[true, true])?;
+    /// assert_eq!(*bools.get_raw(), 0b1000_0010);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * `positions` and `values` have different lengths
+    /// * any position is invalid
+    pub fn scatter(&mut self, positions: &[u8], values: &[bool]) -> Result<(), BBoolError> {
+        if positions.len() != values.len() {
+            return Err(BBoolError::Other(format!(
+                "Got {} positions but {} values",
+                positions.len(),
+                values.len()
+            )));
+        }
+        if let Some(&pos) = positions.iter().find(|&&pos| pos >= Self::CAP) {
+            return Err(BBoolError::InvalidPos(pos, Self::CAP));
+        }
+        for (&pos, &value) in positions.iter().zip(values) {
+            self.set_at_pos(pos, value)?;
+        }
+        Ok(())
+    }
+
+    /// Reads the `width`-bit field starting at `start` as an integer, with `start` as its lowest bit.
+    ///
+    /// # Arguments
+    /// * `start` - The position of the field's lowest bit
+    /// * `width` - The number of bits in the field, from 1 to 32
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B16;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = B16::from_num(0b0000_0010_1100_0000);
+    /// assert_eq!(bools.get_field(6, 4)?, 0b1011);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * start position is invalid
+    /// * `width` is 0, over 32, or runs the field past `CAP`
+    pub fn get_field(&self, start: u8, width: u8) -> Result<u32, BBoolError> {
+        if start >= Self::CAP {
+            return Err(BBoolError::InvalidPos(start, Self::CAP));
+        }
+        let end = usize::from(start) + usize::from(width);
+        if width == 0 || width > 32 || end > usize::from(Self::CAP) {
+            return Err(BBoolError::InvalidRange(start as usize, end));
+        }
+
+        let mut value = 0;
+        for offset in 0..width {
+            value |= u32::from(self.get_at_pos(start + offset)?) << offset;
+        }
+        Ok(value)
+    }
+
+    /// Reads the `width`-bit field starting at `start`, as `get_field` does, and converts it to `E`.
+    ///
+    /// Meant for decoding register fields into enums that implement `TryFrom<u32>`.
+    ///
+    /// # Arguments
+    /// * `start` - The position of the field's lowest bit
+    /// * `width` - The number of bits in the field, from 1 to 32
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// use anyhow::Result;
+    /// #[derive(Debug, PartialEq)]
+    /// enum Mode { Off, On }
+    /// impl TryFrom<u32> for Mode {
+    ///     type Error = ();
+    ///     fn try_from(value: u32) -> Result<Self, ()> {
+    ///         match value {
+    ///             0 => Ok(Mode::Off),
+    ///             1 => Ok(Mode::On),
+    ///             _ => Err(()),
+    ///         }
+    ///     }
+    /// }
+    /// fn main() -> Result<()> {
+    /// let bools = B8::from_num(0b0000_0100);
+    /// assert_eq!(bools.decode_field::<Mode>(2, 1)?, Mode::On);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * the field is invalid, as for `get_field`
+    /// * the field's value doesn't convert to `E`
+    pub fn decode_field<E: TryFrom<u32>>(&self, start: u8, width: u8) -> Result<E, BBoolError> {
+        let value = self.get_field(start, width)?;
+        E::try_from(value).map_err(|_| {
+            BBoolError::Other(format!(
+                "Field value {value} at position {start} doesn't map to a valid variant"
+            ))
+        })
+    }
+
     /// Gets the bool at the current head position. (doesn't clone self.store)
     ///
     /// # Examples
@@ -195,7 +429,7 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
             let mask = T::one() << self.reader_head_pos;
             return Ok((self.store & mask) != T::zero());
         }
-        Err(BBoolError::InvalidHeadPos(self.reader_head_pos))
+        Err(BBoolError::InvalidHeadPos(self.reader_head_pos, Self::CAP))
     }
 
     /// Gets the bool at the given position. (doesn't clone self.store)
@@ -222,7 +456,7 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
             return Ok((self.store & mask) != T::zero());
         }
 
-        Err(BBoolError::InvalidPos(pos))
+        Err(BBoolError::InvalidPos(pos, Self::CAP))
     }
 
     /// Gets the bool at the current head position without validity checks. (doesn't clone self.store)
@@ -260,11 +494,16 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
 
     /// Get a mutable reference to the bools contained in a raw binary format.
     ///
+    /// `CAP` covers every bit of `T`, so any value written here is a valid store: there are no
+    /// padding bits that could leak into comparisons or counts.
+    ///
     /// # Examples
     /// ```
     /// use btypes::bbool::B8;
     /// let mut bools = B8::from_num(5);
-    /// let raw_mut = bools.get_raw_mut();
+    /// *bools.get_raw_mut() = 0xFF;
+    /// assert_eq!(bools.all().unwrap().len(), usize::from(B8::CAP));
+    /// assert!(bools.all().unwrap().into_iter().all(|b| b));
     /// ```
     pub fn get_raw_mut(&mut self) -> &mut T {
         &mut self.store
@@ -298,7 +537,7 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
             }
             return Ok(());
         }
-        Err(BBoolError::InvalidHeadPos(self.reader_head_pos))
+        Err(BBoolError::InvalidHeadPos(self.reader_head_pos, Self::CAP))
     }
 
     /// Sets the bool at the given position.
@@ -330,7 +569,7 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
             }
             return Ok(());
         }
-        Err(BBoolError::InvalidPos(pos))
+        Err(BBoolError::InvalidPos(pos, Self::CAP))
     }
 
     /// Sets the bool at the current head position without validity checks.
@@ -414,6 +653,27 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
         Ok(val)
     }
 
+    /// Gets the value at the current head position and advances the head, wrapping back to 0 after the last position.
+    ///
+    /// Never errors; a head position left out of range (e.g. by `shp_unchecked`) is wrapped first.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// let mut bools = B8::from_num(0b1000_0001);
+    /// bools.shp(7).unwrap();
+    /// assert!(bools.next_b_wrapping()); // Position 7
+    /// assert!(bools.next_b_wrapping()); // Back to position 0
+    /// assert!(!bools.next_b_wrapping());
+    /// ```
+    pub fn next_b_wrapping(&mut self) -> bool {
+        self.reader_head_pos %= Self::CAP;
+        let mask = T::one() << self.reader_head_pos;
+        let val = (self.store & mask) != T::zero();
+        self.inc_wrapping();
+        val
+    }
+
     /// Increments the head position by 1.
     ///
     /// # Examples
@@ -430,11 +690,27 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
     /// # Errors
     /// Returns an error if the new head position would be invalid
     pub fn inc(&mut self) -> Result<(), BBoolError> {
-        if self.reader_head_pos + 1 < Self::CAP {
-            self.reader_head_pos += 1;
-            return Ok(());
+        match self.reader_head_pos.checked_add(1) {
+            Some(next) if next < Self::CAP => {
+                self.reader_head_pos = next;
+                Ok(())
+            }
+            _ => Err(BBoolError::InvalidHeadPos(self.reader_head_pos, Self::CAP)),
         }
-        Err(BBoolError::InvalidHeadPos(self.reader_head_pos))
+    }
+
+    /// Increments the head position by 1, wrapping back to 0 after the last position.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// let mut bools = B8::new();
+    /// bools.shp(7).unwrap();
+    /// bools.inc_wrapping();
+    /// assert_eq!(*bools.ghp(), 0);
+    /// ```
+    pub const fn inc_wrapping(&mut self) {
+        self.reader_head_pos = (self.reader_head_pos % Self::CAP + 1) % Self::CAP;
     }
 
     /// Increments the head position by 1 without validity checks.
@@ -479,7 +755,7 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
             self.reader_head_pos = new;
             return Ok(());
         }
-        Err(BBoolError::InvalidHeadPos(new))
+        Err(BBoolError::InvalidHeadPos(new, Self::CAP))
     }
 
     /// Gets an immutable reference to the current head position.
@@ -494,6 +770,47 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
         &self.reader_head_pos
     }
 
+    /// Returns a copy of the values with the head position reset to 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = B8::from_num(0b100);
+    /// bools.shp(5)?;
+    /// let copy = bools.clone_at_start();
+    /// assert_eq!(*copy.ghp(), 0);
+    /// assert_eq!(copy.get_raw_cl(), 0b100);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn clone_at_start(&self) -> Self {
+        Self {
+            reader_head_pos: 0,
+            ..*self
+        }
+    }
+
+    /// Returns a copy of the values that keeps the current head position.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = B8::from_num(0b100);
+    /// bools.shp(5)?;
+    /// assert_eq!(*bools.clone_preserving_head().ghp(), 5);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn clone_preserving_head(&self) -> Self {
+        *self
+    }
+
     /// Gets a mutable reference to the current head position (use disrecommended).
     ///
     /// # Warning
@@ -520,100 +837,698 @@ impl<T: BitwiseOpsCopy> BetterBool<T> {
     pub fn clear(&mut self) {
         self.store = T::zero();
     }
-}
 
-impl<T: BitwiseOpsClone> BetterBool<T> {
-    /// Gets the bool at the current head position (clones self.store).
+    /// Toggles every bool whose bit is set in `mask`.
+    ///
+    /// `CAP` always matches the bit width of `T`, so every bit of the mask maps to a valid position.
+    ///
+    /// # Arguments
+    /// * `mask` - The positions to toggle, as a raw value
     ///
     /// # Examples
     /// ```
     /// use btypes::bbool::B8;
-    /// use anyhow::Result;
-    /// fn main() -> Result<()> {
-    /// let bools = B8::from_num(5);
-    /// let value = bools.get_cl()?;
-    /// Ok(())
-    /// }
+    /// let mut bools = B8::from_num(0b1010_1010);
+    /// bools.toggle_mask(0b1111_0000);
+    /// assert_eq!(*bools.get_raw(), 0b0101_1010);
     /// ```
+    pub fn toggle_mask(&mut self, mask: T) {
+        self.store = self.store ^ mask;
+    }
+
+    /// Copies the bits selected by `mask` from `src`, leaving every other bit unchanged.
     ///
-    /// # Errors
-    /// Returns an error if head position is invalid
-    pub fn get_cl(&self) -> Result<bool, BBoolError> {
-        if self.reader_head_pos < Self::CAP {
-            let mask = T::one() << self.reader_head_pos;
-            return Ok((self.store.clone() & mask) != T::zero());
-        }
-        Err(BBoolError::InvalidHeadPos(self.reader_head_pos))
+    /// # Arguments
+    /// * `src` - The value to copy bits from
+    /// * `mask` - A mask with a 1 at each position to copy
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// let mut bools = B8::from_num(0b1111_0000);
+    /// bools.copy_bits_from(&B8::from_num(0b0000_1010), 0b0011_1100);
+    /// assert_eq!(*bools.get_raw(), 0b1100_1000);
+    /// ```
+    pub fn copy_bits_from(&mut self, src: &Self, mask: T) {
+        // T is exactly CAP bits wide, so the mask can't select anything out of range
+        self.store = (self.store & !mask) | (src.store & mask);
     }
 
-    /// Gets the bool at the current head position without validity checks. (clones self.store)
+    /// Splits the bools into one `BetterBool<u8>` per byte of `T`, low byte first.
     ///
-    /// # Safety
-    /// This function performs no bounds checking. The caller must ensure the head position is valid.
-    pub unsafe fn get_unchecked_cl(&self) -> bool {
-        let mask = T::one() << self.reader_head_pos;
-        (self.store.clone() & mask) != T::zero()
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B32;
+    /// let parts = B32::from_num(0x1234_5678).split_bytes();
+    /// let bytes: Vec<u8> = parts.iter().map(|part| *part.get_raw()).collect();
+    /// assert_eq!(bytes, vec![0x78, 0x56, 0x34, 0x12]);
+    /// ```
+    #[must_use]
+    pub fn split_bytes(&self) -> Vec<BetterBool<u8>> {
+        self.bytes().map(BetterBool::from_num).collect()
     }
 
-    /// Gets the bool at the given position (clones self.store).
+    /// Formats the raw value in base 2, 8, 10 or 16, zero-padded to the width of the largest value `T` can hold.
+    ///
+    /// The store is read as unsigned, so signed stores show their bit pattern.
     ///
     /// # Arguments
-    /// * `pos` - The position to read from
+    /// * `radix` - The base to format in
     ///
     /// # Examples
     /// ```
-    /// use btypes::bbool::B8;
+    /// use btypes::bbool::{B16, B8};
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
-    /// let bools = B8::from_num(5);
-    /// let value = bools.get_cl_at_pos(2)?;
+    /// assert_eq!(B8::from_num(5).to_radix_string(2)?, "00000101");
+    /// assert_eq!(B8::from_num(5).to_radix_string(10)?, "005");
+    /// assert_eq!(B16::from_num(0xBEEF).to_radix_string(8)?, "137357");
+    /// assert_eq!(B16::from_num(0x00EF).to_radix_string(16)?, "00ef");
+    /// assert!(B8::from_num(5).to_radix_string(7).is_err());
     /// Ok(())
     /// }
     /// ```
     ///
     /// # Errors
-    /// Returns an error if position is invalid
-    pub fn get_cl_at_pos(&self, pos: u8) -> Result<bool, BBoolError> {
-        if pos < Self::CAP {
-            let mask = T::one() << pos;
-            return Ok((self.store.clone() & mask) != T::zero());
+    /// Returns an error if `radix` is not 2, 8, 10 or 16
+    pub fn to_radix_string(&self, radix: u32) -> Result<String, BBoolError> {
+        let value = self
+            .bytes()
+            .enumerate()
+            .fold(0u128, |acc, (i, byte)| acc | u128::from(byte) << (i * 8));
+        let bits = u32::from(Self::CAP);
+        match radix {
+            2 => Ok(format!("{value:0width$b}", width = bits as usize)),
+            8 => Ok(format!("{value:0width$o}", width = bits.div_ceil(3) as usize)),
+            10 => {
+                let width = (u128::MAX >> (128 - bits)).to_string().len();
+                Ok(format!("{value:0width$}"))
+            }
+            16 => Ok(format!("{value:0width$x}", width = (bits / 4) as usize)),
+            _ => Err(BBoolError::Other(format!(
+                "Unsupported radix {radix}, expected 2, 8, 10 or 16"
+            ))),
         }
-        Err(BBoolError::InvalidPos(pos))
     }
 
-    /// Gets the bool at the given position without validity checks. (clones self.store)
+    /// Parses a value written in base 2, 8, 10 or 16, such as the output of `to_radix_string`.
     ///
     /// # Arguments
-    /// * `pos` - The position to read from
+    /// * `s` - The digits to parse, without any prefix
+    /// * `radix` - The base the digits are written in
     ///
-    /// # Safety
-    /// This function performs no bounds checking. The caller must ensure the position is valid.
-    pub unsafe fn get_unchecked_cl_at_pos(&self, pos: u8) -> bool {
-        let mask = T::one() << pos;
-        (self.store.clone() & mask) != T::zero()
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B16;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// assert_eq!(*B16::from_radix_str("00ef", 16)?.get_raw(), 0xEF);
+    /// assert!(B16::from_radix_str("10000", 16).is_err());
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * `radix` is not 2, 8, 10 or 16
+    /// * `s` is empty or contains a digit that isn't valid in `radix`
+    /// * The value doesn't fit in `CAP` bits
+    pub fn from_radix_str(s: &str, radix: u32) -> Result<Self, BBoolError> {
+        if ![2, 8, 10, 16].contains(&radix) {
+            return Err(BBoolError::Other(format!(
+                "Unsupported radix {radix}, expected 2, 8, 10 or 16"
+            )));
+        }
+        let value = u128::from_str_radix(s, radix)
+            .map_err(|e| BBoolError::Other(format!("Invalid base-{radix} value {s:?}: {e}")))?;
+        if Self::CAP < 128 && value >> Self::CAP != 0 {
+            return Err(BBoolError::CollectionCapacityReached);
+        }
+        let store = (0..Self::CAP)
+            .filter(|&pos| value >> pos & 1 == 1)
+            .fold(T::zero(), |acc, pos| acc | (T::one() << pos));
+        Ok(Self::from_num(store))
     }
 
-    /// Get a clone of the bools contained in a raw numeric format.
+    /// Returns an iterator over the bytes of the store, low byte first.
     ///
     /// # Examples
     /// ```
-    /// use btypes::bbool::B8;
-    /// let bools = B8::from_num(5);
-    /// let raw_clone = bools.get_raw_cl();
+    /// use btypes::bbool::B32;
+    /// let bytes: Vec<u8> = B32::from_num(0x1234_5678).bytes().collect();
+    /// assert_eq!(bytes, vec![0x78, 0x56, 0x34, 0x12]);
     /// ```
-    pub fn get_raw_cl(&self) -> T {
-        self.store.clone()
-    }
-}
-
-impl<T: BitwiseOpsCopy> Display for BetterBool<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:#?}", self.all())
+    pub fn bytes(&self) -> impl Iterator<Item = u8> {
+        let store = self.store;
+        (0..Self::CAP).step_by(8).map(move |shift| {
+            let byte = store >> shift;
+            (0..8)
+                .filter(|&bit| byte & T::from(1 << bit) != T::zero())
+                .fold(0u8, |acc, bit| acc | (1 << bit))
+        })
     }
-}
 
-impl<T: BitwiseOpsCopy> IntoIterator for BetterBool<T> {
-    type Item = bool;
+    /// Joins `BetterBool<u8>` parts, low byte first, into one `BetterBool`. Missing high bytes are zero.
+    ///
+    /// The inverse of `split_bytes`.
+    ///
+    /// # Arguments
+    /// * `parts` - The bytes to join, low byte first
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::{B16, B8};
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let joined = B16::from_bytes(&[B8::from_num(0x34), B8::from_num(0x12)])?;
+    /// assert_eq!(*joined.get_raw(), 0x1234);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `BBoolError::CollectionCapacityReached` if there are more parts than bytes in `T`
+    pub fn from_bytes(parts: &[BetterBool<u8>]) -> Result<Self, BBoolError> {
+        if parts.len() > usize::from(Self::CAP / 8) {
+            return Err(BBoolError::CollectionCapacityReached);
+        }
+        let store = (0..Self::CAP)
+            .step_by(8)
+            .zip(parts)
+            .fold(T::zero(), |acc, (shift, part)| acc | (T::from(part.store) << shift));
+        Ok(Self::from_num(store))
+    }
+
+    /// Returns whether every bool is `false`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// assert!(B8::new().is_all_false());
+    /// assert!(!B8::from_num(0b0001_0000).is_all_false());
+    /// ```
+    #[must_use]
+    pub fn is_all_false(&self) -> bool {
+        self.store == T::zero()
+    }
+
+    /// Returns whether every bool within `CAP` is `true`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// assert!(B8::from_num(0xFF).is_all_true());
+    /// assert!(!B8::from_num(0x7F).is_all_true());
+    /// ```
+    #[must_use]
+    pub fn is_all_true(&self) -> bool {
+        // CAP always matches the bit width of T, so every bit of !0 is a valid position
+        self.store == !T::zero()
+    }
+
+    /// Finds the lowest unset bit, sets it, and returns its position, treating the bools as an occupancy bitmap.
+    ///
+    /// Returns `None` if every bit is already set.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// let mut slots = B8::from_num(0b0000_0101);
+    /// assert_eq!(slots.acquire_first_free(), Some(1));
+    /// assert_eq!(slots.acquire_first_free(), Some(3));
+    /// assert_eq!(*slots.get_raw(), 0b0000_1111);
+    /// ```
+    pub fn acquire_first_free(&mut self) -> Option<u8> {
+        let pos = (0..Self::CAP).find(|&pos| (self.store & (T::one() << pos)) == T::zero())?;
+        self.store |= T::one() << pos;
+        Some(pos)
+    }
+
+    /// Unsets the bit at `pos`, freeing it for `acquire_first_free`.
+    ///
+    /// # Arguments
+    /// * `pos` - The position to release
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut slots = B8::from_num(0xFF);
+    /// slots.release(4)?;
+    /// assert_eq!(slots.acquire_first_free(), Some(4));
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the position is invalid
+    pub fn release(&mut self, pos: u8) -> Result<(), BBoolError> {
+        self.set_at_pos(pos, false)
+    }
+
+    /// Returns the number of set bits.
+    fn popcount(&self) -> u8 {
+        let mut count = 0;
+        for pos in 0..Self::CAP {
+            if (self.store & (T::one() << pos)) != T::zero() {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Returns the number of set bits within the range [start, end).
+    ///
+    /// Counting from 0 gives the rank of a position, i.e. how many set bits come before it.
+    ///
+    /// # Arguments
+    /// * `start` - The starting position (inclusive)
+    /// * `end` - The ending position (exclusive)
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B16;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = B16::from_num(0b1011_0110_0000_1101);
+    /// assert_eq!(bools.count_ones_in_range(0, 4)?, 3);
+    /// assert_eq!(bools.count_ones_in_range(4, 16)?, 5);
+    /// assert_eq!(bools.count_ones_in_range(7, 7)?, 0);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * start position is invalid
+    /// * end position is invalid
+    /// * end is less than start
+    pub fn count_ones_in_range(&self, start: u8, end: u8) -> Result<u32, BBoolError> {
+        if start >= Self::CAP {
+            return Err(BBoolError::InvalidPos(start, Self::CAP));
+        }
+        if end > Self::CAP {
+            return Err(BBoolError::InvalidPos(end, Self::CAP));
+        }
+        if end < start {
+            return Err(BBoolError::InvalidRange(start as usize, end as usize));
+        }
+
+        let mut mask = !T::zero() << start;
+        // Shifting by the full width would overflow, and an `end` of CAP needs no upper bound anyway
+        if end < Self::CAP {
+            mask &= !(!T::zero() << end);
+        }
+        Ok(u32::from(Self::from_num(self.store & mask).popcount()))
+    }
+
+    /// Returns a copy where each position is set if it or any lower position is set.
+    ///
+    /// Once a bit turns on, every higher position stays on, which suits "sticky" flags.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// assert_eq!(*B8::from_num(0b0000_0100).prefix_or().get_raw(), 0b1111_1100);
+    /// assert_eq!(*B8::from_num(0).prefix_or().get_raw(), 0);
+    /// ```
+    #[must_use]
+    pub fn prefix_or(&self) -> Self {
+        let mut store = self.store;
+        // Doubling the shift each round spreads every set bit across the full width in log2(CAP) steps
+        let mut shift = 1;
+        while shift < Self::CAP {
+            store |= store << shift;
+            shift *= 2;
+        }
+        Self { store, ..*self }
+    }
+
+    /// Advances to the next larger value with the same number of set bits, using Gosper's hack.
+    ///
+    /// Starting from the lowest `k` bits set and calling this until it returns `false` visits
+    /// every `k`-subset of the `CAP` positions in increasing numeric order. Returns `false`,
+    /// leaving the value unchanged, when no larger value fits within `CAP`, including for 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = B8::from_num(0b0000_1011);
+    /// assert!(bools.next_permutation_with_same_popcount()?);
+    /// assert_eq!(*bools.get_raw(), 0b0000_1101);
+    /// let mut last = B8::from_num(0b1110_0000);
+    /// assert!(!last.next_permutation_with_same_popcount()?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if accessing any position fails
+    pub fn next_permutation_with_same_popcount(&mut self) -> Result<bool, BBoolError> {
+        // Find the lowest run of set bits, positions `start..end`
+        let is_set = |pos: u8| (self.store & (T::one() << pos)) != T::zero();
+        let Some(start) = (0..Self::CAP).find(|&pos| is_set(pos)) else {
+            return Ok(false);
+        };
+        let Some(end) = (start..Self::CAP).find(|&pos| !is_set(pos)) else {
+            return Ok(false);
+        };
+
+        // Move the run's top bit up one place and drop the rest of the run to the bottom
+        let run = end - start;
+        for pos in 0..end {
+            self.set_at_pos(pos, pos + 1 < run)?;
+        }
+        self.set_at_pos(end, true)?;
+        Ok(true)
+    }
+
+    /// Returns true if every bit set in `self` is also set in `other`.
+    ///
+    /// # Arguments
+    /// * `other` - The value to compare against
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// let granted = B8::from_num(0b0111);
+    /// assert!(B8::from_num(0b0101).is_subset_of(&granted));
+    /// assert!(!B8::from_num(0b1001).is_subset_of(&granted));
+    /// ```
+    #[must_use]
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.store & other.store == self.store
+    }
+
+    /// Returns true if every bit set in `other` is also set in `self`.
+    ///
+    /// # Arguments
+    /// * `other` - The value to compare against
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// let granted = B8::from_num(0b0111);
+    /// assert!(granted.is_superset_of(&B8::from_num(0b0011)));
+    /// assert!(!granted.is_superset_of(&B8::from_num(0b1000)));
+    /// ```
+    #[must_use]
+    pub fn is_superset_of(&self, other: &Self) -> bool {
+        other.is_subset_of(self)
+    }
+
+    /// Returns the number of positions at which the two values differ.
+    ///
+    /// # Arguments
+    /// * `other` - The value to compare against
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// assert_eq!(B8::from_num(0b1011).hamming_distance(&B8::from_num(0b0110)), 3);
+    /// ```
+    #[must_use]
+    pub fn hamming_distance(&self, other: &Self) -> u8 {
+        Self::from_num(self.store ^ other.store).popcount()
+    }
+
+    /// Returns the index of the candidate with the smallest Hamming distance to `query`, or `None` if there are no candidates.
+    ///
+    /// Ties are broken by the lowest index.
+    ///
+    /// # Arguments
+    /// * `query` - The value to match
+    /// * `candidates` - The values to search
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// let candidates = [B8::from_num(0b1111_0000), B8::from_num(0b0000_1111), B8::from_num(0b0000_0111)];
+    /// assert_eq!(B8::nearest(&B8::from_num(0b0000_0011), &candidates), Some(2));
+    /// ```
+    #[must_use]
+    pub fn nearest(query: &Self, candidates: &[Self]) -> Option<usize> {
+        candidates
+            .iter()
+            .enumerate()
+            .min_by_key(|(i, candidate)| (query.hamming_distance(candidate), *i))
+            .map(|(i, _)| i)
+    }
+
+    /// Compares by the number of set bits, breaking ties by raw value.
+    ///
+    /// # Arguments
+    /// * `other` - The value to compare against
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// use std::cmp::Ordering;
+    /// // 0b1000_0000 is larger, but has fewer bits set
+    /// assert_eq!(B8::from_num(0b1000_0000).cmp_by_popcount(&B8::from_num(0b11)), Ordering::Less);
+    /// assert_eq!(B8::from_num(0b01).cmp_by_popcount(&B8::from_num(0b10)), Ordering::Less);
+    /// ```
+    #[must_use]
+    pub fn cmp_by_popcount(&self, other: &Self) -> std::cmp::Ordering
+    where
+        T: Ord,
+    {
+        self.popcount()
+            .cmp(&other.popcount())
+            .then_with(|| self.store.cmp(&other.store))
+    }
+}
+
+impl<T: BitwiseOpsClone> BetterBool<T> {
+    /// Gets the bool at the current head position (clones self.store).
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = B8::from_num(5);
+    /// let value = bools.get_cl()?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if head position is invalid
+    pub fn get_cl(&self) -> Result<bool, BBoolError> {
+        if self.reader_head_pos < Self::CAP {
+            let mask = T::one() << self.reader_head_pos;
+            return Ok((self.store.clone() & mask) != T::zero());
+        }
+        Err(BBoolError::InvalidHeadPos(self.reader_head_pos, Self::CAP))
+    }
+
+    /// Gets the bool at the current head position without validity checks. (clones self.store)
+    ///
+    /// # Safety
+    /// This function performs no bounds checking. The caller must ensure the head position is valid.
+    pub unsafe fn get_unchecked_cl(&self) -> bool {
+        let mask = T::one() << self.reader_head_pos;
+        (self.store.clone() & mask) != T::zero()
+    }
+
+    /// Gets the bool at the given position (clones self.store).
+    ///
+    /// # Arguments
+    /// * `pos` - The position to read from
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = B8::from_num(5);
+    /// let value = bools.get_cl_at_pos(2)?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if position is invalid
+    pub fn get_cl_at_pos(&self, pos: u8) -> Result<bool, BBoolError> {
+        if pos < Self::CAP {
+            let mask = T::one() << pos;
+            return Ok((self.store.clone() & mask) != T::zero());
+        }
+        Err(BBoolError::InvalidPos(pos, Self::CAP))
+    }
+
+    /// Gets the bool at the given position without validity checks. (clones self.store)
+    ///
+    /// # Arguments
+    /// * `pos` - The position to read from
+    ///
+    /// # Safety
+    /// This function performs no bounds checking. The caller must ensure the position is valid.
+    pub unsafe fn get_unchecked_cl_at_pos(&self, pos: u8) -> bool {
+        let mask = T::one() << pos;
+        (self.store.clone() & mask) != T::zero()
+    }
+
+    /// Get a clone of the bools contained in a raw numeric format.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B8;
+    /// let bools = B8::from_num(5);
+    /// let raw_clone = bools.get_raw_cl();
+    /// ```
+    pub fn get_raw_cl(&self) -> T {
+        self.store.clone()
+    }
+}
+
+impl BetterBool<u64> {
+    /// Interleaves the bits of two 32-bit `BetterBool`s into a 64-bit Morton code.
+    ///
+    /// Bit `i` of `x` ends up at position `2 * i` and bit `i` of `y` at position `2 * i + 1`.
+    ///
+    /// # Arguments
+    /// * `x` - The value placed in the even bits
+    /// * `y` - The value placed in the odd bits
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::{B32, B64};
+    /// let code = B64::interleave(B32::from_num(0b11), B32::from_num(0b01));
+    /// assert_eq!(*code.get_raw(), 0b0111);
+    /// ```
+    #[must_use]
+    pub const fn interleave(x: BetterBool<u32>, y: BetterBool<u32>) -> Self {
+        Self::from_num(Self::spread(x.store) | (Self::spread(y.store) << 1))
+    }
+
+    /// Splits a 64-bit Morton code back into its two 32-bit `BetterBool`s, the inverse of `interleave`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::{B32, B64};
+    /// let code = B64::interleave(B32::from_num(7), B32::from_num(42));
+    /// let (x, y) = code.deinterleave();
+    /// assert_eq!((*x.get_raw(), *y.get_raw()), (7, 42));
+    /// ```
+    #[must_use]
+    pub const fn deinterleave(&self) -> (BetterBool<u32>, BetterBool<u32>) {
+        (
+            BetterBool::from_num(Self::compact(self.store)),
+            BetterBool::from_num(Self::compact(self.store >> 1)),
+        )
+    }
+
+    /// Spreads the bits of a `u32` out to the even bit positions of a `u64`.
+    const fn spread(value: u32) -> u64 {
+        let mut v = value as u64;
+        v = (v | (v << 16)) & 0x0000_FFFF_0000_FFFF;
+        v = (v | (v << 8)) & 0x00FF_00FF_00FF_00FF;
+        v = (v | (v << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+        v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+        (v | (v << 1)) & 0x5555_5555_5555_5555
+    }
+
+    /// Gathers the even bit positions of a `u64` back into a `u32`.
+    const fn compact(value: u64) -> u32 {
+        let mut v = value & 0x5555_5555_5555_5555;
+        v = (v | (v >> 1)) & 0x3333_3333_3333_3333;
+        v = (v | (v >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+        v = (v | (v >> 4)) & 0x00FF_00FF_00FF_00FF;
+        v = (v | (v >> 8)) & 0x0000_FFFF_0000_FFFF;
+        v = (v | (v >> 16)) & 0x0000_0000_FFFF_FFFF;
+        // Able to allow as the mask above leaves only the low 32 bits set.
+        #[allow(clippy::cast_possible_truncation)]
+        let out = v as u32;
+        out
+    }
+}
+
+#[cfg(feature = "inf_bools")]
+impl BetterBool<u64> {
+    /// Converts rows of flags into bit planes, where plane `i` holds bit `i` of every row.
+    ///
+    /// Always returns 64 planes, each holding one bit per row in row order.
+    ///
+    /// # Arguments
+    /// * `rows` - The rows of flags to transpose
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B64;
+    /// let planes = B64::transpose(&[B64::from_num(0b01), B64::from_num(0b11)]);
+    /// assert_eq!(planes.len(), 64);
+    /// assert_eq!(planes[0].get_raw(), &vec![0b11]);
+    /// assert_eq!(planes[1].get_raw(), &vec![0b10]);
+    /// ```
+    #[must_use]
+    pub fn transpose(rows: &[Self]) -> Vec<BetterBoolInf> {
+        (0..64)
+            .map(|bit| {
+                let mut store = vec![0u8; rows.len().div_ceil(8)];
+                for (i, row) in rows.iter().enumerate() {
+                    if (row.store >> bit) & 1 == 1 {
+                        store[i / 8] |= 1 << (i % 8);
+                    }
+                }
+                BetterBoolInf::from_vec(store)
+            })
+            .collect()
+    }
+
+    /// Converts bit planes back into rows of flags, the inverse of `transpose`.
+    ///
+    /// # Arguments
+    /// * `planes` - The bit planes, where plane `i` holds bit `i` of every row
+    /// * `rows` - How many rows to rebuild, since planes are padded to whole bytes
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bbool::B64;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let rows = vec![B64::from_num(5), B64::from_num(u64::MAX)];
+    /// let planes = B64::transpose(&rows);
+    /// let back = B64::detranspose(&planes, rows.len())?;
+    /// assert_eq!(*back[0].get_raw(), 5);
+    /// assert_eq!(*back[1].get_raw(), u64::MAX);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if there are more than 64 planes
+    pub fn detranspose(planes: &[BetterBoolInf], rows: usize) -> Result<Vec<Self>, BBoolError> {
+        if planes.len() > 64 {
+            return Err(BBoolError::Other(format!(
+                "Expected at most 64 bit planes, got {}",
+                planes.len()
+            )));
+        }
+        let mut out = vec![Self::new(); rows];
+        for (bit, plane) in planes.iter().enumerate() {
+            for (i, row) in out.iter_mut().enumerate() {
+                if plane.get_at_pos(i)? {
+                    row.store |= 1 << bit;
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl<T: BitwiseOpsCopy> Display for BetterBool<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#?}", self.all())
+    }
+}
+
+impl<T: BitwiseOpsCopy> IntoIterator for BetterBool<T> {
+    type Item = bool;
     type IntoIter = std::vec::IntoIter<Self::Item>;
     fn into_iter(self) -> Self::IntoIter {
         self.all()
@@ -622,4 +1537,291 @@ impl<T: BitwiseOpsCopy> IntoIterator for BetterBool<T> {
     }
 }
 
+/// Creates a `B8` with bit 0 set to `value`.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B8;
+/// assert_eq!(*B8::from(true).get_raw(), 1);
+/// assert_eq!(*B8::from(false).get_raw(), 0);
+/// ```
+impl From<bool> for BetterBool<u8> {
+    fn from(value: bool) -> Self {
+        Self::from_num(u8::from(value))
+    }
+}
+
+/// Creates a `BetterBool` with position `i` set to `values[i]`.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B8;
+/// use btypes::error::BBoolError;
+/// assert_eq!(*B8::try_from(&[true, false, true][..]).unwrap().get_raw(), 0b101);
+/// assert!(matches!(B8::try_from(&[false; 9][..]), Err(BBoolError::InvalidPos(8, 8))));
+/// ```
+///
+/// # Errors
+/// Returns `BBoolError::InvalidPos` with the first position past `CAP` if there are more values than fit
+impl<T: BitwiseOpsCopy> TryFrom<&[bool]> for BetterBool<T> {
+    type Error = BBoolError;
+    fn try_from(values: &[bool]) -> Result<Self, Self::Error> {
+        if values.len() > usize::from(Self::CAP) {
+            return Err(BBoolError::InvalidPos(Self::CAP, Self::CAP));
+        }
+        let mut bools = Self::new();
+        for (pos, &value) in (0..Self::CAP).zip(values) {
+            bools.set_at_pos(pos, value)?;
+        }
+        Ok(bools)
+    }
+}
+
+/// Shifts the bools towards higher positions, dropping any that move past `CAP`.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B8;
+/// assert_eq!(*(B8::from_num(0b1000_0001) << 1).get_raw(), 0b0000_0010);
+/// assert_eq!(*(B8::from_num(0b1000_0001) << 8).get_raw(), 0);
+/// ```
+impl<T: BitwiseOpsCopy> Shl<u8> for BetterBool<T> {
+    type Output = Self;
+    fn shl(self, rhs: u8) -> Self::Output {
+        let store = if rhs < Self::CAP {
+            self.store << rhs
+        } else {
+            T::zero()
+        };
+        Self { store, ..self }
+    }
+}
+
+/// Shifts the bools towards lower positions, dropping any that move below position 0.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B8;
+/// assert_eq!(*(B8::from_num(0b1000_0001) >> 1).get_raw(), 0b0100_0000);
+/// assert_eq!(*(B8::from_num(0b1000_0001) >> 8).get_raw(), 0);
+/// ```
+impl<T: BitwiseOpsCopy> Shr<u8> for BetterBool<T> {
+    type Output = Self;
+    fn shr(self, rhs: u8) -> Self::Output {
+        let store = if rhs < Self::CAP {
+            self.store >> rhs
+        } else {
+            T::zero()
+        };
+        Self { store, ..self }
+    }
+}
+
+/// Bitwise ANDs the bools, keeping the left operand's head position.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B8;
+/// assert_eq!(*(B8::from_num(0b1100) & B8::from_num(0b1010)).get_raw(), 0b1000);
+/// ```
+impl<T: BitwiseOpsCopy> BitAnd for BetterBool<T> {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self {
+            store: self.store & rhs.store,
+            ..self
+        }
+    }
+}
+
+/// Bitwise ANDs the bools in place, keeping the head position.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B8;
+/// let mut bools = B8::from_num(0b1100);
+/// bools &= B8::from_num(0b1010);
+/// assert_eq!(*bools.get_raw(), 0b1000);
+/// ```
+impl<T: BitwiseOpsCopy> BitAndAssign for BetterBool<T> {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.store = self.store & rhs.store;
+    }
+}
+
+/// Bitwise ORs the bools, keeping the left operand's head position.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B8;
+/// assert_eq!(*(B8::from_num(0b1100) | B8::from_num(0b1010)).get_raw(), 0b1110);
+/// ```
+impl<T: BitwiseOpsCopy> BitOr for BetterBool<T> {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            store: self.store | rhs.store,
+            ..self
+        }
+    }
+}
+
+/// Bitwise ORs the bools in place, keeping the head position.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B8;
+/// let mut bools = B8::from_num(0b1100);
+/// bools |= B8::from_num(0b1010);
+/// assert_eq!(*bools.get_raw(), 0b1110);
+/// ```
+impl<T: BitwiseOpsCopy> BitOrAssign for BetterBool<T> {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.store = self.store | rhs.store;
+    }
+}
+
+/// Bitwise XORs the bools, keeping the left operand's head position.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B8;
+/// assert_eq!(*(B8::from_num(0b1100) ^ B8::from_num(0b1010)).get_raw(), 0b0110);
+/// ```
+impl<T: BitwiseOpsCopy> BitXor for BetterBool<T> {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self {
+            store: self.store ^ rhs.store,
+            ..self
+        }
+    }
+}
+
+/// Bitwise XORs the bools in place, keeping the head position.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B8;
+/// let mut bools = B8::from_num(0b1100);
+/// bools ^= B8::from_num(0b1010);
+/// assert_eq!(*bools.get_raw(), 0b0110);
+/// ```
+impl<T: BitwiseOpsCopy> BitXorAssign for BetterBool<T> {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.store = self.store ^ rhs.store;
+    }
+}
+
+/// Flips every bit, resetting the head position to 0.
+///
+/// `CAP` covers every bit of the store, so the complement has no bits set outside the capacity.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B8;
+/// assert_eq!(*(!B8::from_num(0)).get_raw(), 255);
+/// assert_eq!(*(!B8::from_num(0b1010_0000)).get_raw(), 0b0101_1111);
+/// ```
+impl<T: BitwiseOpsCopy> Not for BetterBool<T> {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        Self {
+            store: !self.store,
+            reader_head_pos: 0,
+            ..self
+        }
+    }
+}
+
+// The inherent methods take precedence over the trait's, so these calls don't recurse.
+impl<T: BitwiseOpsCopy> BoolCollection for BetterBool<T> {
+    type Error = BBoolError;
+
+    fn get_at_pos(&self, pos: usize) -> Result<bool, BBoolError> {
+        let pos = u8::try_from(pos).map_err(|_| BBoolError::InvalidPosInf(pos))?;
+        Self::get_at_pos(self, pos)
+    }
+
+    fn set_at_pos(&mut self, pos: usize, value: bool) -> Result<(), BBoolError> {
+        let pos = u8::try_from(pos).map_err(|_| BBoolError::InvalidPosInf(pos))?;
+        Self::set_at_pos(self, pos, value)
+    }
+
+    fn all(&self) -> Result<Vec<bool>, BBoolError> {
+        Self::all(self)
+    }
+
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
+}
+
 impl<T: Copy + Nums> Copy for BetterBool<T> {}
+
+/// Serializes as the raw number alone, so JSON shows e.g. `5` rather than an object.
+///
+/// The named types hold a `BetterBool`, so they can reuse this same representation for their bits.
+impl<T: Nums + Serialize> Serialize for BetterBool<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.store.serialize(serializer)
+    }
+}
+
+/// Deserializes from the raw number alone, with the head position reset to 0.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B64;
+/// use serde::de::{value::Error, Deserialize, IntoDeserializer};
+/// let bools = B64::deserialize(IntoDeserializer::<Error>::into_deserializer(5u64)).unwrap();
+/// assert_eq!(*bools.get_raw(), 5);
+/// assert_eq!(*bools.ghp(), 0);
+/// ```
+impl<'de, T: Nums + Deserialize<'de>> Deserialize<'de> for BetterBool<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(|store| Self {
+            store,
+            reader_head_pos: 0,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Wraps a `BetterBool` so that it orders by `BetterBool::cmp_by_popcount`, e.g. for sorting masks by density.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::{PopcountOrd, B32};
+/// let mut masks = vec![PopcountOrd(B32::from_num(0b111)), PopcountOrd(B32::from_num(0b1000))];
+/// masks.sort();
+/// assert_eq!(*masks[0].0.get_raw(), 0b1000);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PopcountOrd<T: Nums>(pub BetterBool<T>);
+
+impl<T: BitwiseOpsCopy + Ord> Ord for PopcountOrd<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp_by_popcount(&other.0)
+    }
+}
+
+impl<T: BitwiseOpsCopy + Ord> PartialOrd for PopcountOrd<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: BitwiseOpsCopy + Ord> PartialEq for PopcountOrd<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<T: BitwiseOpsCopy + Ord> Eq for PopcountOrd<T> {}
@@ -0,0 +1,171 @@
+use crate::error::BBoolError;
+use crate::inf_bbool::BetterBoolInf;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// A probabilistic set membership filter backed by a `BetterBoolInf`
+///
+/// Items that were inserted are always reported as present. Items that were never
+/// inserted may occasionally be reported as present too, at roughly the false-positive
+/// rate the filter was sized for.
+#[derive(Clone, Debug)]
+pub struct BloomFilter {
+    /// The underlying bit storage
+    bits: BetterBoolInf,
+    /// The number of bits used by the filter
+    bit_count: usize,
+    /// The number of hashes applied per item
+    hash_count: u32,
+}
+
+impl BloomFilter {
+    /// Creates a new `BloomFilter` sized for the expected number of items and target false-positive rate.
+    ///
+    /// # Arguments
+    /// * `expected_items` - The number of items expected to be inserted
+    /// * `false_positive_rate` - The target false-positive rate, between 0 and 1 (exclusive)
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bloom::BloomFilter;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut filter = BloomFilter::new(100, 0.01)?;
+    /// filter.insert(b"hello");
+    /// assert!(filter.contains(b"hello"));
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * `expected_items` is zero
+    /// * `false_positive_rate` is not between 0 and 1 (exclusive)
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Result<Self, BBoolError> {
+        if expected_items == 0 {
+            return Err(BBoolError::Other(
+                "Expected item count must be greater than zero".to_string(),
+            ));
+        }
+        if !(false_positive_rate > 0.0 && false_positive_rate < 1.0) {
+            return Err(BBoolError::Other(
+                "False-positive rate must be between 0 and 1".to_string(),
+            ));
+        }
+
+        // Able to allow as the item count is only used to size the filter, where precision loss is irrelevant.
+        #[allow(clippy::cast_precision_loss)]
+        let n = expected_items as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let m = (-n * false_positive_rate.ln() / (ln2 * ln2)).ceil().max(8.0);
+        let k = (m / n * ln2).round().max(1.0);
+
+        // Able to allow as both values are positive and far below the limits of their types with any sensible input.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let (bit_count, hash_count) = (m as usize, k as u32);
+
+        Ok(Self {
+            bits: BetterBoolInf::from_vec(vec![0; bit_count.div_ceil(8)]),
+            bit_count,
+            hash_count,
+        })
+    }
+
+    /// Inserts an item into the filter.
+    ///
+    /// # Arguments
+    /// * `item` - The bytes of the item to insert
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bloom::BloomFilter;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut filter = BloomFilter::new(100, 0.01)?;
+    /// filter.insert(b"hello");
+    /// Ok(())
+    /// }
+    /// ```
+    pub fn insert(&mut self, item: &[u8]) {
+        for i in 0..self.hash_count {
+            let pos = self.bit_index(item, i);
+            // `bit_index` always returns a position within the store
+            let _ = self.bits.set_at_pos(pos, true);
+        }
+    }
+
+    /// Checks whether an item may have been inserted into the filter.
+    ///
+    /// Returns `false` only if the item was definitely never inserted.
+    ///
+    /// # Arguments
+    /// * `item` - The bytes of the item to check
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bloom::BloomFilter;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut filter = BloomFilter::new(100, 0.01)?;
+    /// filter.insert(b"hello");
+    /// assert!(filter.contains(b"hello"));
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn contains(&self, item: &[u8]) -> bool {
+        (0..self.hash_count).all(|i| {
+            let pos = self.bit_index(item, i);
+            self.bits.get_at_pos(pos).unwrap_or(false)
+        })
+    }
+
+    /// Returns the number of bits used by the filter.
+    #[must_use]
+    pub const fn bit_count(&self) -> usize {
+        self.bit_count
+    }
+
+    /// Returns the number of hashes applied per item.
+    #[must_use]
+    pub const fn hash_count(&self) -> u32 {
+        self.hash_count
+    }
+
+    /// Removes all items from the filter.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bloom::BloomFilter;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut filter = BloomFilter::new(100, 0.01)?;
+    /// filter.insert(b"hello");
+    /// filter.clear();
+    /// assert!(!filter.contains(b"hello"));
+    /// Ok(())
+    /// }
+    /// ```
+    pub fn clear(&mut self) {
+        self.bits.get_raw_mut().fill(0);
+    }
+
+    /// Computes the bit position for the `i`th hash of an item using double hashing.
+    fn bit_index(&self, item: &[u8], i: u32) -> usize {
+        let h1 = Self::hash_with_seed(item, 0);
+        // Forcing the second hash to be odd keeps the probe sequence from collapsing onto one bit.
+        let h2 = Self::hash_with_seed(item, 1) | 1;
+        let combined = h1.wrapping_add(u64::from(i).wrapping_mul(h2));
+        // Able to allow as the result of the modulo always fits in usize, since `bit_count` does.
+        #[allow(clippy::cast_possible_truncation)]
+        let pos = (combined % self.bit_count as u64) as usize;
+        pos
+    }
+
+    /// Hashes an item together with a seed.
+    fn hash_with_seed(item: &[u8], seed: u64) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        seed.hash(&mut hasher);
+        item.hash(&mut hasher);
+        hasher.finish()
+    }
+}
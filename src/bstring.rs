@@ -1,8 +1,14 @@
 use crate::error::BStringError;
+#[cfg(feature = "bools")]
+use crate::bbool::BetterBool;
+#[cfg(feature = "inf_bools")]
+use crate::inf_bbool::BetterBoolInf;
 use base64::engine::general_purpose;
 use base64::Engine;
 use serde::{Deserialize, Serialize};
-use std::fmt::{Display, Error};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fmt::{Display, Error, Write};
 use std::hash::Hash;
 use std::ops::{
     Add, AddAssign, Deref, DerefMut, Div, DivAssign, Index, Mul, MulAssign, Sub, SubAssign,
@@ -14,6 +20,221 @@ use std::str::FromStr;
 /// A more convenient alias for `BetterString`
 pub type BStr = BetterString;
 
+/// The line ending style used by a string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Newline {
+    /// Unix-style `\n` line endings
+    Lf,
+    /// Windows-style `\r\n` line endings
+    CrLf,
+    /// Both `\n` and `\r\n` line endings
+    Mixed,
+    /// No line endings
+    None,
+}
+
+impl Newline {
+    /// Returns the line ending as a string, using `\n` for `Mixed` and `None`
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::CrLf => "\r\n",
+            Self::Lf | Self::Mixed | Self::None => "\n",
+        }
+    }
+}
+
+/// Requirements checked by `BetterString::check_password_policy`
+// Able to allow as each bool is an independent on/off requirement.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    /// Minimum length, in characters
+    pub min_length: usize,
+    /// Maximum length, in characters, if any
+    pub max_length: Option<usize>,
+    /// Whether at least one uppercase letter is required
+    pub require_uppercase: bool,
+    /// Whether at least one lowercase letter is required
+    pub require_lowercase: bool,
+    /// Whether at least one digit is required
+    pub require_digit: bool,
+    /// Whether at least one symbol (any other non-whitespace character) is required
+    pub require_symbol: bool,
+    /// Whether whitespace is forbidden
+    pub forbid_whitespace: bool,
+}
+
+impl Default for PasswordPolicy {
+    /// At least 8 characters, with an uppercase letter, a lowercase letter and a digit
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: None,
+            require_uppercase: true,
+            require_lowercase: true,
+            require_digit: true,
+            require_symbol: false,
+            forbid_whitespace: false,
+        }
+    }
+}
+
+/// Flags for the `_with_flags` regex methods on `BetterString`
+///
+/// Equivalent to the `i`, `m` and `s` inline flags, without having to put them in the pattern.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegexFlags {
+    /// Whether letters match regardless of case
+    pub case_insensitive: bool,
+    /// Whether `^` and `$` match at the start and end of each line
+    pub multi_line: bool,
+    /// Whether `.` also matches `\n`
+    pub dot_matches_new_line: bool,
+}
+
+impl RegexFlags {
+    /// Compiles `pattern` with these flags.
+    fn build(self, pattern: &str) -> Result<regex::Regex, BStringError> {
+        regex::RegexBuilder::new(pattern)
+            .case_insensitive(self.case_insensitive)
+            .multi_line(self.multi_line)
+            .dot_matches_new_line(self.dot_matches_new_line)
+            .build()
+            .map_err(|e| BStringError::InvalidOperation(e.to_string()))
+    }
+}
+
+/// A regex pattern compiled once by `BetterString::compile`, for matching many strings
+///
+/// The methods mirror `find_all` and `replace_all` on `BetterString`, without recompiling
+/// the pattern on every call.
+#[derive(Debug, Clone)]
+pub struct CompiledPattern {
+    regex: regex::Regex,
+}
+
+impl CompiledPattern {
+    /// Returns the pattern this was compiled from
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.regex.as_str()
+    }
+
+    /// Returns whether the pattern matches anywhere in `s`
+    ///
+    /// Invalid UTF-8 never matches.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let digits = BetterString::compile(r"\d+").unwrap();
+    /// assert!(digits.is_match(&BetterString::new("abc123")));
+    /// assert!(!digits.is_match(&BetterString::new("abc")));
+    /// ```
+    #[must_use]
+    pub fn is_match(&self, s: &BetterString) -> bool {
+        std::str::from_utf8(&s.bytes).is_ok_and(|s| self.regex.is_match(s))
+    }
+
+    /// Finds all matches in `s`, as each match's byte offset and text
+    ///
+    /// Invalid UTF-8 gives no matches.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let digits = BetterString::compile(r"\d+").unwrap();
+    /// let found = digits.find_all(&BetterString::new("a1 b22"));
+    /// assert_eq!(found, vec![(1, BetterString::new("1")), (4, BetterString::new("22"))]);
+    /// ```
+    #[must_use]
+    pub fn find_all(&self, s: &BetterString) -> Vec<(usize, BetterString)> {
+        std::str::from_utf8(&s.bytes).map_or_else(
+            |_| Vec::new(),
+            |s| {
+                self.regex
+                    .find_iter(s)
+                    .map(|m| (m.start(), BetterString::from(m.as_str())))
+                    .collect()
+            },
+        )
+    }
+
+    /// Replaces all matches in `s` with `replacement`, which may refer to groups as `$1` or `$name`
+    ///
+    /// Invalid UTF-8 is returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let digits = BetterString::compile(r"\d+").unwrap();
+    /// assert_eq!(digits.replace_all(&BetterString::new("a1 b22"), "#"), "a# b#");
+    /// ```
+    #[must_use]
+    pub fn replace_all(&self, s: &BetterString, replacement: &str) -> BetterString {
+        std::str::from_utf8(&s.bytes).map_or_else(
+            |_| s.clone(),
+            |text| BetterString::new(self.regex.replace_all(text, replacement)),
+        )
+    }
+}
+
+/// A reason a password failed a `PasswordPolicy`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyViolation {
+    /// The password has fewer characters than the minimum
+    TooShort {
+        /// The required minimum length
+        min: usize,
+        /// The password's length
+        actual: usize,
+    },
+    /// The password has more characters than the maximum
+    TooLong {
+        /// The allowed maximum length
+        max: usize,
+        /// The password's length
+        actual: usize,
+    },
+    /// No uppercase letter was found
+    MissingUppercase,
+    /// No lowercase letter was found
+    MissingLowercase,
+    /// No digit was found
+    MissingDigit,
+    /// No symbol was found
+    MissingSymbol,
+    /// Whitespace was found but is forbidden
+    ContainsWhitespace,
+}
+
+/// A region that `BetterString::merge3` couldn't merge because both sides changed it differently
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    /// The index of the first base line in the conflicting region
+    pub base_line: usize,
+    /// The region's lines in the base version
+    pub base: Vec<BetterString>,
+    /// The region's lines in our version
+    pub ours: Vec<BetterString>,
+    /// The region's lines in their version
+    pub theirs: Vec<BetterString>,
+}
+
+/// Length and encoding facts about a `BetterString`, gathered by `BetterString::encoding_info`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodingInfo {
+    /// The length in bytes
+    pub byte_len: usize,
+    /// The length in characters, if the bytes are valid UTF-8
+    pub char_len: Option<usize>,
+    /// Whether the bytes are valid UTF-8
+    pub is_valid_utf8: bool,
+    /// Whether every byte is ASCII, which implies valid UTF-8
+    pub is_ascii: bool,
+}
+
 /// An enhanced string type that provides additional functionality
 #[derive(Debug, Clone, Eq)]
 pub struct BetterString {
@@ -128,6 +349,32 @@ impl BetterString {
             .is_ok_and(|s| regex::Regex::new(pattern).is_ok_and(|re| re.is_match(s)))
     }
 
+    /// Validates if the entire string matches a given pattern
+    ///
+    /// Unlike `matches_pattern`, the pattern is anchored at both ends, so a match on
+    /// only part of the string is not enough.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bstr = BetterString::new("abc123");
+    /// assert!(bstr.matches_pattern(r"\d+"));
+    /// assert!(!bstr.matches_full(r"\d+").unwrap());
+    /// assert!(bstr.matches_full(r"[a-z]+\d+").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `BStringError::InvalidOperation` if the pattern is not a valid regex.
+    /// Returns `BStringError::InvalidUtf8` if the string contains invalid UTF-8.
+    pub fn matches_full(&self, pattern: &str) -> Result<bool, BStringError> {
+        let s = std::str::from_utf8(&self.bytes)
+            .map_err(|e| BStringError::InvalidUtf8(e.to_string()))?;
+        regex::Regex::new(&format!("^(?:{pattern})$"))
+            .map_err(|e| BStringError::InvalidOperation(e.to_string()))
+            .map(|re| re.is_match(s))
+    }
+
     /// Validates if the string is a valid URL
     #[must_use]
     pub fn is_valid_url(&self) -> bool {
@@ -247,10 +494,102 @@ impl BetterString {
     }
 
     /// Validates if the string is a valid IPv4 address
+    ///
+    /// Uses the same strict rules as [`BetterString::parse_ipv4`].
     #[must_use]
     pub fn is_valid_ipv4(&self) -> bool {
-        std::str::from_utf8(&self.bytes)
-            .is_ok_and(|s| s.split('.').filter_map(|s| s.parse::<u8>().ok()).count() == 4)
+        self.parse_ipv4().is_some()
+    }
+
+    /// Checks the string against a password policy, returning every violated requirement
+    ///
+    /// An empty result means the password satisfies the policy.
+    ///
+    /// # Arguments
+    /// * `policy` - The requirements to check against
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::{BetterString, PasswordPolicy, PolicyViolation};
+    /// let policy = PasswordPolicy::default();
+    /// assert!(BetterString::new("Secur3Pass").check_password_policy(&policy).is_empty());
+    /// assert_eq!(
+    ///     BetterString::new("password1").check_password_policy(&policy),
+    ///     vec![PolicyViolation::MissingUppercase]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn check_password_policy(&self, policy: &PasswordPolicy) -> Vec<PolicyViolation> {
+        let mut violations = Vec::new();
+        let length = self.chars().count();
+
+        if length < policy.min_length {
+            violations.push(PolicyViolation::TooShort {
+                min: policy.min_length,
+                actual: length,
+            });
+        }
+        if let Some(max) = policy.max_length.filter(|&max| length > max) {
+            violations.push(PolicyViolation::TooLong {
+                max,
+                actual: length,
+            });
+        }
+
+        let has = |pred: fn(char) -> bool| self.chars().any(pred);
+        if policy.require_uppercase && !has(char::is_uppercase) {
+            violations.push(PolicyViolation::MissingUppercase);
+        }
+        if policy.require_lowercase && !has(char::is_lowercase) {
+            violations.push(PolicyViolation::MissingLowercase);
+        }
+        if policy.require_digit && !has(|c| c.is_ascii_digit()) {
+            violations.push(PolicyViolation::MissingDigit);
+        }
+        if policy.require_symbol && !has(|c| !c.is_alphanumeric() && !c.is_whitespace()) {
+            violations.push(PolicyViolation::MissingSymbol);
+        }
+        if policy.forbid_whitespace && has(char::is_whitespace) {
+            violations.push(PolicyViolation::ContainsWhitespace);
+        }
+
+        violations
+    }
+
+    /// Parses the string as an IPv4 address, returning its octets
+    ///
+    /// Requires exactly four dot-separated decimal octets in `0..=255`, without
+    /// leading zeros (a lone `0` is allowed).
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// assert_eq!(BetterString::new("192.168.0.1").parse_ipv4(), Some([192, 168, 0, 1]));
+    /// assert_eq!(BetterString::new("192.168.0").parse_ipv4(), None);
+    /// assert_eq!(BetterString::new("192.168.00.1").parse_ipv4(), None);
+    /// ```
+    #[must_use]
+    pub fn parse_ipv4(&self) -> Option<[u8; 4]> {
+        let s = std::str::from_utf8(&self.bytes).ok()?;
+        let mut octets = [0u8; 4];
+        let mut parts = s.split('.');
+
+        for octet in &mut octets {
+            let part = parts.next()?;
+            if part.is_empty()
+                || part.len() > 3
+                || !part.bytes().all(|b| b.is_ascii_digit())
+                || (part.len() > 1 && part.starts_with('0'))
+            {
+                return None;
+            }
+            *octet = part.parse().ok()?;
+        }
+
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(octets)
     }
 }
 
@@ -262,18 +601,99 @@ impl BetterString {
         Self::new(general_purpose::STANDARD.encode(&self.bytes))
     }
 
+    /// Converts the string to base64 encoding, breaking the output into lines of `line_len` characters
+    ///
+    /// Lines are joined with `\n` and there is no trailing newline. A `line_len` of 0 disables wrapping.
+    ///
+    /// # Arguments
+    /// * `line_len` - The maximum number of characters per line
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let s = BetterString::new("hello world");
+    /// assert_eq!(s.to_base64_wrapped(8), "aGVsbG8g\nd29ybGQ=");
+    /// assert_eq!(BetterString::from_base64(&s.to_base64_wrapped(8)).unwrap(), "hello world");
+    /// ```
+    #[must_use]
+    pub fn to_base64_wrapped(&self, line_len: usize) -> Self {
+        let encoded = general_purpose::STANDARD.encode(&self.bytes);
+        if line_len == 0 {
+            return Self::new(encoded);
+        }
+        // Base64 output is pure ASCII, so byte chunks are character chunks
+        let lines: Vec<&[u8]> = encoded.as_bytes().chunks(line_len).collect();
+        Self {
+            bytes: lines.join(&b'\n'),
+        }
+    }
+
     /// Attempts to decode a base64 string
     ///
+    /// Whitespace such as the line breaks added by `to_base64_wrapped` is ignored.
+    ///
     /// # Errors
     ///
     /// Returns a `BStringError::EncodingError` if the input string is not valid base64
     pub fn from_base64(encoded: &Self) -> Result<Self, BStringError> {
-        general_purpose::STANDARD
-            .decode(encoded)
+        Self::decode_base64_bytes(&encoded.bytes)
             .map_err(|e| BStringError::EncodingError(e.to_string()))
             .map(|bytes| Self { bytes })
     }
 
+    /// Decodes standard base64, skipping any ASCII whitespace in the input
+    fn decode_base64_bytes(encoded: &[u8]) -> Result<Vec<u8>, base64::DecodeError> {
+        if encoded.iter().any(u8::is_ascii_whitespace) {
+            let stripped: Vec<u8> = encoded
+                .iter()
+                .copied()
+                .filter(|b| !b.is_ascii_whitespace())
+                .collect();
+            general_purpose::STANDARD.decode(stripped)
+        } else {
+            general_purpose::STANDARD.decode(encoded)
+        }
+    }
+
+    /// Converts every string in a slice to base64 encoding
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let items = vec![BetterString::new("a"), BetterString::new("b")];
+    /// let encoded = BetterString::encode_all_base64(&items);
+    /// assert_eq!(encoded, vec!["YQ==", "Yg=="]);
+    /// ```
+    #[must_use]
+    pub fn encode_all_base64(items: &[Self]) -> Vec<Self> {
+        items.iter().map(Self::to_base64).collect()
+    }
+
+    /// Attempts to decode every base64 string in a slice, stopping at the first failure
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let items = vec![BetterString::new("YQ=="), BetterString::new("Yg==")];
+    /// let decoded = BetterString::decode_all_base64(&items).unwrap();
+    /// assert_eq!(decoded, vec!["a", "b"]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BStringError::EncodingError` naming the index of the first item that is not valid base64
+    pub fn decode_all_base64(items: &[Self]) -> Result<Vec<Self>, BStringError> {
+        items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                Self::decode_base64_bytes(&item.bytes)
+                    .map_err(|e| BStringError::EncodingError(format!("Item at index {i}: {e}")))
+                    .map(|bytes| Self { bytes })
+            })
+            .collect()
+    }
+
     /// Converts the string to URL-safe encoding
     #[allow(clippy::option_if_let_else)]
     #[must_use]
@@ -297,6 +717,111 @@ impl BetterString {
             .map(|s| Self::new(s.to_string()))
     }
 
+    /// Escapes backslashes and control characters so the string is safe to log on one line
+    ///
+    /// `\n`, `\t`, `\r` and `\\` use their usual escapes, other ASCII control characters and bytes
+    /// that aren't valid UTF-8 become `\xNN`, and non-ASCII control characters become `\u{NN}`.
+    /// `unescape` reverses this exactly.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let raw = BetterString::from(b"a\tb\n\x07\xff\\".to_vec());
+    /// assert_eq!(raw.escape_debug(), r"a\tb\n\x07\xff\\");
+    /// ```
+    #[must_use]
+    pub fn escape_debug(&self) -> Self {
+        let mut out = String::with_capacity(self.bytes.len());
+        for chunk in self.bytes.utf8_chunks() {
+            for c in chunk.valid().chars() {
+                match c {
+                    '\n' => out.push_str("\\n"),
+                    '\t' => out.push_str("\\t"),
+                    '\r' => out.push_str("\\r"),
+                    '\\' => out.push_str("\\\\"),
+                    c if c.is_ascii_control() => {
+                        let _ = write!(out, "\\x{:02x}", u32::from(c));
+                    }
+                    c if c.is_control() => {
+                        let _ = write!(out, "\\u{{{:x}}}", u32::from(c));
+                    }
+                    c => out.push(c),
+                }
+            }
+            for byte in chunk.invalid() {
+                let _ = write!(out, "\\x{byte:02x}");
+            }
+        }
+        Self::from(out)
+    }
+
+    /// Reverses `escape_debug`, turning escapes back into the characters and bytes they stand for
+    ///
+    /// Accepts `\n`, `\t`, `\r`, `\0`, `\\`, `\xNN` (any byte) and `\u{N...}` (any Unicode scalar value).
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let escaped = BetterString::new(r"a\tb\x41\u{e9}");
+    /// assert_eq!(escaped.unescape().unwrap(), "a\tbAé");
+    /// assert!(BetterString::new(r"\q").unescape().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `BStringError::EncodingError` if:
+    /// * A backslash is followed by an unknown escape or ends the string
+    /// * A `\x` escape isn't followed by two hex digits
+    /// * A `\u{...}` escape is malformed or not a Unicode scalar value
+    pub fn unescape(&self) -> Result<Self, BStringError> {
+        let invalid = |at: usize| BStringError::EncodingError(format!("Invalid escape at byte {at}"));
+        let hex = |digits: &[u8], at: usize| {
+            std::str::from_utf8(digits)
+                .ok()
+                .filter(|d| !d.is_empty() && d.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|d| u32::from_str_radix(d, 16).ok())
+                .ok_or_else(|| invalid(at))
+        };
+
+        let bytes = &self.bytes;
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] != b'\\' {
+                out.push(bytes[i]);
+                i += 1;
+                continue;
+            }
+            let start = i;
+            match bytes.get(i + 1) {
+                Some(b'n') => out.push(b'\n'),
+                Some(b't') => out.push(b'\t'),
+                Some(b'r') => out.push(b'\r'),
+                Some(b'0') => out.push(0),
+                Some(b'\\') => out.push(b'\\'),
+                Some(b'x') => {
+                    let digits = bytes.get(i + 2..i + 4).ok_or_else(|| invalid(start))?;
+                    // Two hex digits always fit in a byte
+                    #[allow(clippy::cast_possible_truncation)]
+                    out.push(hex(digits, start)? as u8);
+                    i += 2;
+                }
+                Some(b'u') if bytes.get(i + 2) == Some(&b'{') => {
+                    let close = bytes[i + 3..]
+                        .iter()
+                        .position(|&b| b == b'}')
+                        .ok_or_else(|| invalid(start))?;
+                    let c = char::from_u32(hex(&bytes[i + 3..i + 3 + close], start)?)
+                        .ok_or_else(|| invalid(start))?;
+                    out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                    i += close + 2;
+                }
+                _ => return Err(invalid(start)),
+            }
+            i += 2;
+        }
+        Ok(Self { bytes: out })
+    }
+
     /// Returns an empty `BetterString`.
     #[must_use]
     pub fn empty() -> Self {
@@ -306,6 +831,35 @@ impl BetterString {
 
 // Add pattern matching support
 impl BetterString {
+    /// Compiles a regex pattern once, for reuse across many strings
+    ///
+    /// Unlike `find_all` and `replace_all`, an invalid pattern is an error rather than being
+    /// matched literally.
+    ///
+    /// # Arguments
+    /// * `pattern` - The regex pattern to compile
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let word = BetterString::compile(r"\bcat\b").unwrap();
+    /// let hits: Vec<bool> = ["a cat", "concat", "cat!"]
+    ///     .into_iter()
+    ///     .map(|line| word.is_match(&BetterString::new(line)))
+    ///     .collect();
+    /// assert_eq!(hits, vec![true, false, true]);
+    /// assert!(BetterString::compile("(").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `BStringError::InvalidOperation` if the pattern is not a valid regex.
+    pub fn compile(pattern: &str) -> Result<CompiledPattern, BStringError> {
+        regex::Regex::new(pattern)
+            .map(|regex| CompiledPattern { regex })
+            .map_err(|e| BStringError::InvalidOperation(e.to_string()))
+    }
+
     /// Finds all matches of a pattern in the string
     ///
     /// # Panics
@@ -325,6 +879,132 @@ impl BetterString {
         }
     }
 
+    /// Lazily finds the matches of a pattern, yielding each match's byte offset and text
+    ///
+    /// Unlike `find_all`, nothing is allocated per match, so taking the first few is cheap.
+    ///
+    /// # Arguments
+    /// * `pattern` - The regex pattern to match
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bstr = BetterString::new("a1 b22 c333");
+    /// let first: Vec<_> = bstr.find_iter(r"\d+").unwrap().take(2).collect();
+    /// assert_eq!(first, vec![(1, "1"), (4, "22")]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `BStringError::InvalidOperation` if the pattern is not a valid regex.
+    /// Returns `BStringError::InvalidUtf8` if the string contains invalid UTF-8.
+    pub fn find_iter(
+        &self,
+        pattern: &str,
+    ) -> Result<impl Iterator<Item = (usize, &str)> + '_, BStringError> {
+        let re = regex::Regex::new(pattern)
+            .map_err(|e| BStringError::InvalidOperation(e.to_string()))?;
+        let s = self.as_str_checked()?;
+
+        let mut pos = 0;
+        let mut last_end = None;
+        Ok(std::iter::from_fn(move || loop {
+            let m = re.find_at(s, pos)?;
+            // Like `Regex::find_iter`, skip an empty match right where the previous one ended
+            if m.is_empty() && last_end == Some(m.end()) {
+                pos = m.end() + s[m.end()..].chars().next()?.len_utf8();
+                continue;
+            }
+            pos = m.end();
+            last_end = Some(m.end());
+            return Some((m.start(), m.as_str()));
+        }))
+    }
+
+    /// Finds the byte offsets of all non-overlapping occurrences of a literal needle
+    ///
+    /// Unlike `find_all`, the needle is never treated as a regex; matching is a
+    /// Rabin-Karp rolling-hash search over the raw bytes. An empty needle matches nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bstr = BetterString::new("a.b.a.b");
+    /// assert_eq!(bstr.find_all_literal("a.b"), vec![0, 4]);
+    /// assert_eq!(bstr.find_all_literal("aa"), Vec::<usize>::new());
+    /// ```
+    #[must_use]
+    pub fn find_all_literal(&self, needle: &str) -> Vec<usize> {
+        const BASE: u64 = 257;
+
+        let (hay, needle) = (self.bytes.as_slice(), needle.as_bytes());
+        let n = needle.len();
+        if n == 0 || n > hay.len() {
+            return Vec::new();
+        }
+
+        let hash = |bytes: &[u8]| {
+            bytes
+                .iter()
+                .fold(0u64, |h, &b| h.wrapping_mul(BASE).wrapping_add(u64::from(b)))
+        };
+        // BASE^(n - 1), used to remove the outgoing byte from the window hash
+        let high = (1..n).fold(1u64, |p, _| p.wrapping_mul(BASE));
+        let target = hash(needle);
+        let mut window = hash(&hay[..n]);
+
+        let mut out = Vec::new();
+        let mut next_allowed = 0;
+        for i in 0..=hay.len() - n {
+            if i > 0 {
+                window = window
+                    .wrapping_sub(u64::from(hay[i - 1]).wrapping_mul(high))
+                    .wrapping_mul(BASE)
+                    .wrapping_add(u64::from(hay[i + n - 1]));
+            }
+            if i >= next_allowed && window == target && &hay[i..i + n] == needle {
+                out.push(i);
+                next_allowed = i + n;
+            }
+        }
+        out
+    }
+
+    /// Returns the `(start, end)` character ranges of every match of a regex pattern
+    ///
+    /// Ranges are half-open and count Unicode scalar values rather than bytes, so they can
+    /// be used directly for styling spans.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bstr = BetterString::new("größe grün");
+    /// assert_eq!(bstr.match_ranges("gr").unwrap(), vec![(0, 2), (6, 8)]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `BStringError::InvalidOperation` if the pattern is not a valid regex.
+    /// Returns `BStringError::InvalidUtf8` if the string contains invalid UTF-8.
+    pub fn match_ranges(&self, pattern: &str) -> Result<Vec<(usize, usize)>, BStringError> {
+        let s = std::str::from_utf8(&self.bytes)
+            .map_err(|e| BStringError::InvalidUtf8(e.to_string()))?;
+        let re =
+            regex::Regex::new(pattern).map_err(|e| BStringError::InvalidOperation(e.to_string()))?;
+
+        // Matches come in increasing order, so char counts can be carried forward
+        let (mut byte_pos, mut char_pos) = (0, 0);
+        let mut to_char = |byte: usize| {
+            char_pos += s[byte_pos..byte].chars().count();
+            byte_pos = byte;
+            char_pos
+        };
+        Ok(re
+            .find_iter(s)
+            .map(|m| (to_char(m.start()), to_char(m.end())))
+            .collect())
+    }
+
     /// Replaces all matches of a pattern with a replacement string
     ///
     /// # Panics
@@ -341,24 +1021,330 @@ impl BetterString {
             self.clone()
         }
     }
-}
 
-// Add additional utility methods
-impl BetterString {
-    /// Reverses the string
-    #[must_use]
-    pub fn reverse(&self) -> Self {
-        std::str::from_utf8(&self.bytes).map_or_else(
-            |_| self.clone(),
-            |s| Self::new(s.chars().rev().collect::<String>()),
-        )
+    /// Splits the string on every match of a regex pattern
+    ///
+    /// Empty matches are handled the same way as `regex::Regex::split`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bstr = BetterString::new("a  b\tc");
+    /// let parts = bstr.split_regex(r"\s+").unwrap();
+    /// assert_eq!(parts, vec!["a", "b", "c"]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `BStringError::InvalidOperation` if the pattern is not a valid regex.
+    /// Returns `BStringError::InvalidUtf8` if the string contains invalid UTF-8.
+    pub fn split_regex(&self, pattern: &str) -> Result<Vec<Self>, BStringError> {
+        let s = std::str::from_utf8(&self.bytes)
+            .map_err(|e| BStringError::InvalidUtf8(e.to_string()))?;
+        let re =
+            regex::Regex::new(pattern).map_err(|e| BStringError::InvalidOperation(e.to_string()))?;
+        Ok(re.split(s).map(Self::from).collect())
     }
 
-    /// Counts occurrences of a pattern using regex
+    /// Finds all matches of a regex pattern, compiled with the given flags
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::{BetterString, RegexFlags};
+    /// let bstr = BetterString::new("Error: a\nerror: b");
+    /// let flags = RegexFlags { case_insensitive: true, multi_line: true, ..Default::default() };
+    /// let found = bstr.find_all_with_flags("^error", flags).unwrap();
+    /// assert_eq!(found, vec![(0, BetterString::new("Error")), (9, BetterString::new("error"))]);
+    /// ```
     ///
     /// # Errors
     ///
-    /// Returns `BStringError::InvalidOperation` if creating a new `RegEx` from the pattern fails.
+    /// Returns `BStringError::InvalidOperation` if the pattern is not a valid regex.
+    /// Returns `BStringError::InvalidUtf8` if the string contains invalid UTF-8.
+    pub fn find_all_with_flags(
+        &self,
+        pattern: &str,
+        flags: RegexFlags,
+    ) -> Result<Vec<(usize, Self)>, BStringError> {
+        let s = std::str::from_utf8(&self.bytes)
+            .map_err(|e| BStringError::InvalidUtf8(e.to_string()))?;
+        let re = flags.build(pattern)?;
+        Ok(re
+            .find_iter(s)
+            .map(|m| (m.start(), Self::from(m.as_str())))
+            .collect())
+    }
+
+    /// Replaces all matches of a regex pattern, compiled with the given flags
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::{BetterString, RegexFlags};
+    /// let bstr = BetterString::new("Cat cat CAT");
+    /// let flags = RegexFlags { case_insensitive: true, ..Default::default() };
+    /// assert_eq!(bstr.replace_all_with_flags("cat", "dog", flags).unwrap(), "dog dog dog");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `BStringError::InvalidOperation` if the pattern is not a valid regex.
+    /// Returns `BStringError::InvalidUtf8` if the string contains invalid UTF-8.
+    pub fn replace_all_with_flags(
+        &self,
+        pattern: &str,
+        replacement: &str,
+        flags: RegexFlags,
+    ) -> Result<Self, BStringError> {
+        let s = std::str::from_utf8(&self.bytes)
+            .map_err(|e| BStringError::InvalidUtf8(e.to_string()))?;
+        let re = flags.build(pattern)?;
+        Ok(Self::new(re.replace_all(s, replacement)))
+    }
+}
+
+/// Morse code for the ASCII letters and digits
+const MORSE_TABLE: [(char, &str); 36] = [
+    ('A', ".-"),
+    ('B', "-..."),
+    ('C', "-.-."),
+    ('D', "-.."),
+    ('E', "."),
+    ('F', "..-."),
+    ('G', "--."),
+    ('H', "...."),
+    ('I', ".."),
+    ('J', ".---"),
+    ('K', "-.-"),
+    ('L', ".-.."),
+    ('M', "--"),
+    ('N', "-."),
+    ('O', "---"),
+    ('P', ".--."),
+    ('Q', "--.-"),
+    ('R', ".-."),
+    ('S', "..."),
+    ('T', "-"),
+    ('U', "..-"),
+    ('V', "...-"),
+    ('W', ".--"),
+    ('X', "-..-"),
+    ('Y', "-.--"),
+    ('Z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+];
+
+// Add Morse code support
+impl BetterString {
+    /// Converts ASCII letters and digits to Morse code
+    ///
+    /// Letters are separated by spaces and words by ` / `. Any other characters are skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let morse = BetterString::new("SOS, help!").to_morse();
+    /// assert_eq!(morse, "... --- ... / .... . .-.. .--.");
+    /// ```
+    #[must_use]
+    pub fn to_morse(&self) -> Self {
+        let words: Vec<String> = self
+            .as_str()
+            .split_whitespace()
+            .map(|word| {
+                word.chars()
+                    .filter_map(|c| {
+                        let c = c.to_ascii_uppercase();
+                        MORSE_TABLE.iter().find(|(l, _)| *l == c).map(|(_, code)| *code)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .filter(|word| !word.is_empty())
+            .collect();
+        Self::new(words.join(" / "))
+    }
+
+    /// Decodes Morse code produced by `to_morse` into uppercase text
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let text = BetterString::new("... --- ... / .... . .-.. .--.").from_morse().unwrap();
+    /// assert_eq!(text, "SOS HELP");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `BStringError::ConversionError` if a symbol is not valid Morse code for a letter or digit.
+    pub fn from_morse(&self) -> Result<Self, BStringError> {
+        let mut out = String::new();
+        for symbol in self.as_str().split_whitespace() {
+            if symbol == "/" {
+                out.push(' ');
+                continue;
+            }
+            let letter = MORSE_TABLE
+                .iter()
+                .find(|(_, code)| *code == symbol)
+                .map(|(l, _)| *l)
+                .ok_or_else(|| {
+                    BStringError::ConversionError(format!("Unknown Morse symbol: {symbol}"))
+                })?;
+            out.push(letter);
+        }
+        Ok(Self::new(out))
+    }
+}
+
+// Add ASCII charset support
+#[cfg(feature = "bools")]
+impl BetterString {
+    /// Builds a presence map of the ASCII characters in the string
+    ///
+    /// Bit `c` is set for every ASCII byte `c` that appears; non-ASCII bytes are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let set = BetterString::new("hello").ascii_charset();
+    /// assert!(set.get_at_pos(b'h').unwrap());
+    /// assert!(!set.get_at_pos(b'z').unwrap());
+    /// ```
+    #[must_use]
+    pub fn ascii_charset(&self) -> BetterBool<u128> {
+        let mask = self
+            .bytes
+            .iter()
+            .filter(|b| b.is_ascii())
+            .fold(0u128, |mask, &b| mask | (1 << b));
+        BetterBool::from_num(mask)
+    }
+
+    /// Returns whether every byte of the string is an ASCII character present in `set`
+    ///
+    /// # Arguments
+    /// * `set` - A presence map, as built by [`BetterString::ascii_charset`]
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let set = BetterString::new("helo").ascii_charset();
+    /// assert!(BetterString::new("hello").contains_only_charset(&set));
+    /// assert!(!BetterString::new("help").contains_only_charset(&set));
+    /// ```
+    #[must_use]
+    pub fn contains_only_charset(&self, set: &BetterBool<u128>) -> bool {
+        let mask = *set.get_raw();
+        self.bytes
+            .iter()
+            .all(|&b| b.is_ascii() && mask & (1 << b) != 0)
+    }
+}
+
+// Add character mask support
+#[cfg(feature = "inf_bools")]
+impl BetterString {
+    /// Builds a mask with bit `i` set iff the `i`-th character satisfies `pred`
+    ///
+    /// The mask's logical length is the number of characters. Invalid UTF-8 yields an empty mask.
+    ///
+    /// # Arguments
+    /// * `pred` - The predicate to test each character with
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let mask = BetterString::new("a1b2").char_mask(|c| c.is_ascii_digit());
+    /// assert_eq!(mask.all().unwrap(), vec![false, true, false, true]);
+    /// ```
+    #[must_use]
+    pub fn char_mask<F: Fn(char) -> bool>(&self, pred: F) -> BetterBoolInf {
+        let Ok(chars) = self.try_chars() else {
+            return BetterBoolInf::new();
+        };
+
+        let mut bytes = Vec::new();
+        let mut len = 0;
+        for (i, c) in chars.enumerate() {
+            if i % 8 == 0 {
+                bytes.push(0u8);
+            }
+            if pred(c) {
+                bytes[i / 8] |= 1 << (i % 8);
+            }
+            len = i + 1;
+        }
+        // The bytes hold exactly `len` bits, so this can't fail
+        BetterBoolInf::from_bytes_with_len(bytes, len).unwrap_or_default()
+    }
+
+    /// Parses a string of `0`s and `1`s into bits, reading left-to-right as positions `0..n`
+    ///
+    /// The result's logical length is the length of the string.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bits = BetterString::new("1011").parse_bits().unwrap();
+    /// assert_eq!(bits.all().unwrap(), vec![true, false, true, true]);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `BStringError::ConversionError` if a character is neither `0` nor `1`, or
+    /// `BStringError::InvalidUtf8` if the string contains invalid UTF-8.
+    pub fn parse_bits(&self) -> Result<BetterBoolInf, BStringError> {
+        BetterBoolInf::from_bit_string(self.as_str_checked()?)
+            .map_err(|e| BStringError::ConversionError(e.to_string()))
+    }
+}
+
+// Add additional utility methods
+impl BetterString {
+    /// Reverses the string
+    #[must_use]
+    pub fn reverse(&self) -> Self {
+        std::str::from_utf8(&self.bytes).map_or_else(
+            |_| self.clone(),
+            |s| Self::new(s.chars().rev().collect::<String>()),
+        )
+    }
+
+    /// Counts the non-overlapping occurrences of a literal substring
+    ///
+    /// An empty `needle` gives 0, as does invalid UTF-8. This is what the `/` operator computes.
+    ///
+    /// # Arguments
+    /// * `needle` - The substring to count
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bstr = BetterString::new("aaaa");
+    /// assert_eq!(bstr.occurrences("aa"), 2);
+    /// assert_eq!(bstr.occurrences(""), 0);
+    /// ```
+    #[must_use]
+    pub fn occurrences(&self, needle: &str) -> usize {
+        if needle.is_empty() {
+            return 0;
+        }
+        std::str::from_utf8(&self.bytes).map_or(0, |s| s.matches(needle).count())
+    }
+
+    /// Counts occurrences of a pattern using regex
+    ///
+    /// # Errors
+    ///
+    /// Returns `BStringError::InvalidOperation` if creating a new `RegEx` from the pattern fails.
     pub fn count_pattern(&self, pattern: &str) -> Result<usize, BStringError> {
         std::str::from_utf8(&self.bytes).map_or_else(
             |_| {
@@ -386,6 +1372,235 @@ impl BetterString {
             cleaned == cleaned.chars().rev().collect::<String>()
         })
     }
+
+    /// Returns the Levenshtein edit distance to another string, counted in characters
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// assert_eq!(BetterString::new("kitten").levenshtein("sitting"), 3);
+    /// assert_eq!(BetterString::new("café").levenshtein("cafe"), 1);
+    /// ```
+    #[must_use]
+    pub fn levenshtein(&self, other: &str) -> usize {
+        let a: Vec<char> = self.chars().collect();
+        let b: Vec<char> = other.chars().collect();
+
+        let mut prev: Vec<usize> = (0..=b.len()).collect();
+        let mut curr = vec![0; b.len() + 1];
+        for (i, &ca) in a.iter().enumerate() {
+            curr[0] = i + 1;
+            for (j, &cb) in b.iter().enumerate() {
+                let substitution = prev[j] + usize::from(ca != cb);
+                curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+            }
+            std::mem::swap(&mut prev, &mut curr);
+        }
+        prev[b.len()]
+    }
+
+    /// Checks if any substring is within `max_distance` character edits of `needle`
+    ///
+    /// Every substring is considered, not just ones the same length as the needle, so
+    /// missing or extra characters are tolerated as well as typos.
+    ///
+    /// # Arguments
+    /// * `needle` - The string to search for
+    /// * `max_distance` - The maximum number of insertions, deletions, or substitutions allowed
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let text = BetterString::new("the quick brown fox");
+    /// assert!(text.fuzzy_contains("quack", 1));
+    /// assert!(!text.fuzzy_contains("quack", 0));
+    /// ```
+    #[must_use]
+    pub fn fuzzy_contains(&self, needle: &str, max_distance: usize) -> bool {
+        let hay: Vec<char> = self.chars().collect();
+        let needle: Vec<char> = needle.chars().collect();
+
+        // Column j holds the best distance for needle[..j] ending at the current haystack position,
+        // with the match free to start anywhere in the haystack
+        let mut prev: Vec<usize> = (0..=needle.len()).collect();
+        if prev[needle.len()] <= max_distance {
+            return true;
+        }
+        let mut curr = vec![0; needle.len() + 1];
+        for &ch in &hay {
+            for (j, &cn) in needle.iter().enumerate() {
+                let substitution = prev[j] + usize::from(ch != cn);
+                curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+            }
+            if curr[needle.len()] <= max_distance {
+                return true;
+            }
+            std::mem::swap(&mut prev, &mut curr);
+        }
+        false
+    }
+
+    /// Compares two strings without exiting early at the first differing byte
+    ///
+    /// Intended for comparing secrets, where the time taken must not reveal how much
+    /// of the input matched. Whether the lengths differ is still observable.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let token = BetterString::new("s3cr3t");
+    /// assert!(token.constant_time_eq(&BetterString::new("s3cr3t")));
+    /// assert!(!token.constant_time_eq(&BetterString::new("s3cr3T")));
+    /// ```
+    #[must_use]
+    pub fn constant_time_eq(&self, other: &Self) -> bool {
+        if self.bytes.len() != other.bytes.len() {
+            return false;
+        }
+        let diff = self
+            .bytes
+            .iter()
+            .zip(&other.bytes)
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        // Keep the compiler from turning the fold back into an early-exit comparison
+        std::hint::black_box(diff) == 0
+    }
+
+    /// Compares two strings ignoring case, using Unicode case mappings rather than just ASCII
+    ///
+    /// Both sides are uppercased and then lowercased before comparing, which folds
+    /// multi-character mappings such as `ß` to `ss`. This relies only on the standard
+    /// library's Unicode tables. Invalid UTF-8 is compared byte for byte.
+    ///
+    /// # Arguments
+    /// * `other` - The string to compare with
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// assert!(BetterString::new("Straße").eq_ignore_case(&BetterString::new("STRASSE")));
+    /// assert!(!BetterString::new("Strasse").eq_ignore_case(&BetterString::new("Strase")));
+    /// ```
+    #[must_use]
+    pub fn eq_ignore_case(&self, other: &Self) -> bool {
+        match (self.as_str_checked(), other.as_str_checked()) {
+            (Ok(a), Ok(b)) => {
+                a.eq_ignore_ascii_case(b)
+                    || a.to_uppercase().to_lowercase() == b.to_uppercase().to_lowercase()
+            }
+            _ => self.bytes == other.bytes,
+        }
+    }
+
+    /// Returns the longest prefix, by character, shared by every item
+    ///
+    /// An empty slice gives an empty string. Invalid UTF-8 counts as empty.
+    ///
+    /// # Arguments
+    /// * `items` - The strings to compare
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let items = [BetterString::new("user.name"), BetterString::new("user.id")];
+    /// assert_eq!(BetterString::common_prefix(&items), "user.");
+    /// ```
+    #[must_use]
+    pub fn common_prefix(items: &[Self]) -> Self {
+        let Some((first, rest)) = items.split_first() else {
+            return Self::empty();
+        };
+        let first = first.as_str();
+        let len = rest.iter().fold(first.len(), |len, item| {
+            let item = item.as_str();
+            first[..len]
+                .char_indices()
+                .zip(item.chars())
+                .find(|((_, a), b)| a != b)
+                .map_or_else(|| len.min(item.len()), |((i, _), _)| i)
+        });
+        Self::from(&first[..len])
+    }
+
+    /// Returns the longest suffix, by character, shared by every item
+    ///
+    /// An empty slice gives an empty string. Invalid UTF-8 counts as empty.
+    ///
+    /// # Arguments
+    /// * `items` - The strings to compare
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let items = [BetterString::new("main.rs"), BetterString::new("lib.rs")];
+    /// assert_eq!(BetterString::common_suffix(&items), ".rs");
+    /// ```
+    #[must_use]
+    pub fn common_suffix(items: &[Self]) -> Self {
+        let Some((first, rest)) = items.split_first() else {
+            return Self::empty();
+        };
+        let first = first.as_str();
+        let start = rest.iter().fold(0, |start, item| {
+            let tail = &first[start..];
+            let shared: usize = tail
+                .chars()
+                .rev()
+                .zip(item.as_str().chars().rev())
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a.len_utf8())
+                .sum();
+            first.len() - shared
+        });
+        Self::from(&first[start..])
+    }
+
+    /// Joins `other` onto this string, writing the longest suffix of this string that is also
+    /// a prefix of `other` only once
+    ///
+    /// Without any overlap, this is a plain concatenation. Invalid UTF-8 counts as empty.
+    ///
+    /// # Arguments
+    /// * `other` - The string to append
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let merged = BetterString::new("abcdef").merge_overlap(&BetterString::new("defghi"));
+    /// assert_eq!(merged, "abcdefghi");
+    /// ```
+    #[must_use]
+    pub fn merge_overlap(&self, other: &Self) -> Self {
+        let (head, tail) = (self.as_str(), other.as_str());
+        let overlap = tail
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(tail.len()))
+            .rev()
+            .find(|&len| head.ends_with(&tail[..len]))
+            .unwrap_or(0);
+        Self::new(format!("{head}{}", &tail[overlap..]))
+    }
+
+    /// Returns a 64-bit FNV-1a hash of the string's bytes
+    ///
+    /// Unlike the derived `Hash` impl, which goes through `std`'s `Hasher` and may change
+    /// between Rust versions or platforms, this value is fixed and stable across releases,
+    /// so it's safe to persist (e.g. for content-addressable storage).
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// assert_eq!(BetterString::new("hello").content_hash(), 0xa430_d846_80aa_bd0b);
+    /// ```
+    #[must_use]
+    pub fn content_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+        self.bytes.iter().fold(FNV_OFFSET_BASIS, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+    }
 }
 
 impl BetterString {
@@ -410,6 +1625,25 @@ impl BetterString {
         }
     }
 
+    /// Creates a string of exactly `target_chars` characters by repeating `pattern`
+    ///
+    /// The last repetition is cut on a character boundary. An empty pattern gives an empty string.
+    ///
+    /// # Arguments
+    /// * `pattern` - The string to repeat
+    /// * `target_chars` - The number of characters in the result
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// assert_eq!(BetterString::repeat_to_len("ab", 5), "ababa");
+    /// assert_eq!(BetterString::repeat_to_len("", 5), "");
+    /// ```
+    #[must_use]
+    pub fn repeat_to_len(pattern: &str, target_chars: usize) -> Self {
+        Self::new(pattern.chars().cycle().take(target_chars).collect::<String>())
+    }
+
     /// Returns the length of the string in bytes
     #[must_use]
     pub fn len(&self) -> usize {
@@ -467,6 +1701,424 @@ impl BetterString {
         }
     }
 
+    /// Splits the string on the first occurrence of `delimiter`, like `str::split_once`
+    ///
+    /// # Arguments
+    /// * `delimiter` - The string to split on
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let (key, value) = BetterString::new("key=a=b").split_once("=").unwrap();
+    /// assert_eq!(key, "key");
+    /// assert_eq!(value, "a=b");
+    /// assert!(BetterString::new("key").split_once("=").is_none());
+    /// ```
+    #[must_use]
+    pub fn split_once(&self, delimiter: &str) -> Option<(Self, Self)> {
+        let (before, after) = std::str::from_utf8(&self.bytes).ok()?.split_once(delimiter)?;
+        Some((Self::from(before), Self::from(after)))
+    }
+
+    /// Splits the string on any of the given characters
+    ///
+    /// # Arguments
+    /// * `delimiters` - The characters to split on
+    /// * `drop_empty` - Whether to leave out empty segments, e.g. between adjacent delimiters
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bstr = BetterString::new("a,b; c");
+    /// assert_eq!(bstr.split_any(&[',', ';', ' '], true), vec!["a", "b", "c"]);
+    /// assert_eq!(bstr.split_any(&[',', ';', ' '], false), vec!["a", "b", "", "c"]);
+    /// ```
+    #[must_use]
+    pub fn split_any(&self, delimiters: &[char], drop_empty: bool) -> Vec<Self> {
+        self.as_str()
+            .split(delimiters)
+            .filter(|segment| !(drop_empty && segment.is_empty()))
+            .map(Self::from)
+            .collect()
+    }
+
+    /// Splits the string into owned lines, accepting both `\n` and `\r\n` line endings
+    ///
+    /// Like `str::lines`, a trailing line ending does not produce an empty last line, so
+    /// `"a\nb\n"` and `"a\nb"` give the same lines. Check `ends_with('\n')` first if the
+    /// trailing line ending needs to be restored after `join_lines`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bstr = BetterString::new("a\r\nb\nc");
+    /// assert_eq!(bstr.to_owned_lines(), vec!["a", "b", "c"]);
+    /// assert_eq!(BetterString::new("a\nb\n").to_owned_lines(), vec!["a", "b"]);
+    /// ```
+    #[must_use]
+    pub fn to_owned_lines(&self) -> Vec<Self> {
+        self.as_str().lines().map(Self::from).collect()
+    }
+
+    /// Calls `f` with each line of the string, accepting both `\n` and `\r\n` line endings
+    ///
+    /// Unlike `to_owned_lines`, no line is copied or collected.
+    ///
+    /// # Arguments
+    /// * `f` - Closure called with each line, without its line ending
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let mut longest = 0;
+    /// BetterString::new("ab\r\nabcd\nabc").for_each_line(|line| longest = longest.max(line.len()));
+    /// assert_eq!(longest, 4);
+    /// ```
+    pub fn for_each_line<F: FnMut(&str)>(&self, f: F) {
+        self.as_str().lines().for_each(f);
+    }
+
+    /// Calls `f` with each line of the string, stopping at the first error
+    ///
+    /// # Arguments
+    /// * `f` - Closure called with each line, without its line ending
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let mut seen = Vec::new();
+    /// let result = BetterString::new("a\nb\nEND\nc").try_for_each_line(|line| {
+    ///     if line == "END" {
+    ///         return Err("stopped");
+    ///     }
+    ///     seen.push(line.to_string());
+    ///     Ok(())
+    /// });
+    /// assert_eq!(result, Err("stopped"));
+    /// assert_eq!(seen, vec!["a", "b"]);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the first error returned by `f`
+    pub fn try_for_each_line<E, F: FnMut(&str) -> Result<(), E>>(&self, f: F) -> Result<(), E> {
+        self.as_str().lines().try_for_each(f)
+    }
+
+    /// Returns the distinct lines of the string, in the order they first appear
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bstr = BetterString::new("b\na\nb\nc\na");
+    /// assert_eq!(bstr.unique_lines(), vec!["b", "a", "c"]);
+    /// ```
+    #[must_use]
+    pub fn unique_lines(&self) -> Vec<Self> {
+        let mut seen = HashSet::new();
+        self.to_owned_lines()
+            .into_iter()
+            .filter(|line| seen.insert(line.clone()))
+            .collect()
+    }
+
+    /// Returns true if any line appears more than once
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// assert!(BetterString::new("a\nb\na").has_duplicate_lines());
+    /// assert!(!BetterString::new("a\nb\nc").has_duplicate_lines());
+    /// ```
+    #[must_use]
+    pub fn has_duplicate_lines(&self) -> bool {
+        let mut seen = HashSet::new();
+        !self.as_str().lines().all(|line| seen.insert(line))
+    }
+
+    /// Prepends `prefix` to every line, keeping the string's line ending style
+    ///
+    /// # Arguments
+    /// * `prefix` - The text to put at the start of each line
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let code = BetterString::new("fn main() {\n}\n");
+    /// assert_eq!(code.indent("    "), "    fn main() {\n    }\n");
+    /// ```
+    #[must_use]
+    pub fn indent(&self, prefix: &str) -> Self {
+        let lines: Vec<Self> = self
+            .to_owned_lines()
+            .into_iter()
+            .map(|line| Self::new(format!("{prefix}{line}")))
+            .collect();
+        self.rejoin_lines(&lines)
+    }
+
+    /// Removes the longest leading whitespace prefix shared by all non-blank lines
+    ///
+    /// The prefix is compared exactly, so a tab and spaces don't count as common
+    /// indentation. Blank lines don't affect the prefix and are emptied.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let code = BetterString::new("    if x {\n        y();\n    }");
+    /// assert_eq!(code.dedent(), "if x {\n    y();\n}");
+    /// ```
+    #[must_use]
+    pub fn dedent(&self) -> Self {
+        let lines = self.to_owned_lines();
+        let common = lines
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let s = line.as_str();
+                &s[..s.len() - s.trim_start().len()]
+            })
+            .reduce(|common, indent| {
+                let shared = common
+                    .chars()
+                    .zip(indent.chars())
+                    .take_while(|(a, b)| a == b)
+                    .map(|(c, _)| c.len_utf8())
+                    .sum();
+                &common[..shared]
+            })
+            .unwrap_or("");
+
+        let dedented: Vec<Self> = lines
+            .iter()
+            .map(|line| {
+                if line.trim().is_empty() {
+                    Self::empty()
+                } else {
+                    Self::from(&line.as_str()[common.len()..])
+                }
+            })
+            .collect();
+        self.rejoin_lines(&dedented)
+    }
+
+    /// Converts every `\r\n` and lone `\r` line ending to `\n`
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let text = BetterString::new("a\r\nb\rc\n");
+    /// assert_eq!(text.canonicalize_newlines(), "a\nb\nc\n");
+    /// ```
+    #[must_use]
+    pub fn canonicalize_newlines(&self) -> Self {
+        Self::new(self.as_str().replace("\r\n", "\n").replace('\r', "\n"))
+    }
+
+    /// Replaces each tab with enough spaces to reach the next tab stop
+    ///
+    /// Tab stops fall every `tab_width` characters, counting from the start of each line,
+    /// so a tab may expand to anything from one space up to `tab_width`. A `tab_width` of
+    /// 0 removes tabs.
+    ///
+    /// # Arguments
+    /// * `tab_width` - The distance between tab stops, in characters
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let row = BetterString::new("a\tbcd\te\n\tf");
+    /// assert_eq!(row.expand_tabs(4), "a   bcd e\n    f");
+    /// ```
+    #[must_use]
+    pub fn expand_tabs(&self, tab_width: usize) -> Self {
+        let mut out = String::with_capacity(self.len());
+        let mut column = 0;
+        for c in self.as_str().chars() {
+            match c {
+                '\t' if tab_width > 0 => {
+                    let spaces = tab_width - column % tab_width;
+                    out.extend(std::iter::repeat_n(' ', spaces));
+                    column += spaces;
+                }
+                '\t' => {}
+                '\n' | '\r' => {
+                    out.push(c);
+                    column = 0;
+                }
+                c => {
+                    out.push(c);
+                    column += 1;
+                }
+            }
+        }
+        Self::new(out)
+    }
+
+    /// Joins lines using this string's line ending style, keeping a trailing line ending if it had one.
+    fn rejoin_lines(&self, lines: &[Self]) -> Self {
+        let nl = self.detect_newline();
+        let mut out = Self::join_lines(lines, nl);
+        if self.as_str().ends_with('\n') {
+            out.bytes.extend_from_slice(nl.as_str().as_bytes());
+        }
+        out
+    }
+
+    /// Detects which line ending style the string uses
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::{BetterString, Newline};
+    /// assert_eq!(BetterString::new("a\r\nb").detect_newline(), Newline::CrLf);
+    /// assert_eq!(BetterString::new("a\nb\r\n").detect_newline(), Newline::Mixed);
+    /// ```
+    #[must_use]
+    pub fn detect_newline(&self) -> Newline {
+        let total = self.as_str().matches('\n').count();
+        let crlf = self.as_str().matches("\r\n").count();
+        match (total, crlf) {
+            (0, _) => Newline::None,
+            (_, 0) => Newline::Lf,
+            (t, c) if t == c => Newline::CrLf,
+            _ => Newline::Mixed,
+        }
+    }
+
+    /// Joins lines into a single string using the given line ending style
+    ///
+    /// Line endings are only placed between lines, never after the last one. Since
+    /// `to_owned_lines` drops a trailing line ending, append `nl.as_str()` to round-trip a
+    /// string that ended with one.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::{BetterString, Newline};
+    /// let original = BetterString::new("a\r\nb\r\nc");
+    /// let lines = original.to_owned_lines();
+    /// let joined = BetterString::join_lines(&lines, original.detect_newline());
+    /// assert_eq!(joined, original);
+    ///
+    /// // The trailing line ending has to be restored by the caller
+    /// let original = BetterString::new("one\ntwo\n");
+    /// let nl = original.detect_newline();
+    /// let mut joined = BetterString::join_lines(&original.to_owned_lines(), nl);
+    /// assert_eq!(joined, "one\ntwo");
+    /// if original.as_str().ends_with('\n') {
+    ///     joined += BetterString::new(nl.as_str());
+    /// }
+    /// assert_eq!(joined, original);
+    /// ```
+    #[must_use]
+    pub fn join_lines(lines: &[Self], nl: Newline) -> Self {
+        let mut bytes = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                bytes.extend_from_slice(nl.as_str().as_bytes());
+            }
+            bytes.extend_from_slice(&line.bytes);
+        }
+        Self { bytes }
+    }
+
+    /// Merges two versions of a string that were both derived from `base`, line by line
+    ///
+    /// Lines changed on only one side are taken from that side, and identical changes on both
+    /// sides are taken once. The result uses `ours`'s line ending style.
+    ///
+    /// # Arguments
+    /// * `base` - The common ancestor
+    /// * `ours` - One edited version
+    /// * `theirs` - The other edited version
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let base = BetterString::new("a\nb\nc");
+    /// let ours = BetterString::new("A\nb\nc");
+    /// let theirs = BetterString::new("a\nb\nC");
+    /// assert_eq!(BetterString::merge3(&base, &ours, &theirs).unwrap(), "A\nb\nC");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns every region where both sides changed the same lines in different ways
+    pub fn merge3(base: &Self, ours: &Self, theirs: &Self) -> Result<Self, Vec<Conflict>> {
+        let base_lines = base.to_owned_lines();
+        let our_lines = ours.to_owned_lines();
+        let their_lines = theirs.to_owned_lines();
+        let our_matches = Self::match_lines(&base_lines, &our_lines);
+        let their_matches = Self::match_lines(&base_lines, &their_lines);
+
+        let mut merged = Vec::new();
+        let mut conflicts = Vec::new();
+        let (mut base_pos, mut our_pos, mut their_pos) = (0, 0, 0);
+        loop {
+            // The next base line kept unchanged by both sides
+            let sync = (base_pos..base_lines.len())
+                .find_map(|b| Some((b, our_matches[b]?, their_matches[b]?)));
+            let (base_end, our_end, their_end) =
+                sync.unwrap_or((base_lines.len(), our_lines.len(), their_lines.len()));
+
+            let base_chunk = &base_lines[base_pos..base_end];
+            let our_chunk = &our_lines[our_pos..our_end];
+            let their_chunk = &their_lines[their_pos..their_end];
+            if our_chunk == base_chunk || our_chunk == their_chunk {
+                merged.extend_from_slice(their_chunk);
+            } else if their_chunk == base_chunk {
+                merged.extend_from_slice(our_chunk);
+            } else {
+                conflicts.push(Conflict {
+                    base_line: base_pos,
+                    base: base_chunk.to_vec(),
+                    ours: our_chunk.to_vec(),
+                    theirs: their_chunk.to_vec(),
+                });
+            }
+
+            if sync.is_none() {
+                break;
+            }
+            merged.push(base_lines[base_end].clone());
+            (base_pos, our_pos, their_pos) = (base_end + 1, our_end + 1, their_end + 1);
+        }
+
+        if conflicts.is_empty() {
+            Ok(ours.rejoin_lines(&merged))
+        } else {
+            Err(conflicts)
+        }
+    }
+
+    /// Matches up the lines of `a` and `b` using their longest common subsequence, returning
+    /// the index in `b` matched to each line of `a`.
+    fn match_lines(a: &[Self], b: &[Self]) -> Vec<Option<usize>> {
+        // lcs[x][y] is the LCS length of a[x..] and b[y..]
+        let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+        for x in (0..a.len()).rev() {
+            for y in (0..b.len()).rev() {
+                lcs[x][y] = if a[x] == b[y] {
+                    lcs[x + 1][y + 1] + 1
+                } else {
+                    lcs[x + 1][y].max(lcs[x][y + 1])
+                };
+            }
+        }
+
+        let mut matches = vec![None; a.len()];
+        let (mut x, mut y) = (0, 0);
+        while x < a.len() && y < b.len() {
+            if a[x] == b[y] {
+                matches[x] = Some(y);
+                x += 1;
+                y += 1;
+            } else if lcs[x + 1][y] >= lcs[x][y + 1] {
+                x += 1;
+            } else {
+                y += 1;
+            }
+        }
+        matches
+    }
+
     /// Returns a new string with all occurrences of `from` replaced with `to`
     #[must_use]
     pub fn replace(&self, from: &str, to: &str) -> Self {
@@ -477,6 +2129,35 @@ impl BetterString {
         )
     }
 
+    /// Consumes the string and returns an uppercase version, for method chaining
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let s = BetterString::new("  hello ").with_trim().with_uppercase();
+    /// assert_eq!(s.to_string(), "HELLO");
+    /// ```
+    #[must_use]
+    pub fn with_uppercase(self) -> Self {
+        self.to_uppercase()
+    }
+
+    /// Consumes the string and returns it with whitespace removed from both ends, for method chaining
+    #[must_use]
+    pub fn with_trim(self) -> Self {
+        self.trim()
+    }
+
+    /// Consumes the string and returns it with all occurrences of `from` replaced with `to`, for method chaining
+    ///
+    /// # Arguments
+    /// * `from` - The substring to replace
+    /// * `to` - The replacement
+    #[must_use]
+    pub fn with_replaced(self, from: &str, to: &str) -> Self {
+        self.replace(from, to)
+    }
+
     /// Returns true if the string contains the given substring
     #[must_use]
     pub fn contains(&self, substr: &str) -> bool {
@@ -566,15 +2247,199 @@ impl BetterString {
             .map_err(|e| BStringError::InvalidUtf8(e.to_string()))
     }
 
+    /// Returns a substring between the given character indices
+    ///
+    /// Unlike `substring`, indices count Unicode scalar values rather than bytes,
+    /// so a multibyte character is never split.
+    ///
+    /// # Arguments
+    /// * `start` - The starting character index (inclusive)
+    /// * `end` - The ending character index (exclusive)
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bstr = BetterString::new("héllo wörld");
+    /// assert_eq!(bstr.substr_chars(1, 5).unwrap(), "éllo");
+    /// assert!(bstr.substr_chars(5, 20).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `BStringError::InvalidOperation` if `start` is after `end` or `end` is past the
+    /// string's character count.
+    /// Returns `BStringError::InvalidUtf8` if the string contains invalid UTF-8.
+    pub fn substr_chars(&self, start: usize, end: usize) -> Result<Self, BStringError> {
+        let s = std::str::from_utf8(&self.bytes)
+            .map_err(|e| BStringError::InvalidUtf8(e.to_string()))?;
+        if start > end {
+            return Err(BStringError::InvalidOperation(
+                "Invalid substring indices".to_string(),
+            ));
+        }
+
+        let boundaries: Vec<usize> = s
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(s.len()))
+            .collect();
+        if end >= boundaries.len() {
+            return Err(BStringError::InvalidOperation(
+                "Substring indices out of range".to_string(),
+            ));
+        }
+
+        Ok(Self::from(&s[boundaries[start]..boundaries[end]]))
+    }
+
+    /// Splits the string in two at the given character index
+    ///
+    /// Unlike splitting at a byte index, this never lands in the middle of a multibyte character.
+    ///
+    /// # Arguments
+    /// * `mid` - The number of characters in the first half
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let (head, tail) = BetterString::new("héllo").split_at_char(2).unwrap();
+    /// assert_eq!(head, "hé");
+    /// assert_eq!(tail, "llo");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `BStringError::InvalidOperation` if `mid` is past the string's character count.
+    /// Returns `BStringError::InvalidUtf8` if the string contains invalid UTF-8.
+    pub fn split_at_char(&self, mid: usize) -> Result<(Self, Self), BStringError> {
+        let s = std::str::from_utf8(&self.bytes)
+            .map_err(|e| BStringError::InvalidUtf8(e.to_string()))?;
+        let byte_mid = s
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(s.len()))
+            .nth(mid)
+            .ok_or_else(|| {
+                BStringError::InvalidOperation("Split index out of range".to_string())
+            })?;
+        let (head, tail) = s.split_at(byte_mid);
+        Ok((Self::from(head), Self::from(tail)))
+    }
+
+    /// Truncates the string to at most `max_chars` characters, ending in `…` when shortened
+    ///
+    /// Strings that already fit are returned unchanged. Truncation never splits a character.
+    ///
+    /// # Arguments
+    /// * `max_chars` - The maximum number of characters, including the ellipsis
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let label = BetterString::new("Hello, World!");
+    /// assert_eq!(label.truncate_ellipsis(6), "Hello…");
+    /// assert_eq!(label.truncate_ellipsis(20), "Hello, World!");
+    /// ```
+    #[must_use]
+    pub fn truncate_ellipsis(&self, max_chars: usize) -> Self {
+        if max_chars == 0 {
+            return Self::empty();
+        }
+        let s = self.as_str();
+        match s.char_indices().nth(max_chars) {
+            // There are more than max_chars chars, so keep max_chars - 1 and add the ellipsis
+            Some(_) => {
+                let cut = s.char_indices().nth(max_chars - 1).map_or(s.len(), |(i, _)| i);
+                Self::new(format!("{}…", &s[..cut]))
+            }
+            None => self.clone(),
+        }
+    }
+
     /// Returns the number of words in the string
     #[must_use]
     pub fn word_count(&self) -> usize {
         std::str::from_utf8(&self.bytes).map_or(0, |s| s.split_whitespace().count())
     }
+
+    /// Splits the string into lowercase words, with surrounding punctuation stripped from each
+    ///
+    /// Tokens are whitespace-delimited; tokens made up entirely of punctuation are dropped.
+    /// Punctuation inside a token, as in `"don't"`, is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bstr = BetterString::new("Hello, World!");
+    /// assert_eq!(bstr.tokenize_words(), vec!["hello", "world"]);
+    /// ```
+    #[must_use]
+    pub fn tokenize_words(&self) -> Vec<Self> {
+        self.as_str()
+            .split_whitespace()
+            .map(|token| token.trim_matches(|c: char| c.is_ascii_punctuation()))
+            .filter(|token| !token.is_empty())
+            .map(|token| Self::new(token.to_lowercase()))
+            .collect()
+    }
     #[must_use]
     pub fn as_str(&self) -> &str {
         std::str::from_utf8(&self.bytes).unwrap_or("")
     }    
+
+    /// Returns the string as a `&str`, unlike `as_str` which returns `""` for invalid UTF-8
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// assert_eq!(BetterString::new("hi").as_str_checked().unwrap(), "hi");
+    /// assert!(BetterString::from(vec![0xff]).as_str_checked().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `BStringError::InvalidUtf8` if the string contains invalid UTF-8.
+    pub fn as_str_checked(&self) -> Result<&str, BStringError> {
+        std::str::from_utf8(&self.bytes).map_err(|e| BStringError::InvalidUtf8(e.to_string()))
+    }
+
+    /// Returns the string with any invalid UTF-8 sequences replaced by `U+FFFD`
+    ///
+    /// Borrows when the string is already valid UTF-8.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let bstr = BetterString::from(b"ab\xffc".to_vec());
+    /// assert_eq!(bstr.to_string_lossy(), "ab\u{FFFD}c");
+    /// ```
+    #[must_use]
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.bytes)
+    }
+
+    /// Returns the byte length, character length, UTF-8 validity and ASCII-ness in one call
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// let info = BetterString::new("héllo").encoding_info();
+    /// assert_eq!((info.byte_len, info.char_len), (6, Some(5)));
+    /// assert!(info.is_valid_utf8 && !info.is_ascii);
+    /// ```
+    #[must_use]
+    pub fn encoding_info(&self) -> EncodingInfo {
+        let is_valid_utf8 = std::str::from_utf8(&self.bytes).is_ok();
+        // Once the bytes are known to be valid, each non-continuation byte starts a character
+        let (chars, is_ascii) = self.bytes.iter().fold((0, true), |(chars, ascii), &b| {
+            (chars + usize::from(b & 0xC0 != 0x80), ascii && b.is_ascii())
+        });
+        EncodingInfo {
+            byte_len: self.bytes.len(),
+            char_len: is_valid_utf8.then_some(chars),
+            is_valid_utf8,
+            is_ascii,
+        }
+    }
 }
 
 // Implement basic arithmetic operations
@@ -655,26 +2520,12 @@ impl Mul<usize> for BetterString {
     }
 }
 
-/// Count occurences of a substring within a string
+/// Count occurences of a substring within a string; see [`BetterString::occurrences`]
 impl Div<&str> for BetterString {
     type Output = usize;
 
     fn div(self, rhs: &str) -> usize {
-        if rhs.is_empty() {
-            return 0;
-        }
-
-        std::str::from_utf8(&self.bytes).map_or(0, |s| {
-            let mut count = 0;
-            let mut start = 0;
-
-            while let Some(pos) = s[start..].find(rhs) {
-                count += 1;
-                start += pos + rhs.len();
-            }
-
-            count
-        })
+        self.occurrences(rhs)
     }
 }
 
@@ -733,6 +2584,60 @@ impl BetterString {
     pub fn char_indices(&self) -> std::str::CharIndices<'_> {
         std::str::from_utf8(&self.bytes).map_or_else(|_| "".char_indices(), |s| s.char_indices())
     }
+    /// Returns an iterator over the characters of the string, or an error if it contains invalid UTF-8
+    ///
+    /// Unlike `chars`, this lets callers tell an empty string apart from invalid bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// assert_eq!(BetterString::new("ab").try_chars().unwrap().count(), 2);
+    /// assert_eq!(BetterString::new("").try_chars().unwrap().count(), 0);
+    /// assert!(BetterString::from(vec![0xff]).try_chars().is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `BStringError::InvalidUtf8` if the string contains invalid UTF-8.
+    pub fn try_chars(&self) -> Result<std::str::Chars<'_>, BStringError> {
+        self.as_str_checked().map(str::chars)
+    }
+    /// Returns the Unicode scalar value of each character
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// assert_eq!(BetterString::new("a€").code_points().unwrap(), vec![0x61, 0x20AC]);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `BStringError::InvalidUtf8` if the string contains invalid UTF-8.
+    pub fn code_points(&self) -> Result<Vec<u32>, BStringError> {
+        Ok(self.try_chars()?.map(u32::from).collect())
+    }
+    /// Creates a string from Unicode scalar values
+    ///
+    /// # Arguments
+    /// * `cps` - The scalar values of the characters, in order
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::bstring::BetterString;
+    /// assert_eq!(BetterString::from_code_points(&[0x61, 0x20AC]).unwrap(), "a€");
+    /// assert!(BetterString::from_code_points(&[0xD800]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `BStringError::ConversionError` if any value is a surrogate or past `U+10FFFF`.
+    pub fn from_code_points(cps: &[u32]) -> Result<Self, BStringError> {
+        cps.iter()
+            .map(|&cp| {
+                char::from_u32(cp).ok_or_else(|| {
+                    BStringError::ConversionError(format!("Invalid Unicode scalar value: {cp:#X}"))
+                })
+            })
+            .collect::<Result<String, _>>()
+            .map(Self::from)
+    }
 }
 
 // TODO: Add remaining safe methods, make them standard instead of separate
@@ -0,0 +1,103 @@
+use crate::bbool::BetterBool;
+use crate::error::BBoolError;
+use std::marker::PhantomData;
+
+/// A set of flags keyed by an enum, backed by a `BetterBool<u128>`
+///
+/// Each variant's `u8` conversion is used as its bit position, so flag names are
+/// checked at compile time instead of being looked up by string.
+#[derive(Clone, Copy, Debug)]
+pub struct EnumFlags<E: Into<u8> + Copy> {
+    /// The underlying bit storage
+    bits: BetterBool<u128>,
+    /// Phantom data for the enum type E
+    _marker: PhantomData<E>,
+}
+
+impl<E: Into<u8> + Copy> Default for EnumFlags<E> {
+    fn default() -> Self {
+        Self {
+            bits: BetterBool::new(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<E: Into<u8> + Copy> EnumFlags<E> {
+    /// Creates a new `EnumFlags` with every flag unset.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::enum_flags::EnumFlags;
+    /// #[derive(Clone, Copy)]
+    /// enum Perm { Read, Write }
+    /// impl From<Perm> for u8 {
+    ///     fn from(p: Perm) -> u8 { p as u8 }
+    /// }
+    /// let flags = EnumFlags::<Perm>::new();
+    /// assert!(!flags.get(Perm::Write).unwrap());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the value of a flag.
+    ///
+    /// # Arguments
+    /// * `flag` - The flag to set
+    /// * `value` - The boolean value to set
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::enum_flags::EnumFlags;
+    /// use anyhow::Result;
+    /// #[derive(Clone, Copy)]
+    /// enum Perm { Read, Write }
+    /// impl From<Perm> for u8 {
+    ///     fn from(p: Perm) -> u8 { p as u8 }
+    /// }
+    /// fn main() -> Result<()> {
+    /// let mut flags = EnumFlags::new();
+    /// flags.set(Perm::Write, true)?;
+    /// assert!(flags.get(Perm::Write)?);
+    /// assert!(!flags.get(Perm::Read)?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the flag's position is 128 or greater
+    pub fn set(&mut self, flag: E, value: bool) -> Result<(), BBoolError> {
+        self.bits.set_at_pos(flag.into(), value)
+    }
+
+    /// Gets the value of a flag.
+    ///
+    /// # Arguments
+    /// * `flag` - The flag to get
+    ///
+    /// # Errors
+    /// Returns an error if the flag's position is 128 or greater
+    pub fn get(&self, flag: E) -> Result<bool, BBoolError> {
+        self.bits.get_at_pos(flag.into())
+    }
+
+    /// Toggles the value of a flag.
+    ///
+    /// # Arguments
+    /// * `flag` - The flag to toggle
+    ///
+    /// # Errors
+    /// Returns an error if the flag's position is 128 or greater
+    pub fn toggle(&mut self, flag: E) -> Result<(), BBoolError> {
+        let current = self.get(flag)?;
+        self.set(flag, !current)
+    }
+
+    /// Get an immutable reference to the underlying `BetterBool`.
+    #[must_use]
+    pub const fn bits(&self) -> &BetterBool<u128> {
+        &self.bits
+    }
+}
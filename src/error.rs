@@ -27,10 +27,10 @@ pub enum BStringError {
 /// and capacity constraints.
 #[derive(Debug, Clone)]
 pub enum BBoolError {
-    /// Error when head position is invalid for fixed-size collections
-    InvalidHeadPos(u8),
-    /// Error when specified position is invalid for fixed-size collections
-    InvalidPos(u8),
+    /// Error when head position is invalid for fixed-size collections (position, capacity)
+    InvalidHeadPos(u8, u8),
+    /// Error when specified position is invalid for fixed-size collections (position, capacity)
+    InvalidPos(u8, u8),
     /// Error when head position is invalid for infinite collections
     InvalidHeadPosInf(usize),
     /// Error when specified position is invalid for infinite collections
@@ -41,8 +41,12 @@ pub enum BBoolError {
     NotFound(String),
     /// Error when a pattern string is invalid
     InvalidPattern(String),
+    /// Error when a name is rejected by a collection's name validator
+    InvalidName(String),
     /// Error when attempting to exceed collection capacity
     CollectionCapacityReached,
+    /// Error when a position exceeds a configured maximum number of bits (position, maximum)
+    ExceedsConfiguredCap(usize, usize),
     /// Error when something went wrong internally, such as converting an index from usize to u128.
     InternalError(String, String),
     /// Error for other miscellaneous error conditions
@@ -55,15 +59,19 @@ impl Display for BBoolError {
             f,
             "{}",
             match self {
-                Self::InvalidHeadPos(pos) => format!("Invalid head position: {pos}"),
-                Self::InvalidPos(pos) => format!("Invalid position: {pos}"),
+                Self::InvalidHeadPos(pos, cap) =>
+                    format!("Invalid head position: {pos} (capacity {cap})"),
+                Self::InvalidPos(pos, cap) => format!("Invalid position: {pos} (capacity {cap})"),
                 Self::InvalidHeadPosInf(pos) => format!("Invalid head position: {pos}"),
                 Self::InvalidPosInf(pos) => format!("Invalid position: {pos}"),
                 Self::InvalidRange(a, b) => format!("Invalid range {a} - {b}"),
                 Self::NotFound(item) => format!("Item not found: {item}"),
                 Self::InvalidPattern(pat) => format!("Invalid pattern: {pat}"),
+                Self::InvalidName(name) => format!("Invalid name: {name:?}"),
                 Self::CollectionCapacityReached =>
                     "Collection capacity has been reached".to_string(),
+                Self::ExceedsConfiguredCap(pos, max) =>
+                    format!("Position {pos} exceeds the configured maximum of {max} bits"),
                 Self::InternalError(t, e) => format!("Internal error of type {t}: {e}"),
                 Self::Other(s) => s.to_string(),
             }
@@ -1,4 +1,5 @@
 use crate::error::BBoolError;
+use crate::traits::BoolCollection;
 use anyhow::Result;
 use std::fmt::Display;
 use std::marker::PhantomData;
@@ -6,6 +7,51 @@ use std::marker::PhantomData;
 /// Type alias for the infinite-capacity `BetterBool` implementation
 pub type BInf = BetterBoolInf;
 
+/// What writing past the end of the store does, chosen per instance with `set_policy`
+///
+/// Applies to every operation that would grow the store: `set`, `set_at_pos`, `insert_at`,
+/// `invert_range` and `append`. Only `reserve_bits` grows it regardless.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SetPolicy {
+    /// Grow the store to hold the position
+    #[default]
+    Grow,
+    /// Return `BBoolError::InvalidPosInf` without growing
+    Error,
+    /// Do nothing, leaving the store unchanged
+    Ignore,
+}
+
+/// Which bit of each byte holds the lowest position, chosen at construction with `with_bit_order`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BitOrder {
+    /// Position 0 is the least significant bit of the first byte
+    #[default]
+    Lsb0,
+    /// Position 0 is the most significant bit of the first byte, as in many wire formats
+    Msb0,
+}
+
+impl BitOrder {
+    /// Returns the mask selecting the given bit offset within a byte.
+    pub(crate) const fn mask(self, bit_offset: usize) -> u8 {
+        match self {
+            Self::Lsb0 => 1 << bit_offset,
+            Self::Msb0 => 0x80 >> bit_offset,
+        }
+    }
+
+    /// Converts a byte between this order and `Lsb0`, so byte-wide code can work in one order.
+    ///
+    /// The conversion is its own inverse.
+    pub(crate) const fn normalize(self, byte: u8) -> u8 {
+        match self {
+            Self::Lsb0 => byte,
+            Self::Msb0 => byte.reverse_bits(),
+        }
+    }
+}
+
 /// A dynamically-sized boolean collection backed by a vector
 ///
 /// This struct provides storage and operations for boolean values with
@@ -16,6 +62,16 @@ pub struct BetterBoolInf {
     pub(crate) store: Vec<u8>,
     /// Current position of the reader head
     pub(crate) reader_head_pos: usize,
+    /// Optional soft limit on the number of bits the store may grow to
+    pub(crate) max_bits: Option<usize>,
+    /// Logical number of bits, when it isn't a whole number of bytes
+    pub(crate) bit_len: Option<usize>,
+    /// Running fingerprint of the store, when tracking is enabled
+    pub(crate) fingerprint: Option<u64>,
+    /// What setting a position past the end of the store does
+    pub(crate) policy: SetPolicy,
+    /// Which bit of each byte the single-bit accessors treat as the lowest position
+    pub(crate) bit_order: BitOrder,
     /// Phantom data for the vector type
     pub(crate) _marker: PhantomData<Vec<u8>>,
 }
@@ -25,6 +81,11 @@ impl Default for BetterBoolInf {
         Self {
             store: Vec::new(),
             reader_head_pos: 0,
+            max_bits: None,
+            bit_len: None,
+            fingerprint: None,
+            policy: SetPolicy::Grow,
+            bit_order: BitOrder::Lsb0,
             _marker: PhantomData,
         }
     }
@@ -48,6 +109,19 @@ impl BetterBoolInf {
         Self::default()
     }
 
+    /// Creates a `BInfBuilder` for building a `BetterBoolInf` fluently.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let bools = BInf::builder().set(0).set(9).build();
+    /// assert_eq!(bools.get_raw(), &vec![0b0000_0001, 0b0000_0010]);
+    /// ```
+    #[must_use]
+    pub fn builder() -> BInfBuilder {
+        BInfBuilder::default()
+    }
+
     /// Creates a new empty `BetterBoolInf` instance initialized with a vector with the specified capacity.
     ///
     /// # Examples
@@ -60,10 +134,290 @@ impl BetterBoolInf {
         Self {
             store: Vec::with_capacity(cap / 8),
             reader_head_pos: 0,
+            max_bits: None,
+            bit_len: None,
+            fingerprint: None,
+            policy: SetPolicy::Grow,
+            bit_order: BitOrder::Lsb0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new empty `BetterBoolInf` instance that refuses to grow past `max` bits.
+    ///
+    /// Setting a position at or beyond `max` returns `BBoolError::ExceedsConfiguredCap`
+    /// instead of allocating, guarding against accidental huge allocations.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let mut bools = BInf::with_max_bits(16);
+    /// assert!(bools.set_at_pos(15, true).is_ok());
+    /// assert!(bools.set_at_pos(16, true).is_err());
+    /// ```
+    #[must_use]
+    pub const fn with_max_bits(max: usize) -> Self {
+        Self {
+            store: Vec::new(),
+            reader_head_pos: 0,
+            max_bits: Some(max),
+            bit_len: None,
+            fingerprint: None,
+            policy: SetPolicy::Grow,
+            bit_order: BitOrder::Lsb0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new empty `BetterBoolInf` instance using the given bit order.
+    ///
+    /// The order decides which bit of each byte holds each position, so `get_raw` yields bytes
+    /// in that convention. Every positional operation follows it; only the raw byte views
+    /// (`get_raw`, `checksum` and the like) expose the difference.
+    ///
+    /// # Arguments
+    /// * `order` - The bit order to use
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::{BInf, BitOrder};
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::with_bit_order(BitOrder::Msb0);
+    /// bools.set_at_pos(0, true)?;
+    /// assert_eq!(bools.get_raw(), &vec![0b1000_0000]);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn with_bit_order(order: BitOrder) -> Self {
+        Self {
+            store: Vec::new(),
+            reader_head_pos: 0,
+            max_bits: None,
+            bit_len: None,
+            fingerprint: None,
+            policy: SetPolicy::Grow,
+            bit_order: order,
             _marker: PhantomData,
         }
     }
 
+    /// Returns the configured maximum number of bits, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// assert_eq!(BInf::with_max_bits(16).max_bits(), Some(16));
+    /// assert_eq!(BInf::new().max_bits(), None);
+    /// ```
+    #[must_use]
+    pub const fn max_bits(&self) -> Option<usize> {
+        self.max_bits
+    }
+
+    /// Sets what `set`, `set_at_pos`, `insert_at`, `invert_range` and `append` do when they
+    /// would write past the end of the store.
+    ///
+    /// The default, `SetPolicy::Grow`, grows the store. `SetPolicy::Error` and `SetPolicy::Ignore`
+    /// guard against a stray huge position silently allocating; grow the store up front with
+    /// `reserve_bits` instead.
+    ///
+    /// # Arguments
+    /// * `policy` - The policy to use
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::{BInf, SetPolicy};
+    /// let mut bools = BInf::from_vec(vec![0]);
+    /// bools.set_policy(SetPolicy::Error);
+    /// assert!(bools.set_at_pos(7, true).is_ok());
+    /// assert!(bools.set_at_pos(1 << 40, true).is_err());
+    /// ```
+    pub const fn set_policy(&mut self, policy: SetPolicy) {
+        self.policy = policy;
+    }
+
+    /// Returns the policy for setting a position past the end of the store.
+    #[must_use]
+    pub const fn policy(&self) -> SetPolicy {
+        self.policy
+    }
+
+    /// Returns the bit order chosen at construction.
+    #[must_use]
+    pub const fn bit_order(&self) -> BitOrder {
+        self.bit_order
+    }
+
+    /// Checks a position against the configured maximum number of bits.
+    const fn check_max_bits(&self, pos: usize) -> Result<(), BBoolError> {
+        match self.max_bits {
+            Some(max) if pos >= max => Err(BBoolError::ExceedsConfiguredCap(pos, max)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Applies the set policy to an operation that needs the store to hold `bits` bits.
+    ///
+    /// Returns `Ok(false)` when `SetPolicy::Ignore` means the operation should do nothing.
+    const fn allow_growth(&self, bits: usize) -> Result<bool, BBoolError> {
+        if bits.div_ceil(8) <= self.store.len() {
+            return Ok(true);
+        }
+        match self.policy {
+            SetPolicy::Grow => Ok(true),
+            SetPolicy::Error => Err(BBoolError::InvalidPosInf(bits - 1)),
+            SetPolicy::Ignore => Ok(false),
+        }
+    }
+
+    /// Returns the logical number of bits: the length given to `from_bytes_with_len`
+    /// (extended by later writes past it), or else every bit in the store.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// assert_eq!(BInf::from_vec(vec![0, 0]).logical_len(), 16);
+    /// let mut bools = BInf::from_bytes_with_len(vec![0, 0], 10)?;
+    /// assert_eq!(bools.logical_len(), 10);
+    /// bools.set_at_pos(11, true)?;
+    /// assert_eq!(bools.logical_len(), 12);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn logical_len(&self) -> usize {
+        self.bit_len.unwrap_or(self.store.len() * 8)
+    }
+
+    /// Returns whether the container holds no bits at all.
+    ///
+    /// This differs from `is_all_false`: a store of `false` bytes isn't empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// assert!(BInf::new().is_empty());
+    /// assert!(!BInf::from_vec(vec![0]).is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.logical_len() == 0
+    }
+
+    /// Returns how far into the logical length the highest `true` bit reaches, from `0.0`
+    /// when no bit is set to `1.0` when the last bit is.
+    ///
+    /// An empty container reports `0.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// assert!((BInf::from_vec(vec![0b1000_0000, 0]).utilization() - 0.5).abs() < f64::EPSILON);
+    /// assert!(BInf::from_vec(vec![0, 0]).utilization() == 0.0);
+    /// ```
+    #[must_use]
+    // Bit counts stay far below the 2^52 where f64 loses precision
+    #[allow(clippy::cast_precision_loss)]
+    pub fn utilization(&self) -> f64 {
+        let len = self.logical_len();
+        let Some((index, &byte)) = self.store.iter().enumerate().rev().find(|&(_, &b)| b != 0)
+        else {
+            return 0.0;
+        };
+        let byte = self.bit_order.normalize(byte);
+        let used = (index * 8 + 8 - byte.leading_zeros() as usize).min(len);
+        used as f64 / len as f64
+    }
+
+    /// Starts maintaining a running fingerprint, so `fingerprint` no longer rescans the store.
+    ///
+    /// Single-bit writes then update the fingerprint for just the changed byte; bulk
+    /// operations recompute it. Writes made through `get_raw_mut` aren't tracked, so call
+    /// this again after editing the raw store directly.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::from_vec(vec![5, 42]);
+    /// bools.track_fingerprint();
+    /// bools.set_at_pos(3, true)?;
+    /// assert_eq!(bools.fingerprint(), BInf::from_vec(bools.get_raw().clone()).fingerprint());
+    /// Ok(())
+    /// }
+    /// ```
+    pub fn track_fingerprint(&mut self) {
+        self.fingerprint = Some(self.compute_fingerprint());
+    }
+
+    /// Returns a 64-bit fingerprint of the bits, for cheaply spotting changes.
+    ///
+    /// This is an XOR of per-byte hashes: a weak integrity check against accidental
+    /// corruption, not a cryptographic one. Trailing `false` bytes don't affect it. It's
+    /// maintained incrementally once `track_fingerprint` is called, and computed from scratch
+    /// otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// assert_eq!(BInf::new().fingerprint(), 0);
+    /// assert_ne!(BInf::from_vec(vec![1]).fingerprint(), BInf::from_vec(vec![0, 1]).fingerprint());
+    /// ```
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint.unwrap_or_else(|| self.compute_fingerprint())
+    }
+
+    /// Computes the fingerprint of the whole store.
+    fn compute_fingerprint(&self) -> u64 {
+        self.store
+            .iter()
+            .enumerate()
+            .fold(0, |fp, (i, &byte)| fp ^ Self::byte_fingerprint(i, byte))
+    }
+
+    /// Hashes a byte together with its index, with `false` bytes hashing to 0.
+    fn byte_fingerprint(index: usize, byte: u8) -> u64 {
+        if byte == 0 {
+            return 0;
+        }
+        // SplitMix64 finalizer over the index and byte
+        let mut z = (index as u64)
+            .wrapping_shl(8)
+            .wrapping_add(u64::from(byte))
+            .wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Updates a tracked fingerprint after the byte at `index` changed from `old`.
+    fn update_fingerprint(&mut self, index: usize, old: u8) {
+        if let Some(fp) = self.fingerprint.as_mut() {
+            *fp ^= Self::byte_fingerprint(index, old)
+                ^ Self::byte_fingerprint(index, self.store[index]);
+        }
+    }
+
+    /// Recomputes a tracked fingerprint after a bulk change to the store.
+    fn refresh_fingerprint(&mut self) {
+        if self.fingerprint.is_some() {
+            self.track_fingerprint();
+        }
+    }
+
+    /// Extends the logical length, if one is set, so it covers `end` bits.
+    fn extend_bit_len(&mut self, end: usize) {
+        if let Some(len) = self.bit_len.as_mut() {
+            *len = (*len).max(end);
+        }
+    }
+
     /// Returns a Vec of boolean values within the specified range [start, end).
     ///
     /// # Arguments
@@ -108,500 +462,1739 @@ impl BetterBoolInf {
         Ok(result)
     }
 
-    /// Returns the current capacity of the internal vector, in bits.
+    /// Removes the bools within the specified range [start, end), shifting all higher bools down to fill the gap.
     ///
-    /// # Examples
-    /// ```
-    /// use btypes::inf_bbool::BInf;
-    /// let bools = BInf::with_cap(8); // 8 bools
-    /// println!("{}", bools.cap());
-    /// assert!(bools.cap() == 8);
-    /// ```
-    #[must_use]
-    pub fn cap(&self) -> usize {
-        self.store.capacity() * 8
-    }
-
-    /// Creates a new `BetterBoolInf` instance with a specified initial vector of bytes.
+    /// The store shrinks to the fewest bytes that hold the remaining bools.
     ///
     /// # Arguments
-    /// * `initial_value` - The initial vector of bytes to store the boolean states
-    ///
-    /// # Examples
-    /// ```
-    /// use btypes::inf_bbool::BInf;
-    /// let bools = BInf::from_vec(vec![42]);
-    /// ```
-    #[must_use]
-    pub const fn from_vec(initial_value: Vec<u8>) -> Self {
-        Self {
-            store: initial_value,
-            reader_head_pos: 0,
-            _marker: PhantomData,
-        }
-    }
-
-    /// Returns a Vec of all bools in the container.
+    /// * `start` - The starting position (inclusive)
+    /// * `end` - The ending position (exclusive)
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
-    /// let mut bools = BInf::from_vec(vec![5]);
-    /// let all_bools = bools.all()?;
-    /// Ok(())
+    ///     let mut bools = BInf::from_vec(vec![0b1111_0000, 0b0000_0001]);
+    ///     bools.remove_range(0, 4)?;
+    ///     assert_eq!(bools.get_raw(), &vec![0b0001_1111, 0b0000]);
+    ///     bools.remove_range(2, 10)?;
+    ///     assert_eq!(bools.get_raw(), &vec![0b0000_0011]);
+    ///     Ok(())
     /// }
     /// ```
     ///
     /// # Errors
-    /// Returns an error if accessing any position fails
-    pub fn all(&self) -> Result<Vec<bool>, BBoolError> {
-        let mut out = vec![];
-        // Multiply by 8 since each byte contains 8 bits
-        for i in 0..(self.store.len() * 8) {
-            out.push(self.get_at_pos(i)?);
+    /// Returns an error if:
+    /// * end is less than start
+    /// * end is past the logical end of the store
+    pub fn remove_range(&mut self, start: usize, end: usize) -> Result<(), BBoolError> {
+        if end < start {
+            return Err(BBoolError::InvalidRange(start, end));
         }
-        Ok(out)
+        if end > self.logical_len() {
+            return Err(BBoolError::InvalidPosInf(end));
+        }
+
+        let mut bools = self.all()?;
+        bools.drain(start..end);
+        self.store = Self::pack(&bools, self.bit_order);
+        if self.bit_len.is_some() {
+            self.bit_len = Some(bools.len());
+        }
+        self.refresh_fingerprint();
+        Ok(())
     }
 
-    /// Returns a new `BetterBoolInf` that has been sorted.
+    /// Inverts every bool within the specified range [start, end), leaving the rest untouched.
+    ///
+    /// The store grows as needed to hold `end`, subject to the `SetPolicy`.
+    ///
+    /// # Arguments
+    /// * `start` - The starting position (inclusive)
+    /// * `end` - The ending position (exclusive)
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
-    /// let mut bools = BInf::from_vec(vec![5]);
-    /// let sorted = bools.sorted()?;
-    /// Ok(())
+    ///     let mut bools = BInf::from_vec(vec![0b0000_1111]);
+    ///     bools.invert_range(2, 10)?;
+    ///     assert_eq!(bools.get_raw(), &vec![0b1111_0011, 0b0000_0011]);
+    ///     Ok(())
     /// }
     /// ```
     ///
     /// # Errors
-    /// Returns an error if sorting operation fails
-    pub fn sorted(&self) -> Result<Self, BBoolError> {
-        let mut bools = self.all()?;
-        bools.sort_unstable();
+    /// Returns an error if:
+    /// * end is less than start
+    /// * end is past the maximum set with `with_max_bits`
+    /// * end is past the end of the store under `SetPolicy::Error`
+    pub fn invert_range(&mut self, start: usize, end: usize) -> Result<(), BBoolError> {
+        if end < start {
+            return Err(BBoolError::InvalidRange(start, end));
+        }
+        if end == start {
+            return Ok(());
+        }
+        self.check_max_bits(end - 1)?;
+        if !self.allow_growth(end)? {
+            return Ok(());
+        }
 
-        let mut sorted = Self::new();
-        for (i, &value) in bools.iter().enumerate() {
-            sorted.set_at_pos(i, value)?;
+        let bytes = end.div_ceil(8);
+        if bytes > self.store.len() {
+            self.store.resize(bytes, 0);
         }
-        Ok(sorted)
+        for pos in start..end {
+            self.store[pos / 8] ^= self.bit_order.mask(pos % 8);
+        }
+        self.extend_bit_len(end);
+        self.refresh_fingerprint();
+        Ok(())
     }
 
-    /// Gets the bool at the current head position.
+    /// Inserts a bool at the given position, shifting the bool at that position and all higher bools up by one.
+    ///
+    /// The store grows as needed so no bools are lost, subject to the `SetPolicy`.
+    ///
+    /// # Arguments
+    /// * `pos` - The position to insert at
+    /// * `value` - The boolean value to insert
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
-    /// let bools = BInf::from_vec(vec![5]);
-    /// let value = bools.get()?;
-    /// Ok(())
+    ///     let mut bools = BInf::from_vec(vec![0b1000_0011]);
+    ///     bools.insert_at(1, false)?;
+    ///     assert_eq!(bools.get_raw(), &vec![0b0000_0101, 0b0000_0001]);
+    ///     Ok(())
     /// }
     /// ```
     ///
     /// # Errors
-    /// Returns an error if head position is invalid
-    pub fn get(&self) -> Result<bool, BBoolError> {
-        if self.reader_head_pos < Self::CAP {
-            let byte_index = self.reader_head_pos / 8;
-            let bit_offset = self.reader_head_pos % 8;
-
-            if byte_index >= self.store.len() {
-                return Ok(false); // Return false for unallocated positions
+    /// Returns an error if:
+    /// * The position is invalid
+    /// * The resulting length would be past the maximum set with `with_max_bits`
+    /// * The store would have to grow under `SetPolicy::Error`
+    pub fn insert_at(&mut self, pos: usize, value: bool) -> Result<(), BBoolError> {
+        if pos < Self::CAP {
+            // Validate the grown length before allocating anything for it
+            self.check_max_bits(pos.max(self.logical_len()))?;
+            if !self.allow_growth(pos.max(self.logical_len()) + 1)? {
+                return Ok(());
             }
-
-            let mask = 1u8 << bit_offset;
-            return Ok((self.store[byte_index] & mask) != 0);
+            let mut bools = self.all()?;
+            if pos > bools.len() {
+                bools.resize(pos, false);
+            }
+            bools.insert(pos, value);
+            self.store = Self::pack(&bools, self.bit_order);
+            if self.bit_len.is_some() {
+                self.bit_len = Some(bools.len());
+            }
+            self.refresh_fingerprint();
+            return Ok(());
         }
-        Err(BBoolError::InvalidHeadPosInf(self.reader_head_pos))
+        Err(BBoolError::InvalidPosInf(pos))
     }
 
-    /// Gets the bool at the given position.
+    /// Appends `other`'s logical bits, starting at `self`'s logical length.
+    ///
+    /// When `self`'s length isn't a whole number of bytes, `other`'s bytes are shifted to
+    /// follow on directly, with no gap.
     ///
     /// # Arguments
-    /// * `pos` - The position to read from
+    /// * `other` - The bits to append
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
-    /// let bools = BInf::from_vec(vec![5]);
+    ///     let mut bools = BInf::from_bytes_with_len(vec![0b101], 3)?;
+    ///     bools.append(&BInf::from_bytes_with_len(vec![0b11], 2)?)?;
+    ///     assert_eq!(bools.logical_len(), 5);
+    ///     assert_eq!(bools.get_raw(), &vec![0b11_101]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The result would be past the maximum set with `with_max_bits`
+    /// * The store would have to grow under `SetPolicy::Error`
+    pub fn append(&mut self, other: &Self) -> Result<(), BBoolError> {
+        let start = self.logical_len();
+        let other_len = other.logical_len();
+        let total = start + other_len;
+        if other_len == 0 {
+            return Ok(());
+        }
+        self.check_max_bits(total - 1)?;
+        if !self.allow_growth(total)? {
+            return Ok(());
+        }
+
+        self.store.resize(total.div_ceil(8), 0);
+        // Splice in LSB-first order, converting both sides at the boundary
+        let order = self.bit_order;
+        self.store.iter_mut().for_each(|b| *b = order.normalize(*b));
+        let (base, shift) = (start / 8, start % 8);
+        for (i, &byte) in other.store.iter().take(other_len.div_ceil(8)).enumerate() {
+            let byte = other.bit_order.normalize(byte);
+            self.store[base + i] |= byte << shift;
+            if shift > 0 && base + i + 1 < self.store.len() {
+                self.store[base + i + 1] |= byte >> (8 - shift);
+            }
+        }
+        self.store.iter_mut().for_each(|b| *b = order.normalize(*b));
+        if self.bit_len.is_some() || other.bit_len.is_some() {
+            self.bit_len = Some(total);
+        }
+        self.refresh_fingerprint();
+        Ok(())
+    }
+
+    /// Packs a slice of bools into bytes in the given bit order.
+    fn pack(bools: &[bool], order: BitOrder) -> Vec<u8> {
+        let mut store = vec![0u8; bools.len().div_ceil(8)];
+        for (i, _) in bools.iter().enumerate().filter(|(_, &value)| value) {
+            store[i / 8] |= order.mask(i % 8);
+        }
+        store
+    }
+
+    /// Returns the current capacity of the internal vector, in bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let bools = BInf::with_cap(8); // 8 bools
+    /// println!("{}", bools.cap());
+    /// assert!(bools.cap() == 8);
+    /// ```
+    #[must_use]
+    pub fn cap(&self) -> usize {
+        self.store.capacity() * 8
+    }
+
+    /// Grows the store in a single step so it holds at least `bits` bools, filling new positions with `false`.
+    ///
+    /// Does nothing if the store is already large enough. When a maximum was set with
+    /// `with_max_bits`, the store is never grown past it.
+    ///
+    /// # Arguments
+    /// * `bits` - The number of bools the store should hold
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let mut bools = BInf::new();
+    /// bools.reserve_bits(20);
+    /// assert_eq!(bools.get_raw().len(), 3);
+    /// ```
+    pub fn reserve_bits(&mut self, bits: usize) {
+        let bits = self.max_bits.map_or(bits, |max| bits.min(max));
+        let bytes = bits.div_ceil(8);
+        if bytes > self.store.len() {
+            self.store.resize(bytes, 0);
+        }
+        self.extend_bit_len(bits);
+    }
+
+    /// Creates a new `BetterBoolInf` instance with a specified initial vector of bytes.
+    ///
+    /// # Arguments
+    /// * `initial_value` - The initial vector of bytes to store the boolean states
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let bools = BInf::from_vec(vec![42]);
+    /// ```
+    #[must_use]
+    pub const fn from_vec(initial_value: Vec<u8>) -> Self {
+        Self {
+            store: initial_value,
+            reader_head_pos: 0,
+            max_bits: None,
+            bit_len: None,
+            fingerprint: None,
+            policy: SetPolicy::Grow,
+            bit_order: BitOrder::Lsb0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new `BetterBoolInf` from raw bytes holding exactly `bit_len` bools.
+    ///
+    /// Any padding bits past `bit_len` in the last byte are cleared, and `all` returns
+    /// exactly `bit_len` bools.
+    ///
+    /// # Arguments
+    /// * `bytes` - The bytes storing the boolean states
+    /// * `bit_len` - The logical number of bits
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = BInf::from_bytes_with_len(vec![0xFF, 0xFF], 10)?;
+    /// assert_eq!(bools.all()?, vec![true; 10]);
+    /// assert_eq!(bools.get_raw(), &vec![0xFF, 0b0000_0011]);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if `bit_len` is greater than the number of bits in `bytes`
+    pub fn from_bytes_with_len(mut bytes: Vec<u8>, bit_len: usize) -> Result<Self, BBoolError> {
+        if bit_len > bytes.len() * 8 {
+            return Err(BBoolError::InvalidPosInf(bit_len));
+        }
+        if let Some(last) = bytes.get_mut(bit_len / 8) {
+            *last &= (1u8 << (bit_len % 8)).wrapping_sub(1);
+        }
+        for byte in bytes.iter_mut().skip(bit_len / 8 + 1) {
+            *byte = 0;
+        }
+
+        let mut bools = Self::from_vec(bytes);
+        bools.bit_len = Some(bit_len);
+        Ok(bools)
+    }
+
+    /// Creates a new `BetterBoolInf` with exactly the given positions set.
+    ///
+    /// The logical length is the largest position + 1, and the store is rounded up to a whole
+    /// byte. Duplicate positions are allowed.
+    ///
+    /// # Arguments
+    /// * `positions` - The positions to set
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = BInf::from_set_positions([0, 9])?;
+    /// assert_eq!(bools.get_raw(), &vec![0b0000_0001, 0b0000_0010]);
+    /// assert_eq!(bools.logical_len(), 10);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `BBoolError::InvalidPosInf` if a position is too large to allocate
+    pub fn from_set_positions<I: IntoIterator<Item = usize>>(positions: I) -> Result<Self, BBoolError> {
+        let mut store = Vec::new();
+        let mut highest = None;
+        for pos in positions {
+            if pos / 8 >= store.len() {
+                store
+                    .try_reserve_exact(pos / 8 + 1 - store.len())
+                    .map_err(|_| BBoolError::InvalidPosInf(pos))?;
+                store.resize(pos / 8 + 1, 0);
+            }
+            store[pos / 8] |= 1 << (pos % 8);
+            highest = highest.max(Some(pos));
+        }
+        let mut bools = Self::from_vec(store);
+        bools.bit_len = highest.map(|pos| pos + 1);
+        Ok(bools)
+    }
+
+    /// Creates a new `BetterBoolInf` from a comma-separated list of positions and inclusive `a-b` ranges, such as `"0-3,7,10-12"`.
+    ///
+    /// Whitespace around each item is ignored, and an empty spec gives an empty collection.
+    ///
+    /// # Arguments
+    /// * `spec` - The positions and ranges to set
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = BInf::from_range_spec("0-3, 7, 9-10")?;
+    /// assert_eq!(bools.get_raw(), &vec![0b1000_1111, 0b0000_0110]);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * An item is empty or not a number or `a-b` range
+    /// * A range ends before it starts
+    /// * A range is too large to allocate
+    pub fn from_range_spec(spec: &str) -> Result<Self, BBoolError> {
+        let mut bools = Self::new();
+        bools.set_range_spec(spec)?;
+        Ok(bools)
+    }
+
+    /// Sets every position in a comma-separated list of positions and inclusive `a-b` ranges, such as `"0-3,7,10-12"`.
+    ///
+    /// The whole spec is checked before anything is set, and each range is filled in place
+    /// rather than position by position. Use `with_max_bits` to bound how far a spec from
+    /// untrusted input may grow the store.
+    ///
+    /// # Arguments
+    /// * `spec` - The positions and ranges to set
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::with_max_bits(16);
+    /// bools.set_range_spec("1, 4-6")?;
+    /// assert_eq!(bools.get_raw(), &vec![0b0111_0010]);
+    /// assert!(bools.set_range_spec("8-16").is_err());
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * An item is empty or not a number or `a-b` range
+    /// * A range ends before it starts
+    /// * A range ends past the maximum set with `with_max_bits`
+    /// * A range is past the end of the store under `SetPolicy::Error`
+    /// * A range is too large to allocate
+    pub fn set_range_spec(&mut self, spec: &str) -> Result<(), BBoolError> {
+        if spec.trim().is_empty() {
+            return Ok(());
+        }
+        let parse = |item: &str, part: &str| {
+            part.trim()
+                .parse::<usize>()
+                .map_err(|_| BBoolError::InvalidPattern(item.to_string()))
+        };
+        let mut ranges = Vec::new();
+        for item in spec.split(',') {
+            let (start, last) = if let Some((start, last)) = item.split_once('-') {
+                (parse(item, start)?, parse(item, last)?)
+            } else {
+                let pos = parse(item, item)?;
+                (pos, pos)
+            };
+            if last < start {
+                return Err(BBoolError::InvalidRange(start, last));
+            }
+            self.check_max_bits(last)?;
+            ranges.push((start, last));
+        }
+
+        let Some(&(_, highest)) = ranges.iter().max_by_key(|&&(_, last)| last) else {
+            return Ok(());
+        };
+        if !self.allow_growth(highest.saturating_add(1))? {
+            return Ok(());
+        }
+        let bytes = highest / 8 + 1;
+        if bytes > self.store.len() {
+            let start = ranges.iter().map(|&(start, _)| start).min().unwrap_or(highest);
+            self.store
+                .try_reserve_exact(bytes - self.store.len())
+                .map_err(|_| BBoolError::InvalidRange(start, highest))?;
+            self.store.resize(bytes, 0);
+        }
+        for (start, last) in ranges {
+            self.fill_ones(start, last);
+        }
+        self.extend_bit_len(highest.saturating_add(1));
+        self.refresh_fingerprint();
+        Ok(())
+    }
+
+    /// Sets every bit in `start..=last`, a whole byte at a time where possible.
+    fn fill_ones(&mut self, start: usize, last: usize) {
+        let (first_byte, last_byte) = (start / 8, last / 8);
+        if first_byte == last_byte {
+            for pos in start..=last {
+                self.store[pos / 8] |= self.bit_order.mask(pos % 8);
+            }
+            return;
+        }
+        for pos in start..(first_byte + 1) * 8 {
+            self.store[first_byte] |= self.bit_order.mask(pos % 8);
+        }
+        self.store[first_byte + 1..last_byte].fill(u8::MAX);
+        for pos in last_byte * 8..=last {
+            self.store[last_byte] |= self.bit_order.mask(pos % 8);
+        }
+    }
+
+    /// Parses a string of `0`s and `1`s, as produced by `to_bit_string`, with position 0 leftmost.
+    ///
+    /// The string's length becomes the logical length, so it need not be a whole number of bytes.
+    ///
+    /// # Arguments
+    /// * `s` - The bits to parse
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = BInf::from_bit_string("1011")?;
+    /// assert_eq!(bools.logical_len(), 4);
+    /// assert_eq!(bools.get_raw(), &vec![0b0000_1101]);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `BBoolError::InvalidPattern` if the string holds anything other than `0` and `1`
+    pub fn from_bit_string(s: &str) -> Result<Self, BBoolError> {
+        let mut bytes = vec![0u8; s.len().div_ceil(8)];
+        for (pos, c) in s.bytes().enumerate() {
+            match c {
+                b'0' => {}
+                b'1' => bytes[pos / 8] |= 1 << (pos % 8),
+                _ => return Err(BBoolError::InvalidPattern(s.to_string())),
+            }
+        }
+        Self::from_bytes_with_len(bytes, s.len())
+    }
+
+    /// Returns the logical bits as `0`s and `1`s, with position 0 leftmost.
+    ///
+    /// Unlike `Display`, which groups bits by byte, this has no separators, and it round-trips
+    /// through `from_bit_string` including a logical length that isn't a whole number of bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = BInf::from_bytes_with_len(vec![0b0000_0101, 0b1], 9)?;
+    /// assert_eq!(bools.to_bit_string(), "101000001");
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn to_bit_string(&self) -> String {
+        self.all()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|value| if value { '1' } else { '0' })
+            .collect()
+    }
+
+    /// Returns a Vec of all bools in the container, up to its logical length.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::from_vec(vec![5]);
+    /// let all_bools = bools.all()?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if accessing any position fails
+    pub fn all(&self) -> Result<Vec<bool>, BBoolError> {
+        // Multiply by 8 since each byte contains 8 bits
+        let mut out = Vec::with_capacity(self.store.len() * 8);
+        for &byte in &self.store {
+            for bit in 0..8 {
+                out.push(byte & self.bit_order.mask(bit) != 0);
+            }
+        }
+        out.truncate(self.logical_len());
+        Ok(out)
+    }
+
+    /// Consumes the container and returns an iterator over all bools, like `into_iter` but without panicking.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = BInf::from_vec(vec![0b0000_0101]);
+    /// let first_three: Vec<bool> = bools.try_into_iter()?.take(3).collect();
+    /// assert_eq!(first_three, vec![true, false, true]);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if accessing any position fails
+    pub fn try_into_iter(self) -> Result<std::vec::IntoIter<bool>, BBoolError> {
+        Ok(self.all()?.into_iter())
+    }
+
+    /// Returns a new `BetterBoolInf` that has been sorted.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::from_vec(vec![5]);
+    /// let sorted = bools.sorted()?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if sorting operation fails
+    pub fn sorted(&self) -> Result<Self, BBoolError> {
+        let mut bools = self.all()?;
+        bools.sort_unstable();
+
+        let mut sorted = Self::with_bit_order(self.bit_order);
+        for (i, &value) in bools.iter().enumerate() {
+            sorted.set_at_pos(i, value)?;
+        }
+        Ok(sorted)
+    }
+
+    /// Gets the bool at the current head position.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = BInf::from_vec(vec![5]);
+    /// let value = bools.get()?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if head position is invalid
+    pub fn get(&self) -> Result<bool, BBoolError> {
+        if self.reader_head_pos < Self::CAP {
+            let byte_index = self.reader_head_pos / 8;
+            let bit_offset = self.reader_head_pos % 8;
+
+            if byte_index >= self.store.len() {
+                return Ok(false); // Return false for unallocated positions
+            }
+
+            let mask = self.bit_order.mask(bit_offset);
+            return Ok((self.store[byte_index] & mask) != 0);
+        }
+        Err(BBoolError::InvalidHeadPosInf(self.reader_head_pos))
+    }
+
+    /// Gets the bool at the given position.
+    ///
+    /// # Arguments
+    /// * `pos` - The position to read from
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = BInf::from_vec(vec![5]);
     /// let value = bools.get_at_pos(2)?;
     /// Ok(())
     /// }
     /// ```
     ///
     /// # Errors
-    /// Returns an error if position is invalid
-    pub fn get_at_pos(&self, pos: usize) -> Result<bool, BBoolError> {
-        if pos < Self::CAP {
-            let byte_index = pos / 8;
-            let bit_offset = pos % 8;
+    /// Returns an error if position is invalid
+    pub fn get_at_pos(&self, pos: usize) -> Result<bool, BBoolError> {
+        if pos < Self::CAP {
+            let byte_index = pos / 8;
+            let bit_offset = pos % 8;
+
+            if byte_index >= self.store.len() {
+                return Ok(false); // Return false for unallocated positions
+            }
+
+            let mask = self.bit_order.mask(bit_offset);
+            return Ok((self.store[byte_index] & mask) != 0);
+        }
+        Err(BBoolError::InvalidPosInf(pos))
+    }
+
+    /// Gets the bool at the current head position without validity checks.
+    ///
+    /// # Safety
+    /// This function performs no bounds checking. The caller must ensure the head position is valid.
+    #[must_use]
+    pub unsafe fn get_unchecked(&self) -> bool {
+        let byte_index = self.reader_head_pos / 8;
+        let bit_offset = self.reader_head_pos % 8;
+
+        if byte_index >= self.store.len() {
+            return false;
+        }
+
+        let mask = self.bit_order.mask(bit_offset);
+        (self.store[byte_index] & mask) != 0
+    }
+
+    /// Gets the bool at the given position without validity checks.
+    ///
+    /// # Arguments
+    /// * `pos` - The position to read from
+    ///
+    /// # Safety
+    /// This function performs no bounds checking. the position is valid.
+    #[must_use]
+    pub unsafe fn get_unchecked_at_pos(&self, pos: usize) -> bool {
+        let byte_index = pos / 8;
+        let bit_offset = pos % 8;
+
+        if byte_index >= self.store.len() {
+            return false;
+        }
+
+        let mask = self.bit_order.mask(bit_offset);
+        (self.store[byte_index] & mask) != 0
+    }
+
+    /// Sets the bool at the current head position without validity checks.
+    ///
+    /// # Arguments
+    /// * `new` - The boolean value to set
+    ///
+    /// # Safety
+    /// This function performs no bounds checking. The caller must ensure the head position is valid.
+    pub unsafe fn set_unchecked(&mut self, new: bool) {
+        let byte_index = self.reader_head_pos / 8;
+        let bit_offset = self.reader_head_pos % 8;
+
+        if byte_index >= self.store.len() {
+            self.store.resize(byte_index + 1, 0);
+        }
+        self.extend_bit_len(self.reader_head_pos + 1);
+
+        let old = self.store[byte_index];
+        let mask = self.bit_order.mask(bit_offset);
+        if new {
+            self.store[byte_index] |= mask;
+        } else {
+            self.store[byte_index] &= !mask;
+        }
+        self.update_fingerprint(byte_index, old);
+    }
+
+    /// Sets the bool at the given position without validity checks.
+    ///
+    /// # Arguments
+    /// * `pos` - The position to set
+    /// * `new` - The boolean value to set
+    ///
+    /// # Safety
+    /// This function performs no bounds checking. The caller must ensure the position is valid.
+    pub unsafe fn set_unchecked_at_pos(&mut self, pos: usize, new: bool) {
+        let byte_index = pos / 8;
+        let bit_offset = pos % 8;
+
+        if byte_index >= self.store.len() {
+            self.store.resize(byte_index + 1, 0);
+        }
+        self.extend_bit_len(pos + 1);
+
+        let old = self.store[byte_index];
+        let mask = self.bit_order.mask(bit_offset);
+        if new {
+            self.store[byte_index] |= mask;
+        } else {
+            self.store[byte_index] &= !mask;
+        }
+        self.update_fingerprint(byte_index, old);
+    }
+
+    /// Get an immutable reference to the bools contained in a raw byte vector format.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let bools = BInf::from_vec(vec![5]);
+    /// let raw = bools.get_raw();
+    /// ```
+    #[must_use]
+    pub const fn get_raw(&self) -> &Vec<u8> {
+        &self.store
+    }
+
+    /// Get a mutable reference to the bools contained in a raw byte vector format.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let mut bools = BInf::from_vec(vec![5]);
+    /// let raw_mut = bools.get_raw_mut();
+    /// ```
+    pub fn get_raw_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.store
+    }
+
+    /// Sets the bool at the current head position.
+    ///
+    /// # Arguments
+    /// * `new` - The boolean value to set
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::new();
+    /// bools.set(true)?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if head position is invalid, or if it exceeds the configured maximum bits
+    /// Also returns an error past the end of the store under `SetPolicy::Error`
+    pub fn set(&mut self, new: bool) -> Result<(), BBoolError> {
+        if self.reader_head_pos < Self::CAP {
+            self.check_max_bits(self.reader_head_pos)?;
+            let byte_index = self.reader_head_pos / 8;
+            let bit_offset = self.reader_head_pos % 8;
+
+            // Extend the vector if necessary
+            if byte_index >= self.store.len() {
+                match self.policy {
+                    SetPolicy::Grow => self.store.resize(byte_index + 1, 0),
+                    SetPolicy::Error => return Err(BBoolError::InvalidHeadPosInf(self.reader_head_pos)),
+                    SetPolicy::Ignore => return Ok(()),
+                }
+            }
+            self.extend_bit_len(self.reader_head_pos + 1);
+
+            let old = self.store[byte_index];
+            let mask = self.bit_order.mask(bit_offset);
+            if new {
+                self.store[byte_index] |= mask;
+            } else {
+                self.store[byte_index] &= !mask;
+            }
+            self.update_fingerprint(byte_index, old);
+            return Ok(());
+        }
+        Err(BBoolError::InvalidHeadPosInf(self.reader_head_pos))
+    }
+
+    /// Sets the bool at the given position.
+    ///
+    /// # Arguments
+    /// * `pos` - The position to set
+    /// * `new` - The boolean value to set
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::new();
+    /// bools.set_at_pos(2, true)?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if position is invalid, or if it exceeds the configured maximum bits
+    /// Also returns an error past the end of the store under `SetPolicy::Error`
+    pub fn set_at_pos(&mut self, pos: usize, new: bool) -> Result<(), BBoolError> {
+        if pos < Self::CAP {
+            self.check_max_bits(pos)?;
+            let byte_index = pos / 8;
+            let bit_offset = pos % 8;
+
+            // Extend the vector if necessary
+            if byte_index >= self.store.len() {
+                match self.policy {
+                    SetPolicy::Grow => self.store.resize(byte_index + 1, 0),
+                    SetPolicy::Error => return Err(BBoolError::InvalidPosInf(pos)),
+                    SetPolicy::Ignore => return Ok(()),
+                }
+            }
+            self.extend_bit_len(pos + 1);
+
+            let old = self.store[byte_index];
+            let mask = self.bit_order.mask(bit_offset);
+            if new {
+                self.store[byte_index] |= mask;
+            } else {
+                self.store[byte_index] &= !mask;
+            }
+            self.update_fingerprint(byte_index, old);
+            return Ok(());
+        }
+        Err(BBoolError::InvalidPosInf(pos))
+    }
+
+    /// Gets the value at the current head position and increments the head position.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::from_vec(vec![5]);
+    /// let value = bools.next_b()?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Getting the current value fails
+    /// * Incrementing the head position fails
+    pub fn next_b(&mut self) -> Result<bool, BBoolError> {
+        let val = self.get()?;
+        self.inc()?;
+        Ok(val)
+    }
+
+    /// Gets the value at the current head position, wipes it, and increments the head position.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::from_vec(vec![5]);
+    /// let value = bools.next_b_res()?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Getting the current value fails
+    /// * Setting the value fails
+    /// * Incrementing the head position fails
+    pub fn next_b_res(&mut self) -> Result<bool, BBoolError> {
+        let val = self.get()?;
+        self.set(false)?;
+        self.inc()?;
+        Ok(val)
+    }
 
-            if byte_index >= self.store.len() {
-                return Ok(false); // Return false for unallocated positions
+    /// Reads `n` bits starting at the head position, least significant bit first, without moving the head.
+    ///
+    /// # Arguments
+    /// * `n` - The number of bits to read, at most 64
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::from_vec(vec![0b1011_0100]);
+    /// bools.shp(2)?;
+    /// assert_eq!(bools.peek_bits(4)?, 0b1101);
+    /// assert_eq!(*bools.ghp(), 2);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if `n` is greater than 64, or if reading any position fails
+    pub fn peek_bits(&self, n: usize) -> Result<u64, BBoolError> {
+        if n > 64 {
+            return Err(BBoolError::Other(format!("Can't read {n} bits into a u64")));
+        }
+        let mut value = 0;
+        for i in 0..n {
+            if self.get_at_pos(self.reader_head_pos + i)? {
+                value |= 1 << i;
             }
-
-            let mask = 1u8 << bit_offset;
-            return Ok((self.store[byte_index] & mask) != 0);
         }
-        Err(BBoolError::InvalidPosInf(pos))
+        Ok(value)
     }
 
-    /// Gets the bool at the current head position without validity checks.
+    /// Reads `n` bits starting at the head position, least significant bit first, and moves the head past them.
     ///
-    /// # Safety
-    /// This function performs no bounds checking. The caller must ensure the head position is valid.
-    #[must_use]
-    pub unsafe fn get_unchecked(&self) -> bool {
-        let byte_index = self.reader_head_pos / 8;
-        let bit_offset = self.reader_head_pos % 8;
+    /// # Arguments
+    /// * `n` - The number of bits to read, at most 64
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::from_vec(vec![0b1011_0100]);
+    /// assert_eq!(bools.read_bits(2)?, 0b00);
+    /// assert_eq!(bools.read_bits(4)?, 0b1101);
+    /// assert_eq!(*bools.ghp(), 6);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if `n` is greater than 64, or if reading any position or moving the head fails
+    pub fn read_bits(&mut self, n: usize) -> Result<u64, BBoolError> {
+        let value = self.peek_bits(n)?;
+        self.shp(self.reader_head_pos + n)?;
+        Ok(value)
+    }
 
-        if byte_index >= self.store.len() {
-            return false;
+    /// Writes the low `n` bits of `value` starting at the head position, least significant
+    /// bit first, and moves the head past them.
+    ///
+    /// # Arguments
+    /// * `value` - The bits to write
+    /// * `n` - The number of bits to write, at most 64
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::new();
+    /// bools.shp(2)?;
+    /// bools.write_bits(0b1101, 4)?;
+    /// assert_eq!(bools.get_raw(), &vec![0b0011_0100]);
+    /// assert_eq!(*bools.ghp(), 6);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if `n` is greater than 64, or if setting any position or moving the head fails
+    pub fn write_bits(&mut self, value: u64, n: usize) -> Result<(), BBoolError> {
+        if n > 64 {
+            return Err(BBoolError::Other(format!("Can't write {n} bits from a u64")));
+        }
+        for i in 0..n {
+            self.set_at_pos(self.reader_head_pos + i, (value >> i) & 1 == 1)?;
         }
+        self.shp(self.reader_head_pos + n)
+    }
 
-        let mask = 1u8 << bit_offset;
-        (self.store[byte_index] & mask) != 0
+    /// Increments the head position by 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::new();
+    /// bools.inc()?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the new head position would be invalid
+    pub fn inc(&mut self) -> Result<(), BBoolError> {
+        match self.reader_head_pos.checked_add(1) {
+            Some(next) if next < Self::CAP => {
+                self.reader_head_pos = next;
+                Ok(())
+            }
+            _ => Err(BBoolError::InvalidHeadPosInf(self.reader_head_pos)),
+        }
     }
 
-    /// Gets the bool at the given position without validity checks.
+    /// Increments the head position by 1 without validity checks.
+    ///
+    /// # Safety
+    /// This function performs no bounds checking. The caller must ensure the new head position will be valid.
+    pub unsafe fn inc_unchecked(&mut self) {
+        self.reader_head_pos += 1;
+    }
+
+    /// Sets the head position without validity checks.
     ///
     /// # Arguments
-    /// * `pos` - The position to read from
+    /// * `new` - The new head position
     ///
     /// # Safety
-    /// This function performs no bounds checking. the position is valid.
+    /// This function performs no bounds checking. The caller must ensure the new head position is valid.
+    pub unsafe fn shp_unchecked(&mut self, new: usize) {
+        self.reader_head_pos = new;
+    }
+
+    /// Sets the head position to a new value.
+    ///
+    /// # Arguments
+    /// * new - The new head position
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BInf::new();
+    /// bools.shp(42)?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the new head position would be invalid
+    pub fn shp(&mut self, new: usize) -> Result<(), BBoolError> {
+        if new < Self::CAP {
+            self.reader_head_pos = new;
+            return Ok(());
+        }
+        Err(BBoolError::InvalidHeadPosInf(new))
+    }
+
+    /// Gets an immutable reference to the current head position.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let bools = BInf::new();
+    /// let head_pos = bools.ghp();
+    /// ```
+    ///
     #[must_use]
-    pub unsafe fn get_unchecked_at_pos(&self, pos: usize) -> bool {
-        let byte_index = pos / 8;
-        let bit_offset = pos % 8;
+    pub const fn ghp(&self) -> &usize {
+        &self.reader_head_pos
+    }
 
-        if byte_index >= self.store.len() {
-            return false;
+    /// Returns a copy of the values with the head position reset to 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let mut bools = BInf::from_vec(vec![0b100]);
+    /// *bools.ghp_mut() = 5;
+    /// let copy = bools.clone_at_start();
+    /// assert_eq!(*copy.ghp(), 0);
+    /// assert_eq!(copy.get_raw(), &vec![0b100]);
+    /// ```
+    ///
+    #[must_use]
+    pub fn clone_at_start(&self) -> Self {
+        Self {
+            reader_head_pos: 0,
+            ..self.clone()
         }
+    }
 
-        let mask = 1u8 << bit_offset;
-        (self.store[byte_index] & mask) != 0
+    /// Returns a copy of the values that keeps the current head position.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let mut bools = BInf::from_vec(vec![0b100]);
+    /// *bools.ghp_mut() = 5;
+    /// assert_eq!(*bools.clone_preserving_head().ghp(), 5);
+    /// ```
+    ///
+    #[must_use]
+    pub fn clone_preserving_head(&self) -> Self {
+        self.clone()
     }
 
-    /// Sets the bool at the current head position without validity checks.
+    /// Gets a mutable reference to the current head position.
     ///
-    /// # Arguments
-    /// * `new` - The boolean value to set
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let mut bools = BInf::new();
+    /// let head_pos_mut = bools.ghp_mut();
+    /// ```
     ///
-    /// # Safety
-    /// This function performs no bounds checking. The caller must ensure the head position is valid.
-    pub unsafe fn set_unchecked(&mut self, new: bool) {
-        let byte_index = self.reader_head_pos / 8;
-        let bit_offset = self.reader_head_pos % 8;
+    pub fn ghp_mut(&mut self) -> &mut usize {
+        &mut self.reader_head_pos
+    }
+
+    /// Clears all stored boolean values.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let mut bools = BInf::from_vec(vec![5]);
+    /// bools.clear();
+    /// ```
+    ///
+    pub fn clear(&mut self) {
+        self.store.clear();
+        self.bit_len = None;
+        self.refresh_fingerprint();
+    }
+
+    /// Returns whether every bool is `false`. An empty store counts as all `false`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// assert!(BInf::from_vec(vec![0, 0]).is_all_false());
+    /// assert!(!BInf::from_vec(vec![0, 1]).is_all_false());
+    /// ```
+    #[must_use]
+    pub fn is_all_false(&self) -> bool {
+        self.store.iter().all(|&byte| byte == 0)
+    }
+
+    /// Returns whether every bool is `true`. An empty store counts as all `true`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// assert!(BInf::from_vec(vec![0xFF, 0xFF]).is_all_true());
+    /// assert!(!BInf::from_vec(vec![0xFF, 0xFE]).is_all_true());
+    /// ```
+    #[must_use]
+    pub fn is_all_true(&self) -> bool {
+        self.all().is_ok_and(|bools| bools.iter().all(|&value| value))
+    }
+
+    /// Returns the number of maximal runs of identical bits, over the logical bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// // Bits from position 0: 1010 0000
+    /// assert_eq!(BInf::from_vec(vec![0b0000_0101]).count_runs(), 4);
+    /// assert_eq!(BInf::new().count_runs(), 0);
+    /// ```
+    #[must_use]
+    pub fn count_runs(&self) -> usize {
+        let len = self.logical_len();
+        let mut bits = self
+            .store
+            .iter()
+            .flat_map(|&byte| (0..8).map(move |i| byte & self.bit_order.mask(i) != 0))
+            .take(len);
+        let Some(mut prev) = bits.next() else {
+            return 0;
+        };
 
-        while byte_index >= self.store.len() {
-            self.store.push(0);
+        let mut runs = 1;
+        for bit in bits {
+            if bit != prev {
+                runs += 1;
+                prev = bit;
+            }
         }
+        runs
+    }
 
-        let mask = 1u8 << bit_offset;
-        if new {
-            self.store[byte_index] |= mask;
-        } else {
-            self.store[byte_index] &= !mask;
+    /// Returns the first position where the bits match `pattern` exactly, or `None` if there is none.
+    ///
+    /// An empty pattern matches at position 0.
+    ///
+    /// # Arguments
+    /// * `pattern` - The bits to look for, starting from the lowest position
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// // Bits from position 0: 0101 1100
+    /// let bools = BInf::from_vec(vec![0b0011_1010]);
+    /// assert_eq!(bools.find_window(&[true, true, true]), Some(3));
+    /// assert_eq!(bools.find_window(&[true; 4]), None);
+    /// ```
+    #[must_use]
+    pub fn find_window(&self, pattern: &[bool]) -> Option<usize> {
+        if pattern.is_empty() {
+            return Some(0);
         }
+        let bools = self.all().ok()?;
+        bools.windows(pattern.len()).position(|window| window == pattern)
     }
 
-    /// Sets the bool at the given position without validity checks.
+    /// Returns how many bits were set and cleared going from `previous` to `self`, as `(bits_set, bits_cleared)`.
+    ///
+    /// The shorter of the two is treated as if it were padded with `false`.
     ///
     /// # Arguments
-    /// * `pos` - The position to set
-    /// * `new` - The boolean value to set
+    /// * `previous` - The earlier state to compare against
     ///
-    /// # Safety
-    /// This function performs no bounds checking. The caller must ensure the position is valid.
-    pub unsafe fn set_unchecked_at_pos(&mut self, pos: usize, new: bool) {
-        let byte_index = pos / 8;
-        let bit_offset = pos % 8;
-
-        while byte_index >= self.store.len() {
-            self.store.push(0);
-        }
-
-        let mask = 1u8 << bit_offset;
-        if new {
-            self.store[byte_index] |= mask;
-        } else {
-            self.store[byte_index] &= !mask;
-        }
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let before = BInf::from_vec(vec![0b0000_0011]);
+    /// let after = BInf::from_vec(vec![0b0000_0110, 0b0000_0001]);
+    /// assert_eq!(after.changes_since(&before), (2, 1));
+    /// ```
+    #[must_use]
+    pub fn changes_since(&self, previous: &Self) -> (usize, usize) {
+        let len = self.store.len().max(previous.store.len());
+        (0..len).fold((0, 0), |(set, cleared), i| {
+            let now = self.bit_order.normalize(self.store.get(i).copied().unwrap_or(0));
+            let before = previous
+                .bit_order
+                .normalize(previous.store.get(i).copied().unwrap_or(0));
+            (
+                set + (now & !before).count_ones() as usize,
+                cleared + (before & !now).count_ones() as usize,
+            )
+        })
     }
 
-    /// Get an immutable reference to the bools contained in a raw byte vector format.
+    /// Returns whether any position is set in both `self` and `other`.
+    ///
+    /// Compares a byte at a time, stopping at the first overlapping byte.
+    ///
+    /// # Arguments
+    /// * `other` - The mask to check against
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
-    /// let bools = BInf::from_vec(vec![5]);
-    /// let raw = bools.get_raw();
+    /// let morning = BInf::from_vec(vec![0b0000_1111]);
+    /// let evening = BInf::from_vec(vec![0b1111_0000]);
+    /// assert!(!morning.overlaps(&evening));
+    /// assert!(morning.overlaps(&BInf::from_vec(vec![0b0000_1000])));
     /// ```
     #[must_use]
-    pub const fn get_raw(&self) -> &Vec<u8> {
-        &self.store
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.store
+            .iter()
+            .zip(&other.store)
+            .any(|(&a, &b)| self.bit_order.normalize(a) & other.bit_order.normalize(b) != 0)
     }
 
-    /// Get a mutable reference to the bools contained in a raw byte vector format.
+    /// Returns the positions whose bits differ in `other`, with `other`'s value at each, in ascending order.
+    ///
+    /// Applying the result to `self` with `apply_patch` makes its bits match `other`'s. The
+    /// shorter of the two is treated as if it were padded with `false`.
+    ///
+    /// # Arguments
+    /// * `other` - The state to diff towards
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
-    /// let mut bools = BInf::from_vec(vec![5]);
-    /// let raw_mut = bools.get_raw_mut();
+    /// let before = BInf::from_vec(vec![0b0000_0011]);
+    /// let after = BInf::from_vec(vec![0b0000_0110, 0b0000_0001]);
+    /// assert_eq!(before.diff_patch(&after), vec![(0, false), (2, true), (8, true)]);
     /// ```
-    pub fn get_raw_mut(&mut self) -> &mut Vec<u8> {
-        &mut self.store
+    #[must_use]
+    pub fn diff_patch(&self, other: &Self) -> Vec<(usize, bool)> {
+        let len = self.store.len().max(other.store.len());
+        let mut patch = Vec::new();
+        for i in 0..len {
+            let ours = self.bit_order.normalize(self.store.get(i).copied().unwrap_or(0));
+            let theirs = other.bit_order.normalize(other.store.get(i).copied().unwrap_or(0));
+            let changed = ours ^ theirs;
+            for bit in (0..8).filter(|bit| (changed >> bit) & 1 == 1) {
+                patch.push((i * 8 + bit, (theirs >> bit) & 1 == 1));
+            }
+        }
+        patch
     }
 
-    /// Sets the bool at the current head position.
+    /// Applies a patch from `diff_patch`, setting each listed position to its value.
     ///
     /// # Arguments
-    /// * `new` - The boolean value to set
+    /// * `patch` - The `(position, value)` pairs to set
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
-    /// let mut bools = BInf::new();
-    /// bools.set(true)?;
+    /// let mut bools = BInf::from_vec(vec![0b0000_0011]);
+    /// bools.apply_patch(&[(0, false), (2, true)])?;
+    /// assert_eq!(bools.get_raw(), &vec![0b0000_0110]);
     /// Ok(())
     /// }
     /// ```
     ///
     /// # Errors
-    /// Returns an error if head position is invalid
-    pub fn set(&mut self, new: bool) -> Result<(), BBoolError> {
-        if self.reader_head_pos < Self::CAP {
-            let byte_index = self.reader_head_pos / 8;
-            let bit_offset = self.reader_head_pos % 8;
-
-            // Extend the vector if necessary
-            while byte_index >= self.store.len() {
-                self.store.push(0);
-            }
+    /// Returns an error if setting any position fails, leaving the earlier positions set
+    pub fn apply_patch(&mut self, patch: &[(usize, bool)]) -> Result<(), BBoolError> {
+        for &(pos, value) in patch {
+            self.set_at_pos(pos, value)?;
+        }
+        Ok(())
+    }
 
-            let mask = 1u8 << bit_offset;
-            if new {
-                self.store[byte_index] |= mask;
-            } else {
-                self.store[byte_index] &= !mask;
+    /// Rewrites every logical bit with the result of `f`, called with its position and current value.
+    ///
+    /// Only the bits currently in the store are visited; the store is never grown.
+    ///
+    /// # Arguments
+    /// * `f` - Closure returning the new value for each position
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let mut bools = BInf::from_vec(vec![0b1111_1111]);
+    /// bools.map_bits(|pos, value| value && pos % 2 == 1);
+    /// assert_eq!(bools.get_raw(), &vec![0b1010_1010]);
+    /// ```
+    pub fn map_bits<F: FnMut(usize, bool) -> bool>(&mut self, mut f: F) {
+        let len = self.logical_len();
+        let order = self.bit_order;
+        for (i, byte) in self.store.iter_mut().enumerate() {
+            let mut mapped = 0u8;
+            for bit in (0..8).take_while(|bit| i * 8 + bit < len) {
+                if f(i * 8 + bit, *byte & order.mask(bit) != 0) {
+                    mapped |= order.mask(bit);
+                }
             }
-            return Ok(());
+            *byte = mapped;
         }
-        Err(BBoolError::InvalidHeadPosInf(self.reader_head_pos))
+        self.refresh_fingerprint();
     }
 
-    /// Sets the bool at the given position.
+    /// Rotates the bits towards higher positions by `n`, within the logical length.
+    ///
+    /// Bits moved past `logical_len` wrap around to the lowest positions, like `u8::rotate_left`.
+    /// `n` may exceed the logical length, and only `n % logical_len` matters.
     ///
     /// # Arguments
-    /// * `pos` - The position to set
-    /// * `new` - The boolean value to set
+    /// * `n` - The number of positions to rotate by
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
-    /// let mut bools = BInf::new();
-    /// bools.set_at_pos(2, true)?;
+    /// let mut bools = BInf::from_bytes_with_len(vec![0b0000_0011, 0b10], 10)?;
+    /// bools.rotate_left(1);
+    /// assert_eq!(bools.get_raw(), &vec![0b0000_0111, 0b00]);
     /// Ok(())
     /// }
     /// ```
-    ///
-    /// # Errors
-    /// Returns an error if position is invalid
-    pub fn set_at_pos(&mut self, pos: usize, new: bool) -> Result<(), BBoolError> {
-        if pos < Self::CAP {
-            let byte_index = pos / 8;
-            let bit_offset = pos % 8;
-
-            // Extend the vector if necessary
-            while byte_index >= self.store.len() {
-                self.store.push(0);
-            }
-
-            let mask = 1u8 << bit_offset;
-            if new {
-                self.store[byte_index] |= mask;
+    pub fn rotate_left(&mut self, n: usize) {
+        let len = self.logical_len();
+        let shift = n.checked_rem(len).unwrap_or(0);
+        if shift == 0 {
+            return;
+        }
+        let old = self.store.clone();
+        let order = self.bit_order;
+        for pos in 0..len {
+            let from = (pos + len - shift) % len;
+            let mask = order.mask(pos % 8);
+            if old[from / 8] & order.mask(from % 8) != 0 {
+                self.store[pos / 8] |= mask;
             } else {
-                self.store[byte_index] &= !mask;
+                self.store[pos / 8] &= !mask;
             }
-            return Ok(());
         }
-        Err(BBoolError::InvalidPosInf(pos))
+        self.refresh_fingerprint();
     }
 
-    /// Gets the value at the current head position and increments the head position.
+    /// Rotates the bits towards lower positions by `n`, within the logical length.
+    ///
+    /// Bits moved below position 0 wrap around to the highest logical positions, like
+    /// `u8::rotate_right`. `n` may exceed the logical length, and only `n % logical_len` matters.
+    ///
+    /// # Arguments
+    /// * `n` - The number of positions to rotate by
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
-    /// let mut bools = BInf::from_vec(vec![5]);
-    /// let value = bools.next_b()?;
+    /// let mut bools = BInf::from_bytes_with_len(vec![0b0000_0011], 4)?;
+    /// bools.rotate_right(1);
+    /// assert_eq!(bools.get_raw(), &vec![0b0000_1001]);
     /// Ok(())
     /// }
     /// ```
-    ///
-    /// # Errors
-    /// Returns an error if:
-    /// * Getting the current value fails
-    /// * Incrementing the head position fails
-    pub fn next_b(&mut self) -> Result<bool, BBoolError> {
-        let val = self.get()?;
-        self.inc()?;
-        Ok(val)
+    pub fn rotate_right(&mut self, n: usize) {
+        let len = self.logical_len();
+        if len > 0 {
+            self.rotate_left(len - n % len);
+        }
     }
 
-    /// Gets the value at the current head position, wipes it, and increments the head position.
+    /// Returns a copy where each logical position is set if it or any lower position is set.
+    ///
+    /// Once a bit turns on, every higher position up to `logical_len` stays on.
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
-    /// use anyhow::Result;
-    /// fn main() -> Result<()> {
-    /// let mut bools = BInf::from_vec(vec![5]);
-    /// let value = bools.next_b_res()?;
-    /// Ok(())
-    /// }
+    /// let bools = BInf::from_vec(vec![0b0000_0100, 0]);
+    /// assert_eq!(bools.prefix_or().get_raw(), &vec![0b1111_1100, 0b1111_1111]);
     /// ```
-    ///
-    /// # Errors
-    /// Returns an error if:
-    /// * Getting the current value fails
-    /// * Setting the value fails
-    /// * Incrementing the head position fails
-    pub fn next_b_res(&mut self) -> Result<bool, BBoolError> {
-        let val = self.get()?;
-        self.set(false)?;
-        self.inc()?;
-        Ok(val)
+    #[must_use]
+    pub fn prefix_or(&self) -> Self {
+        let mut result = self.clone();
+        let mut seen = false;
+        result.map_bits(|_, value| {
+            seen |= value;
+            seen
+        });
+        result
     }
 
-    /// Increments the head position by 1.
+    /// Returns the number of set bits in positions `[0, pos)`.
+    ///
+    /// Positions past the end of the store count as unset.
+    ///
+    /// # Arguments
+    /// * `pos` - The exclusive end of the counted range
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
-    /// use anyhow::Result;
-    /// fn main() -> Result<()> {
-    /// let mut bools = BInf::new();
-    /// bools.inc()?;
-    /// Ok(())
-    /// }
+    /// let bools = BInf::from_vec(vec![0b1011_0110, 0b0000_0001]);
+    /// assert_eq!(bools.rank(3), 2);
+    /// assert_eq!(bools.rank(9), 6);
+    /// assert_eq!(bools.rank(1000), 6);
     /// ```
-    ///
-    /// # Errors
-    /// Returns an error if the new head position would be invalid
-    pub fn inc(&mut self) -> Result<(), BBoolError> {
-        if self.reader_head_pos + 1 < Self::CAP {
-            self.reader_head_pos += 1;
-            return Ok(());
+    #[must_use]
+    pub fn rank(&self, pos: usize) -> usize {
+        let full_bytes = (pos / 8).min(self.store.len());
+        let mut count: usize = self.store[..full_bytes]
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum();
+        if let Some(&byte) = self.store.get(pos / 8) {
+            let byte = self.bit_order.normalize(byte);
+            let partial_mask = (1u16 << (pos % 8)) - 1;
+            count += (u16::from(byte) & partial_mask).count_ones() as usize;
         }
-        Err(BBoolError::InvalidHeadPosInf(self.reader_head_pos))
+        count
     }
 
-    /// Increments the head position by 1 without validity checks.
+    /// Returns the position of the `n`-th set bit (0-indexed), or `None` if fewer than `n + 1` bits are set.
     ///
-    /// # Safety
-    /// This function performs no bounds checking. The caller must ensure the new head position will be valid.
-    pub unsafe fn inc_unchecked(&mut self) {
-        self.reader_head_pos += 1;
+    /// # Arguments
+    /// * `n` - Which set bit to find
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let bools = BInf::from_vec(vec![0b1011_0110, 0b0000_0001]);
+    /// assert_eq!(bools.select(0), Some(1));
+    /// assert_eq!(bools.select(5), Some(8));
+    /// assert_eq!(bools.select(6), None);
+    /// ```
+    #[must_use]
+    pub fn select(&self, n: usize) -> Option<usize> {
+        let mut remaining = n;
+        for (i, &byte) in self.store.iter().enumerate() {
+            let ones = byte.count_ones() as usize;
+            if remaining < ones {
+                let byte = self.bit_order.normalize(byte);
+                let bit = (0..8)
+                    .filter(|bit| (byte >> bit) & 1 == 1)
+                    .nth(remaining)?;
+                return Some(i * 8 + bit);
+            }
+            remaining -= ones;
+        }
+        None
     }
 
-    /// Sets the head position without validity checks.
+    /// Returns the CRC-32 (IEEE) checksum of the raw byte store.
     ///
-    /// # Arguments
-    /// * `new` - The new head position
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// assert_eq!(BInf::from_vec(b"123456789".to_vec()).checksum(), 0xCBF4_3926);
+    /// assert_eq!(BInf::new().checksum(), 0);
+    /// ```
+    #[must_use]
+    pub fn checksum(&self) -> u32 {
+        let mut crc = u32::MAX;
+        for &byte in &self.store {
+            crc ^= u32::from(byte);
+            for _ in 0..8 {
+                let mask = (crc & 1).wrapping_neg();
+                crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+            }
+        }
+        !crc
+    }
+
+    /// Serializes the raw byte store followed by its little-endian `checksum`, for
+    /// reading back with `read_with_checksum`.
     ///
-    /// # Safety
-    /// This function performs no bounds checking. The caller must ensure the new head position is valid.
-    pub unsafe fn shp_unchecked(&mut self, new: usize) {
-        self.reader_head_pos = new;
+    /// # Examples
+    /// ```
+    /// use btypes::inf_bbool::BInf;
+    /// let bytes = BInf::from_vec(vec![5]).write_with_checksum();
+    /// assert_eq!(bytes.len(), 5);
+    /// assert_eq!(bytes[0], 5);
+    /// ```
+    #[must_use]
+    pub fn write_with_checksum(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.store.len() + 4);
+        out.extend_from_slice(&self.store);
+        out.extend_from_slice(&self.checksum().to_le_bytes());
+        out
     }
 
-    /// Sets the head position to a new value.
+    /// Reads bytes written by `write_with_checksum`, verifying the trailing checksum.
     ///
     /// # Arguments
-    /// * new - The new head position
+    /// * `bytes` - The raw byte store followed by its little-endian checksum
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
-    /// let mut bools = BInf::new();
-    /// bools.shp(42)?;
+    /// let bytes = BInf::from_vec(vec![5, 42]).write_with_checksum();
+    /// assert_eq!(BInf::read_with_checksum(&bytes)?.get_raw(), &vec![5, 42]);
     /// Ok(())
     /// }
     /// ```
     ///
     /// # Errors
-    /// Returns an error if the new head position would be invalid
-    pub fn shp(&mut self, new: usize) -> Result<(), BBoolError> {
-        if new < Self::CAP {
-            self.reader_head_pos = new;
-            return Ok(());
+    /// Returns an error if `bytes` is too short to hold a checksum, or if the checksum
+    /// doesn't match the data
+    pub fn read_with_checksum(bytes: &[u8]) -> Result<Self, BBoolError> {
+        let Some(split) = bytes.len().checked_sub(4) else {
+            return Err(BBoolError::Other(
+                "Data is too short to contain a checksum".to_string(),
+            ));
+        };
+        let (data, stored) = bytes.split_at(split);
+        let stored = u32::from_le_bytes([stored[0], stored[1], stored[2], stored[3]]);
+
+        let bools = Self::from_vec(data.to_vec());
+        let actual = bools.checksum();
+        if actual != stored {
+            return Err(BBoolError::Other(format!(
+                "Checksum mismatch: expected {stored:#010x}, got {actual:#010x}"
+            )));
         }
-        Err(BBoolError::InvalidHeadPosInf(new))
+        Ok(bools)
     }
 
-    /// Gets an immutable reference to the current head position.
+    /// Renders the bits as rows of `width` characters, `#` for `true` and `.` for `false`.
+    ///
+    /// Rows are separated by `\n`, with no trailing newline; the last row may be shorter.
+    ///
+    /// # Arguments
+    /// * `width` - The number of bits per row
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
-    /// let bools = BInf::new();
-    /// let head_pos = bools.ghp();
+    /// let bools = BInf::from_vec(vec![0b1010_0101]);
+    /// assert_eq!(bools.to_grid(4).unwrap(), "#.#.\n.#.#");
     /// ```
     ///
-    #[must_use]
-    pub const fn ghp(&self) -> &usize {
-        &self.reader_head_pos
+    /// # Errors
+    /// Returns an error if `width` is 0
+    pub fn to_grid(&self, width: usize) -> Result<String, BBoolError> {
+        if width == 0 {
+            return Err(BBoolError::Other("Grid width must be greater than 0".to_string()));
+        }
+        let bools = self.all()?;
+        let rows: Vec<String> = bools
+            .chunks(width)
+            .map(|row| row.iter().map(|&b| if b { '#' } else { '.' }).collect())
+            .collect();
+        Ok(rows.join("\n"))
     }
+}
 
-    /// Gets a mutable reference to the current head position.
+/// A fluent builder for `BetterBoolInf`, created with `BetterBoolInf::builder`.
+///
+/// Positions are applied in order, so a later `clear` undoes an earlier `set`. Like
+/// `set_at_pos`, every touched position grows the store, even when cleared.
+///
+/// # Examples
+/// ```
+/// use btypes::inf_bbool::BInf;
+/// let bools = BInf::builder().set(3).set(7).clear(3).build();
+/// assert_eq!(bools.get_raw(), &vec![0b1000_0000]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BInfBuilder {
+    /// The bools built so far
+    bools: BetterBoolInf,
+}
+
+impl BInfBuilder {
+    /// Sets the bool at `pos` to `value`. `BetterBoolInf::CAP` itself is the only invalid
+    /// position for an unlimited store, and is ignored.
+    fn with(mut self, pos: usize, value: bool) -> Self {
+        let _ = self.bools.set_at_pos(pos, value);
+        self
+    }
+
+    /// Sets the bool at `pos` to `true`.
     ///
-    /// # Examples
-    /// ```
-    /// use btypes::inf_bbool::BInf;
-    /// let mut bools = BInf::new();
-    /// let head_pos_mut = bools.ghp_mut();
-    /// ```
+    /// # Arguments
+    /// * `pos` - The position to set
+    #[must_use]
+    pub fn set(self, pos: usize) -> Self {
+        self.with(pos, true)
+    }
+
+    /// Sets the bool at `pos` to `false`.
     ///
-    pub fn ghp_mut(&mut self) -> &mut usize {
-        &mut self.reader_head_pos
+    /// # Arguments
+    /// * `pos` - The position to clear
+    #[must_use]
+    pub fn clear(self, pos: usize) -> Self {
+        self.with(pos, false)
     }
 
-    /// Clears all stored boolean values.
+    /// Sets every bool within the range [start, end) to `true`. An empty or reversed range does nothing.
+    ///
+    /// # Arguments
+    /// * `start` - The starting position (inclusive)
+    /// * `end` - The ending position (exclusive)
     ///
     /// # Examples
     /// ```
     /// use btypes::inf_bbool::BInf;
-    /// let mut bools = BInf::from_vec(vec![5]);
-    /// bools.clear();
+    /// let bools = BInf::builder().set_range(2, 10).build();
+    /// assert_eq!(bools.get_raw(), &vec![0b1111_1100, 0b0000_0011]);
     /// ```
-    ///
-    pub fn clear(&mut self) {
-        self.store.clear();
+    #[must_use]
+    pub fn set_range(self, start: usize, end: usize) -> Self {
+        (start..end).fold(self, Self::set)
+    }
+
+    /// Finishes building and returns the bools.
+    #[must_use]
+    pub fn build(self) -> BetterBoolInf {
+        self.bools
     }
 }
 
+/// Formats the bools as a string of `0`s and `1`s from position 0 upward, with a space
+/// between each byte.
 impl Display for BetterBoolInf {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:#?}", self.all())
+        let bools = self.all().unwrap_or_default();
+        for (i, chunk) in bools.chunks(8).enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            for &value in chunk {
+                f.write_str(if value { "1" } else { "0" })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// The inherent methods take precedence over the trait's, so these calls don't recurse.
+impl BoolCollection for BetterBoolInf {
+    type Error = BBoolError;
+
+    fn get_at_pos(&self, pos: usize) -> Result<bool, BBoolError> {
+        Self::get_at_pos(self, pos)
+    }
+
+    fn set_at_pos(&mut self, pos: usize, value: bool) -> Result<(), BBoolError> {
+        Self::set_at_pos(self, pos, value)
+    }
+
+    fn all(&self) -> Result<Vec<bool>, BBoolError> {
+        Self::all(self)
+    }
+
+    fn clear(&mut self) {
+        Self::clear(self);
     }
 }
 
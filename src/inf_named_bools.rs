@@ -1,14 +1,41 @@
 use crate::error::BBoolError;
 use crate::inf_bbool::BInf;
-use crate::inf_bbool::BetterBoolInf;
+#[cfg(feature = "named_bools")]
+use crate::named_bools::BetterBoolNamed;
+#[cfg(feature = "named_bools")]
+use crate::traits::BitwiseOpsCopy;
+use crate::inf_bbool::{BetterBoolInf, BitOrder, SetPolicy};
+use crate::traits::BoolCollection;
 use anyhow::Error;
 use anyhow::Result;
-use std::fmt::Display;
-use std::{collections::HashMap, marker::PhantomData};
+use std::cmp::Ordering;
+use std::fmt::{Display, Write};
+#[cfg(feature = "interned_names")]
+use std::sync::Arc;
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    marker::PhantomData,
+    ops::Bound,
+};
 
 /// Type alias for the infinite-capacity named boolean collection
 pub type BNInf = BetterBoolNamedInf;
 
+/// The key type of the name-to-position mapping
+///
+/// This is `String` by default. With the `interned_names` feature it is `Arc<str>`, so the
+/// mapping and the sorted name index share a single allocation per name instead of each
+/// owning a copy.
+#[cfg(not(feature = "interned_names"))]
+pub type NameKey = String;
+/// The key type of the name-to-position mapping
+///
+/// This is `String` by default. With the `interned_names` feature it is `Arc<str>`, so the
+/// mapping and the sorted name index share a single allocation per name instead of each
+/// owning a copy.
+#[cfg(feature = "interned_names")]
+pub type NameKey = Arc<str>;
+
 /// A dynamically-sized collection of named boolean values
 ///
 /// This struct combines the unlimited capacity of `BetterBoolInf` with
@@ -18,7 +45,9 @@ pub struct BetterBoolNamedInf {
     /// The underlying boolean storage
     pub bools: BetterBoolInf,
     /// Mapping of names to boolean positions
-    names: HashMap<String, usize>,
+    names: HashMap<NameKey, usize>,
+    /// Sorted index of the names, used for prefix lookups
+    pub(crate) index: BTreeSet<NameKey>,
     /// Next available position for new boolean values
     next_assign: usize,
 }
@@ -40,11 +69,17 @@ impl BetterBoolNamedInf {
         let bools = BetterBoolInf {
             store: initial_value,
             reader_head_pos: 0,
+            max_bits: None,
+            bit_len: None,
+            fingerprint: None,
+            policy: SetPolicy::Grow,
+            bit_order: BitOrder::Lsb0,
             _marker: PhantomData,
         };
         Self {
             bools,
             names: HashMap::new(),
+            index: BTreeSet::new(),
             next_assign: 0,
         }
     }
@@ -76,6 +111,7 @@ impl BetterBoolNamedInf {
         {
             bools: BInf::with_cap(cap),
             names: HashMap::new(),
+            index: BTreeSet::new(),
             next_assign: 0,
         }
     }
@@ -117,6 +153,8 @@ impl BetterBoolNamedInf {
     /// * The value pattern doesn't contain {r} and the count of bools in it doesn't match or exceed the count.
     /// * The value pattern contains invalid boolean values
     /// * Adding the bools would exceed capacity
+    ///
+    /// All of these are checked before anything is set, so on error the collection is left unchanged.
     pub fn mass_set(
         &mut self,
         count: usize,
@@ -136,7 +174,6 @@ impl BetterBoolNamedInf {
             ));
         }
         if !value_pattern.contains("{r}") && value_parts.len() < count {
-            println!("{}, {}", !value_parts.contains(&"{r}"), value_parts.len());
             return Err(BBoolError::InvalidPattern(
                 "Value pattern must be able to fill all set bools".to_string(),
             ));
@@ -153,6 +190,9 @@ impl BetterBoolNamedInf {
             })
             .collect::<Result<Vec<bool>>>()?;
 
+        // Validate capacity up front so a failure leaves the collection unchanged
+        self.check_mass_capacity(count, pattern)?;
+
         for i in 0..count {
             let name = pattern.replace("{n}", &i.to_string());
             let value_index = if repeating {
@@ -173,6 +213,92 @@ impl BetterBoolNamedInf {
         Ok(())
     }
 
+    /// Like `mass_set`, but without leniency about the length of a non-repeating value pattern.
+    ///
+    /// If the value pattern doesn't contain {r}, it must list exactly `count` values; `mass_set` would
+    /// silently ignore any extras.
+    ///
+    /// # Arguments
+    /// * `count` - Number of bools to set/add
+    /// * `pattern` - Name pattern containing {n} which will be replaced with sequential numbers (0 to count-1)
+    /// * `value_pattern` - Comma-separated list of boolean values with optional {r} suffix to repeat the pattern
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// fn main() -> anyhow::Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.mass_set_strict(3, "val_{n}", "true,false,true")?;
+    /// assert!(bools.mass_set_strict(2, "val_{n}", "true,false,true").is_err());
+    /// // Repeating patterns are unaffected
+    /// bools.mass_set_strict(4, "rep_{n}", "true,false{r}")?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `BBoolError::InvalidPattern` if the value pattern doesn't contain {r} and its length isn't
+    /// exactly `count`, along with every error `mass_set` can return.
+    pub fn mass_set_strict(
+        &mut self,
+        count: usize,
+        pattern: &str,
+        value_pattern: &str,
+    ) -> Result<(), BBoolError> {
+        let value_count = value_pattern.trim().split(',').count();
+        if !value_pattern.contains("{r}") && value_count != count {
+            return Err(BBoolError::InvalidPattern(format!(
+                "Value pattern has {value_count} values, expected exactly {count}"
+            )));
+        }
+        self.mass_set(count, pattern, value_pattern)
+    }
+
+    /// Set/add many named bools, with the names being dictated by the pattern and the values computed by a closure.
+    ///
+    /// # Arguments
+    /// * count - Number of bools to set/add
+    /// * pattern - Name pattern containing {n} which will be replaced with sequential numbers (0 to count-1)
+    /// * f - Closure called with each index, returning the value for that bool
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// fn main() -> anyhow::Result<()> {
+    /// let mut bools = BNInf::new();
+    /// // Creates bool_0=true, bool_1=false, bool_2=true, bool_3=false
+    /// bools.mass_set_fn(4, "bool_{n}", |n| n % 2 == 0)?;
+    /// assert!(!bools.get("bool_3")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The pattern doesn't contain {n}
+    /// * Adding the bools would exceed capacity
+    pub fn mass_set_fn<F: FnMut(u128) -> bool>(
+        &mut self,
+        count: usize,
+        pattern: &str,
+        mut f: F,
+    ) -> Result<(), BBoolError> {
+        if !pattern.contains("{n}") {
+            return Err(BBoolError::InvalidPattern(
+                "Pattern must contain {n}".to_string(),
+            ));
+        }
+
+        self.check_mass_capacity(count, pattern)?;
+
+        for i in 0..count {
+            let name = pattern.replace("{n}", &i.to_string());
+            self.set(&name, f(i as u128))?;
+        }
+
+        Ok(())
+    }
+
     /// Gets multiple boolean values associated with the given names.
     ///
     /// # Arguments
@@ -203,6 +329,37 @@ impl BetterBoolNamedInf {
         Ok(out)
     }
 
+    /// Gets multiple boolean values associated with the given names, keyed by name.
+    ///
+    /// # Arguments
+    /// * names - A slice of string slices containing the names to retrieve
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("test1", true)?;
+    /// bools.add("test2", false)?;
+    /// let values = bools.mass_get_map(&["test1", "test2"])?;
+    /// assert!(values["test1"]);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Any of the names don't exist in the collection
+    /// * Retrieving any value fails
+    pub fn mass_get_map(&self, names: &[&str]) -> Result<HashMap<String, bool>, BBoolError> {
+        let mut out = HashMap::with_capacity(names.len());
+        for &name in names {
+            out.insert(name.to_string(), self.get(name)?);
+        }
+        Ok(out)
+    }
+
     /// Toggles multiple boolean values associated with the given names.
     ///
     /// # Arguments
@@ -253,6 +410,7 @@ impl BetterBoolNamedInf {
     pub fn sort(&mut self) -> Result<(), BBoolError> {
         let b = self.sorted()?;
         self.names = b.names;
+        self.index = b.index;
         self.bools = b.bools;
         Ok(())
     }
@@ -275,15 +433,114 @@ impl BetterBoolNamedInf {
     /// # Errors
     /// Returns an error if the sorting operation fails
     pub fn sorted(&self) -> Result<Self, BBoolError> {
+        self.sorted_by(|(a, _), (b, _)| a.cmp(b))
+    }
+
+    /// Returns a new `BetterBoolNamedInf` instance with positions reassigned in the order given by `cmp`.
+    ///
+    /// `cmp` compares `(name, value)` pairs; names that compare equal keep no particular order.
+    ///
+    /// # Arguments
+    /// * `cmp` - The comparator to order the pairs by
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("a", false)?;
+    /// bools.add("b", true)?;
+    /// bools.add("c", false)?;
+    /// // Descending by name
+    /// let sorted = bools.sorted_by(|(a, _), (b, _)| b.cmp(a))?;
+    /// assert_eq!(sorted.name_at_pos(0), Some("c"));
+    /// assert_eq!(sorted.name_at_pos(2), Some("a"));
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if retrieving any boolean value fails
+    pub fn sorted_by<F: Fn(&(String, bool), &(String, bool)) -> Ordering>(
+        &self,
+        cmp: F,
+    ) -> Result<Self, BBoolError> {
         let mut pairs: Vec<_> = self.all()?.into_iter().collect();
-        pairs.sort_by(|(a, _), (b, _)| a.cmp(b));
+        pairs.sort_unstable_by(cmp);
+        Self::rebuilt_from(pairs)
+    }
 
-        let mut sorted = Self::new();
+    /// Returns a new `BetterBoolNamedInf` instance sorted by name, with insertion order as the tiebreaker.
+    ///
+    /// Names are unique, so this orders the same as `sorted`; see `sorted_stable_by` for
+    /// comparators under which different names compare equal.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("c", true)?;
+    /// bools.add("a", false)?;
+    /// let sorted = bools.sorted_stable()?;
+    /// assert_eq!(sorted.name_at_pos(0), Some("a"));
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if retrieving any boolean value fails
+    pub fn sorted_stable(&self) -> Result<Self, BBoolError> {
+        self.sorted_stable_by(|(a, _), (b, _)| a.cmp(b))
+    }
 
+    /// Returns a new `BetterBoolNamedInf` instance with positions reassigned in the order given by `cmp`,
+    /// keeping pairs that compare equal in the order they were inserted.
+    ///
+    /// Unlike `sorted_by`, the result doesn't depend on the internal map's iteration order.
+    ///
+    /// # Arguments
+    /// * `cmp` - The comparator to order the pairs by
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("net.b", true)?;
+    /// bools.add("io.a", false)?;
+    /// bools.add("net.a", false)?;
+    /// // Group by the part before the dot, keeping insertion order within each group
+    /// let group = |name: &str| name.split('.').next().unwrap_or_default().to_string();
+    /// let sorted = bools.sorted_stable_by(|(a, _), (b, _)| group(a).cmp(&group(b)))?;
+    /// assert_eq!(sorted.name_at_pos(0), Some("io.a"));
+    /// assert_eq!(sorted.name_at_pos(1), Some("net.b"));
+    /// assert_eq!(sorted.name_at_pos(2), Some("net.a"));
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if retrieving any boolean value fails
+    pub fn sorted_stable_by<F: Fn(&(String, bool), &(String, bool)) -> Ordering>(
+        &self,
+        cmp: F,
+    ) -> Result<Self, BBoolError> {
+        // Positions are assigned in insertion order, so start from position order
+        let mut pairs = self.named_bits()?;
+        pairs.sort_by(cmp);
+        Self::rebuilt_from(pairs)
+    }
+
+    /// Builds a collection holding `pairs` at positions 0, 1, 2, ...
+    fn rebuilt_from(pairs: Vec<(String, bool)>) -> Result<Self, BBoolError> {
+        let mut sorted = Self::new();
         for (name, value) in pairs {
             sorted.add(&name, value)?;
         }
-
         Ok(sorted)
     }
 
@@ -308,6 +565,73 @@ impl BetterBoolNamedInf {
         self.bools.all()
     }
 
+    /// Returns the value of every named bool, ordered by position.
+    ///
+    /// Unlike `all_bools`, only the named positions are read, so a few names at very high
+    /// positions don't cost a scan of the whole store.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("first", true)?;
+    /// bools.add("second", false)?;
+    /// assert_eq!(
+    ///     bools.named_bits()?,
+    ///     vec![("first".to_string(), true), ("second".to_string(), false)]
+    /// );
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if retrieving any boolean value fails
+    pub fn named_bits(&self) -> Result<Vec<(String, bool)>, BBoolError> {
+        let mut positions: Vec<(&NameKey, usize)> =
+            self.names.iter().map(|(name, &pos)| (name, pos)).collect();
+        positions.sort_unstable_by_key(|&(_, pos)| pos);
+        positions
+            .into_iter()
+            .map(|(name, pos)| Ok((String::from(&**name), self.bools.get_at_pos(pos)?)))
+            .collect()
+    }
+
+    /// Renders the names and values as a two-column `NAME`/`VALUE` table, sorted by name.
+    ///
+    /// The name column is as wide as the longest name. An empty collection renders just the header.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("verbose", true)?;
+    /// bools.add("dry_run", false)?;
+    /// assert_eq!(bools.to_table(), "NAME     VALUE\ndry_run  false\nverbose  true");
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn to_table(&self) -> String {
+        let mut entries: Vec<_> = self.names.iter().collect();
+        entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        let width = entries
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .fold("NAME".len(), usize::max);
+
+        let mut table = format!("{:<width$}  VALUE", "NAME");
+        for (name, &position) in entries {
+            // Positions in `names` are always within the store
+            let value = self.bools.get_at_pos(position).unwrap_or(false);
+            let _ = write!(table, "\n{name:<width$}  {value}");
+        }
+        table
+    }
+
     /// Returns a clone of the internal name-to-position mapping.
     ///
     /// # Examples
@@ -319,7 +643,10 @@ impl BetterBoolNamedInf {
     ///
     #[must_use]
     pub fn all_names_cl(&self) -> HashMap<String, usize> {
-        self.names.clone()
+        self.names
+            .iter()
+            .map(|(name, &pos)| (String::from(&**name), pos))
+            .collect()
     }
 
     /// Returns a reference to the internal name-to-position mapping.
@@ -332,10 +659,34 @@ impl BetterBoolNamedInf {
     /// ```
     ///
     #[must_use]
-    pub const fn all_names(&self) -> &HashMap<String, usize> {
+    pub const fn all_names(&self) -> &HashMap<NameKey, usize> {
         &self.names
     }
 
+    /// Returns the names in sorted order, borrowed from the collection.
+    ///
+    /// Cheaper than `all_names_cl` or `all_sorted` when only the names are needed, as no
+    /// names are cloned and no values are read.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("beta", true)?;
+    /// bools.add("alpha", false)?;
+    /// assert_eq!(bools.name_list(), vec!["alpha", "beta"]);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn name_list(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.names.keys().map(|name| &**name).collect();
+        names.sort_unstable();
+        names
+    }
+
     /// Returns a mutable reference to the internal name-to-position mapping.
     ///
     /// # Examples
@@ -345,12 +696,14 @@ impl BetterBoolNamedInf {
     /// let names_mut = bools.all_names_mut();
     /// ```
     ///
-    pub fn all_names_mut(&mut self) -> &mut HashMap<String, usize> {
+    pub fn all_names_mut(&mut self) -> &mut HashMap<NameKey, usize> {
         &mut self.names
     }
 
     /// Returns all name-value pairs in the collection.
     ///
+    /// The map iterates in no particular order; use `all_sorted` for a reproducible order.
+    ///
     /// # Examples
     /// ```
     /// use btypes::inf_named_bools::BNInf;
@@ -368,11 +721,242 @@ impl BetterBoolNamedInf {
     pub fn all(&self) -> Result<HashMap<String, bool>, BBoolError> {
         let mut result = HashMap::new();
         for (name, &position) in &self.names {
-            result.insert(name.clone(), self.bools.get_at_pos(position)?);
+            result.insert(String::from(&**name), self.bools.get_at_pos(position)?);
         }
         Ok(result)
     }
 
+    /// Returns all name-value pairs in ascending name order.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("b", true)?;
+    /// bools.add("a", false)?;
+    /// assert_eq!(bools.all_sorted()?, vec![("a".to_string(), false), ("b".to_string(), true)]);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if retrieving any boolean value fails
+    pub fn all_sorted(&self) -> Result<Vec<(String, bool)>, BBoolError> {
+        // Read from `names` rather than the index, which misses names added through `all_names_mut`
+        let mut result = self
+            .names
+            .iter()
+            .map(|(name, &position)| Ok((String::from(&**name), self.bools.get_at_pos(position)?)))
+            .collect::<Result<Vec<_>, BBoolError>>()?;
+        result.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        Ok(result)
+    }
+
+    /// Returns the set of names whose value is `true`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("a", true)?;
+    /// bools.add("b", false)?;
+    /// assert!(bools.true_set().contains("a"));
+    /// assert_eq!(bools.true_set().len(), 1);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn true_set(&self) -> HashSet<String> {
+        self.names_with_value(true)
+    }
+
+    /// Returns the set of names whose value is `false`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("a", true)?;
+    /// bools.add("b", false)?;
+    /// assert!(bools.false_set().contains("b"));
+    /// assert_eq!(bools.false_set().len(), 1);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn false_set(&self) -> HashSet<String> {
+        self.names_with_value(false)
+    }
+
+    /// Splits the names by value, returning `(true_names, false_names)` with each sorted.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("b", true)?;
+    /// bools.add("c", false)?;
+    /// bools.add("a", true)?;
+    /// let (on, off) = bools.partition()?;
+    /// assert_eq!(on, vec!["a", "b"]);
+    /// assert_eq!(off, vec!["c"]);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if retrieving any boolean value fails
+    pub fn partition(&self) -> Result<(Vec<String>, Vec<String>), BBoolError> {
+        let (mut true_names, mut false_names) = (Vec::new(), Vec::new());
+        for (name, &position) in &self.names {
+            if self.bools.get_at_pos(position)? {
+                true_names.push(String::from(&**name));
+            } else {
+                false_names.push(String::from(&**name));
+            }
+        }
+        true_names.sort_unstable();
+        false_names.sort_unstable();
+        Ok((true_names, false_names))
+    }
+
+    /// Collects the names whose value equals `value`.
+    fn names_with_value(&self, value: bool) -> HashSet<String> {
+        self.names
+            .iter()
+            .filter(|&(_, &position)| self.bools.get_at_pos(position).is_ok_and(|v| v == value))
+            .map(|(name, _)| String::from(&**name))
+            .collect()
+    }
+
+    /// Serializes the collection as a JSON object of names to values, with names in sorted order.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("b", false)?;
+    /// bools.add("a", true)?;
+    /// assert_eq!(bools.to_json()?, r#"{"a":true,"b":false}"#);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if any stored position cannot be read
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Result<String, BBoolError> {
+        let map = self.all()?.into_iter().collect();
+        Ok(crate::json::write_object(&map))
+    }
+
+    /// Creates a new `BetterBoolNamedInf` from a JSON object of names to values, as produced by `to_json`.
+    ///
+    /// Positions are assigned in sorted name order.
+    ///
+    /// # Arguments
+    /// * s - The JSON text to parse
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = BNInf::from_json(r#"{ "ready": true, "busy": false }"#)?;
+    /// assert!(bools.get("ready")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the input is not a JSON object with only boolean values
+    #[cfg(feature = "json")]
+    pub fn from_json(s: &str) -> Result<Self, BBoolError> {
+        let mut bools = Self::new();
+        for (name, value) in crate::json::parse_object(s)? {
+            bools.add(&name, value)?;
+        }
+        Ok(bools)
+    }
+
+    /// Serializes the names whose value is `true` as a JSON array, in sorted order.
+    ///
+    /// Names holding `false` are left out, so this is more compact than `to_json` for
+    /// mostly-false collections.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("b", true)?;
+    /// bools.add("c", false)?;
+    /// bools.add("a", true)?;
+    /// assert_eq!(bools.to_json_true_only()?, r#"["a","b"]"#);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Currently never fails; the `Result` mirrors `to_json`
+    #[cfg(feature = "json")]
+    pub fn to_json_true_only(&self) -> Result<String, BBoolError> {
+        let names = self.names_with_value(true).into_iter().collect();
+        Ok(crate::json::write_array(&names))
+    }
+
+    /// Loads a JSON array of names, as produced by `to_json_true_only`.
+    ///
+    /// Every listed name is set to `true`, adding it if needed, and every other existing
+    /// name is set to `false`. If any listed name can't be added, nothing is changed.
+    ///
+    /// # Arguments
+    /// * s - The JSON text to parse
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("busy", true)?;
+    /// bools.load_json_true_only(r#"["ready"]"#)?;
+    /// assert!(bools.get("ready")?);
+    /// assert!(!bools.get("busy")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the input is not a JSON array of distinct strings, or if the listed names
+    /// that aren't already present would go past the maximum set with `with_max_bits`
+    #[cfg(feature = "json")]
+    pub fn load_json_true_only(&mut self, s: &str) -> Result<(), BBoolError> {
+        let names = crate::json::parse_array(s)?;
+        // Validate capacity up front so a failure leaves the collection unchanged
+        let new_names = names.iter().filter(|name| !self.names.contains_key(name.as_str())).count();
+        self.check_new_names(new_names)?;
+        let positions: Vec<usize> = self.names.values().copied().collect();
+        for position in positions {
+            self.bools.set_at_pos(position, false)?;
+        }
+        for name in &names {
+            self.set(name, true)?;
+        }
+        Ok(())
+    }
+
     /// Sets a boolean value for the given name. Creates a new entry if the name doesn't exist.
     ///
     /// # Arguments
@@ -433,7 +1017,7 @@ impl BetterBoolNamedInf {
     /// # Examples
     /// ```
     /// use btypes::inf_named_bools::BNInf;
-    /// let mut bools = BNInf::new();
+    /// let bools = BNInf::new();
     /// let exists = bools.exists("test");
     /// ```
     ///
@@ -442,6 +1026,32 @@ impl BetterBoolNamedInf {
         self.names.contains_key(name)
     }
 
+    /// Returns the name assigned to the given bit position, if any.
+    ///
+    /// # Arguments
+    /// * `pos` - The bit position to look up
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("a", true)?;
+    /// bools.add("b", false)?;
+    /// assert_eq!(bools.name_at_pos(1), Some("b"));
+    /// assert_eq!(bools.name_at_pos(2), None);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn name_at_pos(&self, pos: usize) -> Option<&str> {
+        self.names
+            .iter()
+            .find(|&(_, &p)| p == pos)
+            .map(|(name, _)| &**name)
+    }
+
     /// Returns a reference to the raw underlying vector.
     ///
     /// # Examples
@@ -494,12 +1104,34 @@ impl BetterBoolNamedInf {
         if self.names.len() > usize::MAX {
             return Err(BBoolError::CollectionCapacityReached);
         }
-        self.names.insert(name.to_string(), self.next_assign);
+        let key = NameKey::from(name);
+        self.names.insert(key.clone(), self.next_assign);
+        self.index.insert(key);
         self.bools.set_at_pos(self.next_assign, value)?;
         self.next_assign += 1;
         Ok(())
     }
 
+    /// Ensures that naming `count` bools with `pattern` won't run out of positions midway.
+    fn check_mass_capacity(&self, count: usize, pattern: &str) -> Result<(), BBoolError> {
+        let new_names = (0..count)
+            .filter(|i| !self.names.contains_key(pattern.replace("{n}", &i.to_string()).as_str()))
+            .count();
+        self.check_new_names(new_names)
+    }
+
+    /// Ensures that `new_names` more names can be assigned positions.
+    fn check_new_names(&self, new_names: usize) -> Result<(), BBoolError> {
+        let fits = self
+            .next_assign
+            .checked_add(new_names)
+            .is_some_and(|end| self.bools.max_bits().is_none_or(|max| end <= max));
+        if !fits {
+            return Err(BBoolError::CollectionCapacityReached);
+        }
+        Ok(())
+    }
+
     /// Gets the boolean value associated with the given name.
     ///
     /// # Arguments
@@ -549,6 +1181,40 @@ impl BetterBoolNamedInf {
         if self.names.contains_key(name) {
             self.set(name, false)?;
             self.names.remove(name);
+            self.index.remove(name);
+        }
+        Ok(())
+    }
+
+    /// Sets every value to `false` except the ones with the given names, keeping all names in the collection.
+    ///
+    /// Names in `keep` that don't exist are ignored.
+    ///
+    /// # Arguments
+    /// * `keep` - The names of the values to leave untouched
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("a", true)?;
+    /// bools.add("b", true)?;
+    /// bools.clear_except(&["b"])?;
+    /// assert!(!bools.get("a")?);
+    /// assert!(bools.get("b")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if setting any value fails
+    pub fn clear_except(&mut self, keep: &[&str]) -> Result<(), BBoolError> {
+        for (name, &position) in &self.names {
+            if !keep.contains(&&**name) {
+                self.bools.set_at_pos(position, false)?;
+            }
         }
         Ok(())
     }
@@ -564,8 +1230,64 @@ impl BetterBoolNamedInf {
     ///
     pub fn clear(&mut self) {
         self.names.clear();
+        self.index.clear();
         self.bools.clear();
     }
+
+    /// Returns up to `limit` names starting with `prefix`, in sorted order.
+    ///
+    /// Lookups use a sorted index of the names, so only matching names are visited.
+    /// Names inserted directly through `all_names_mut` are not indexed.
+    ///
+    /// # Arguments
+    /// * prefix - The prefix the names must start with
+    /// * limit - The maximum number of names to return
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::inf_named_bools::BNInf;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BNInf::new();
+    /// bools.add("net.http", true)?;
+    /// bools.add("net.dns", false)?;
+    /// bools.add("disk.io", true)?;
+    /// assert_eq!(bools.complete("net.", 10), vec!["net.dns", "net.http"]);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn complete(&self, prefix: &str, limit: usize) -> Vec<String> {
+        self.index
+            .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+            .take_while(|name| name.starts_with(prefix))
+            .filter(|name| self.names.contains_key::<str>(name))
+            .take(limit)
+            .map(ToString::to_string)
+            .collect()
+    }
+}
+
+/// Accesses the underlying bits by raw position, regardless of which name owns them.
+impl BoolCollection for BetterBoolNamedInf {
+    type Error = BBoolError;
+
+    fn get_at_pos(&self, pos: usize) -> Result<bool, BBoolError> {
+        BoolCollection::get_at_pos(&self.bools, pos)
+    }
+
+    fn set_at_pos(&mut self, pos: usize, value: bool) -> Result<(), BBoolError> {
+        BoolCollection::set_at_pos(&mut self.bools, pos, value)
+    }
+
+    fn all(&self) -> Result<Vec<bool>, BBoolError> {
+        self.all_bools()
+    }
+
+    /// Clears both the values and the names.
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
 }
 
 impl IntoIterator for BetterBoolNamedInf {
@@ -579,8 +1301,98 @@ impl IntoIterator for BetterBoolNamedInf {
     }
 }
 
+/// Formats the bools as `name: value` lines, sorted by name.
+///
+/// If the values can't be read, an `<error: ...>` marker is written instead.
 impl Display for BetterBoolNamedInf {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:#?}", self.all())
+        match self.all() {
+            Ok(all) => {
+                let mut entries: Vec<_> = all.into_iter().collect();
+                entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+                for (i, (name, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{name}: {value}")?;
+                }
+                Ok(())
+            }
+            Err(e) => write!(f, "<error: {e}>"),
+        }
+    }
+}
+
+/// Sets or adds every name-value pair from the iterator.
+///
+/// # Panics
+/// Panics if adding a value fails.
+impl Extend<(String, bool)> for BetterBoolNamedInf {
+    fn extend<I: IntoIterator<Item = (String, bool)>>(&mut self, iter: I) {
+        for (name, value) in iter {
+            self.set(&name, value).expect("Failed to add bool");
+        }
+    }
+}
+
+/// Builds a collection from name-value pairs, assigning positions in iteration order.
+///
+/// # Panics
+/// Panics if adding a value fails.
+impl FromIterator<(String, bool)> for BetterBoolNamedInf {
+    fn from_iter<I: IntoIterator<Item = (String, bool)>>(iter: I) -> Self {
+        let mut bools = Self::new();
+        bools.extend(iter);
+        bools
+    }
+}
+
+/// Migrates a fixed-size named collection to the infinite one, keeping every name and value.
+///
+/// The bits are packed into the byte store at the same positions they had in the fixed type.
+///
+/// # Examples
+/// ```
+/// use btypes::named_bools::BN8;
+/// use btypes::inf_named_bools::BNInf;
+/// use anyhow::Result;
+/// fn main() -> Result<()> {
+/// let mut fixed = BN8::new();
+/// fixed.add("a", true)?;
+/// fixed.add("b", false)?;
+/// let mut inf = BNInf::from(fixed);
+/// assert!(inf.get("a")?);
+/// assert!(!inf.get("b")?);
+/// inf.add("c", true)?;
+/// Ok(())
+/// }
+/// ```
+#[cfg(feature = "named_bools")]
+impl<T: BitwiseOpsCopy> From<BetterBoolNamed<T>> for BetterBoolNamedInf {
+    fn from(named: BetterBoolNamed<T>) -> Self {
+        let cap = crate::bbool::BetterBool::<T>::CAP;
+        let mut store = vec![0u8; usize::from(cap).div_ceil(8)];
+        for pos in 0..cap {
+            // Positions below CAP are always valid
+            if named.bools.get_at_pos(pos).unwrap_or(false) {
+                store[usize::from(pos / 8)] |= 1 << (pos % 8);
+            }
+        }
+
+        let mut inf = Self::from_vec(store);
+        for (name, &pos) in named.all_names() {
+            let key = NameKey::from(name.as_str());
+            inf.names.insert(key.clone(), usize::from(pos));
+            inf.index.insert(key);
+        }
+        // Assign new names after every migrated position, even ones placed with add_at_pos
+        inf.next_assign = named
+            .all_names()
+            .values()
+            .map(|&pos| usize::from(pos) + 1)
+            .max()
+            .unwrap_or(0)
+            .max(usize::from(named.used_bits()));
+        inf
     }
 }
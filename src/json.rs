@@ -0,0 +1,259 @@
+use crate::error::BBoolError;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// Writes a name-value map as a compact JSON object, with keys in sorted order.
+pub fn write_object(map: &BTreeMap<String, bool>) -> String {
+    let mut out = String::from("{");
+    for (i, (name, value)) in map.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_string(&mut out, name);
+        out.push(':');
+        out.push_str(if *value { "true" } else { "false" });
+    }
+    out.push('}');
+    out
+}
+
+/// Writes a set of names as a compact JSON array of strings, in sorted order.
+pub fn write_array(names: &BTreeSet<String>) -> String {
+    let mut out = String::from("[");
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_string(&mut out, name);
+    }
+    out.push(']');
+    out
+}
+
+/// Writes a JSON string literal, escaping quotes, backslashes and control characters.
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                // Writing to a String can't fail
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Parses a JSON object whose values are all booleans.
+///
+/// # Errors
+/// Returns `BBoolError::Other` if the input isn't such an object, or if a name appears twice.
+pub fn parse_object(input: &str) -> Result<BTreeMap<String, bool>, BBoolError> {
+    let mut parser = Parser {
+        chars: input.char_indices().peekable(),
+        len: input.len(),
+    };
+    let mut map = BTreeMap::new();
+
+    parser.skip_ws();
+    parser.expect('{')?;
+    parser.skip_ws();
+    if parser.eat('}') {
+        parser.finish()?;
+        return Ok(map);
+    }
+
+    loop {
+        parser.skip_ws();
+        let name = parser.string()?;
+        parser.skip_ws();
+        parser.expect(':')?;
+        parser.skip_ws();
+        let value = parser.boolean()?;
+        if map.insert(name.clone(), value).is_some() {
+            return Err(BBoolError::Other(format!(
+                "Invalid JSON: duplicate name {name:?}"
+            )));
+        }
+
+        parser.skip_ws();
+        if parser.eat(',') {
+            continue;
+        }
+        parser.expect('}')?;
+        break;
+    }
+
+    parser.finish()?;
+    Ok(map)
+}
+
+/// Parses a JSON array whose elements are all strings.
+///
+/// # Errors
+/// Returns `BBoolError::Other` if the input isn't such an array, or if a name appears twice.
+pub fn parse_array(input: &str) -> Result<BTreeSet<String>, BBoolError> {
+    let mut parser = Parser {
+        chars: input.char_indices().peekable(),
+        len: input.len(),
+    };
+    let mut names = BTreeSet::new();
+
+    parser.skip_ws();
+    parser.expect('[')?;
+    parser.skip_ws();
+    if parser.eat(']') {
+        parser.finish()?;
+        return Ok(names);
+    }
+
+    loop {
+        parser.skip_ws();
+        let name = parser.string()?;
+        if names.contains(&name) {
+            return Err(BBoolError::Other(format!(
+                "Invalid JSON: duplicate name {name:?}"
+            )));
+        }
+        names.insert(name);
+
+        parser.skip_ws();
+        if parser.eat(',') {
+            continue;
+        }
+        parser.expect(']')?;
+        break;
+    }
+
+    parser.finish()?;
+    Ok(names)
+}
+
+/// A minimal cursor over the JSON input.
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    len: usize,
+}
+
+impl Parser<'_> {
+    fn pos(&mut self) -> usize {
+        self.chars.peek().map_or(self.len, |&(i, _)| i)
+    }
+
+    fn error(&mut self, expected: &str) -> BBoolError {
+        let pos = self.pos();
+        BBoolError::Other(format!("Invalid JSON at byte {pos}: expected {expected}"))
+    }
+
+    fn skip_ws(&mut self) {
+        while self
+            .chars
+            .next_if(|&(_, c)| matches!(c, ' ' | '\t' | '\n' | '\r'))
+            .is_some()
+        {}
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.chars.next_if(|&(_, c)| c == expected).is_some()
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), BBoolError> {
+        if self.eat(expected) {
+            return Ok(());
+        }
+        Err(self.error(&format!("'{expected}'")))
+    }
+
+    fn finish(&mut self) -> Result<(), BBoolError> {
+        self.skip_ws();
+        if self.chars.peek().is_some() {
+            return Err(self.error("end of input"));
+        }
+        Ok(())
+    }
+
+    fn boolean(&mut self) -> Result<bool, BBoolError> {
+        for (literal, value) in [("true", true), ("false", false)] {
+            if self
+                .chars
+                .peek()
+                .is_some_and(|&(_, c)| literal.starts_with(c))
+            {
+                if literal.chars().all(|l| self.eat(l)) {
+                    return Ok(value);
+                }
+                break;
+            }
+        }
+        Err(self.error("true or false"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, BBoolError> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self
+                .chars
+                .peek()
+                .and_then(|&(_, c)| c.to_digit(16))
+                .ok_or_else(|| self.error("hex digit"))?;
+            self.chars.next();
+            code = code * 16 + digit;
+        }
+        Ok(code)
+    }
+
+    fn string(&mut self) -> Result<String, BBoolError> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            let Some((_, c)) = self.chars.next() else {
+                return Err(self.error("closing '\"'"));
+            };
+            match c {
+                '"' => return Ok(out),
+                '\\' => {
+                    let Some((_, escape)) = self.chars.next() else {
+                        return Err(self.error("escape character"));
+                    };
+                    match escape {
+                        '"' => out.push('"'),
+                        '\\' => out.push('\\'),
+                        '/' => out.push('/'),
+                        'b' => out.push('\u{8}'),
+                        'f' => out.push('\u{c}'),
+                        'n' => out.push('\n'),
+                        'r' => out.push('\r'),
+                        't' => out.push('\t'),
+                        'u' => {
+                            let mut code = self.hex4()?;
+                            if (0xD800..0xDC00).contains(&code) {
+                                // High surrogate, which must be followed by a low one
+                                if !(self.eat('\\') && self.eat('u')) {
+                                    return Err(self.error("low surrogate"));
+                                }
+                                let low = self.hex4()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(self.error("low surrogate"));
+                                }
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            out.push(
+                                char::from_u32(code)
+                                    .ok_or_else(|| self.error("valid unicode escape"))?,
+                            );
+                        }
+                        _ => return Err(self.error("valid escape character")),
+                    }
+                }
+                c if (c as u32) < 0x20 => return Err(self.error("escaped control character")),
+                c => out.push(c),
+            }
+        }
+    }
+}
@@ -10,6 +10,12 @@ offering additional functionality beyond the standard library implementations.
 * `inf_bools` - Dynamically-sized boolean collections
 * `inf_named_bools` - Dynamically-sized named boolean collections
 * `strings` - Enhanced string type with additional functionality
+* `bloom` - Bloom filter backed by a dynamically-sized boolean collection
+* `enum_flags` - Enum-keyed flags backed by a fixed-capacity boolean collection
+* `json` - JSON import/export for the named boolean collections
+* `shared_flags` - Thread-safe named flag registry with change notifications
+* `std` - Time-based helpers that need the standard clock, such as flag TTLs
+* `interned_names` - Stores each `BetterBoolNamedInf` name once as a shared `Arc<str>` key (not part of `all`, as it changes the key type of `all_names` from `String`)
 
 ## Example Usage
 
@@ -107,6 +113,80 @@ pub mod inf_bbool;
 /// ```
 pub mod inf_named_bools;
 
+#[cfg(feature = "bloom")]
+/// Probabilistic set membership backed by dynamically-sized boolean collections
+///
+/// This module provides `BloomFilter`, which uses a `BetterBoolInf` as its bit array
+/// and is sized at construction from an expected item count and false-positive rate.
+///
+/// # Example
+/// ```
+/// use btypes::bloom::BloomFilter;
+/// use anyhow::Result;
+///
+/// fn main() -> Result<()> {
+///     let mut filter = BloomFilter::new(1000, 0.01)?;
+///     filter.insert(b"flag");
+///     assert!(filter.contains(b"flag"));
+///     Ok(())
+/// }
+/// ```
+pub mod bloom;
+
+#[cfg(feature = "enum_flags")]
+/// Enum-keyed flags backed by fixed-capacity boolean collections
+///
+/// This module provides `EnumFlags`, which uses an enum's `u8` conversion as the
+/// bit position in a `BetterBool<u128>`, giving compile-time checked flag names.
+///
+/// # Example
+/// ```
+/// use btypes::enum_flags::EnumFlags;
+/// use anyhow::Result;
+///
+/// #[derive(Clone, Copy)]
+/// enum Perm { Read, Write, Exec }
+/// impl From<Perm> for u8 {
+///     fn from(p: Perm) -> u8 { p as u8 }
+/// }
+///
+/// fn main() -> Result<()> {
+///     let mut flags = EnumFlags::new();
+///     flags.set(Perm::Read, true)?;
+///     flags.toggle(Perm::Exec)?;
+///     assert!(flags.get(Perm::Exec)?);
+///     Ok(())
+/// }
+/// ```
+pub mod enum_flags;
+
+#[cfg(feature = "shared_flags")]
+/// Thread-safe named flags shared across threads
+///
+/// This module provides `SharedFlags`, which wraps a `BetterBoolNamed<u128>` in an
+/// `Arc<RwLock<_>>` and notifies subscribers of every change over a channel.
+///
+/// # Example
+/// ```
+/// use btypes::shared_flags::SharedFlags;
+/// use anyhow::Result;
+///
+/// fn main() -> Result<()> {
+///     let flags = SharedFlags::new();
+///     let handle = flags.clone();
+///     std::thread::spawn(move || handle.set("maintenance", true)).join().unwrap()?;
+///     assert!(flags.get("maintenance")?);
+///     Ok(())
+/// }
+/// ```
+pub mod shared_flags;
+
+#[cfg(all(
+    feature = "json",
+    any(feature = "named_bools", feature = "inf_named_bools")
+))]
+mod json;
+
 /// Common traits for bitwise operations and numeric conversions
 ///
 /// This module defines traits that ensure types support the necessary
@@ -1,10 +1,18 @@
 use crate::bbool::BetterBool;
 use crate::error::BBoolError;
-use crate::traits::{BitwiseOpsClone, BitwiseOpsCopy, Nums};
+use crate::traits::{BitwiseOpsClone, BitwiseOpsCopy, BoolCollection, Nums};
 use anyhow::Error;
 use anyhow::Result;
-use std::fmt::Display;
-use std::{collections::HashMap, marker::PhantomData};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Write};
+use std::sync::Arc;
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
+use std::collections::{BTreeMap, BTreeSet};
+use std::{
+    collections::{HashMap, HashSet},
+    marker::PhantomData,
+};
 
 /// Type alias for a 128-bit named `BetterBool`
 pub type BN128 = BetterBoolNamed<u128>;
@@ -31,6 +39,58 @@ pub struct BetterBoolNamed<T: Nums> {
     names: HashMap<String, u8>,
     /// Next available position for new boolean values
     next_assign: u8,
+    /// Predicate that new names must pass, if one was set
+    validator: Option<NameValidator>,
+    /// When each flag set with `set_with_ttl` should be reset to `false`
+    #[cfg(feature = "std")]
+    expiries: HashMap<String, Instant>,
+}
+
+/// A predicate deciding which names a `BetterBoolNamed` accepts
+#[derive(Clone)]
+struct NameValidator(Arc<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl Debug for NameValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("NameValidator")
+    }
+}
+
+/// What `BetterBoolNamed::repair` changed to make a collection consistent again
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RepairReport {
+    /// Names that were removed because their position was outside the capacity
+    pub out_of_range: Vec<String>,
+    /// Names that were removed because another name shared their position
+    pub aliases: Vec<String>,
+    /// Set positions with no name that were cleared
+    pub cleared_unnamed: Vec<u8>,
+    /// The previous and new next assigned position, if it changed
+    pub next_assign: Option<(u8, u8)>,
+}
+
+impl RepairReport {
+    /// Returns true if nothing needed repairing.
+    #[must_use]
+    pub const fn is_clean(&self) -> bool {
+        self.out_of_range.is_empty()
+            && self.aliases.is_empty()
+            && self.cleared_unnamed.is_empty()
+            && self.next_assign.is_none()
+    }
+}
+
+/// A checkpoint of the state of a `BetterBoolNamed`
+///
+/// Created with `BetterBoolNamed::snapshot` and applied with `BetterBoolNamed::restore`.
+#[derive(Clone, Debug)]
+pub struct NamedSnapshot<T: Nums> {
+    /// The numeric value storing the boolean bits
+    store: T,
+    /// Mapping of names to boolean positions
+    names: HashMap<String, u8>,
+    /// Next available position for new boolean values
+    next_assign: u8,
 }
 
 impl<T: Nums> Default for BetterBoolNamed<T>
@@ -41,11 +101,17 @@ impl<T: Nums> Default for BetterBoolNamed<T>
             bools: BetterBool::default(),
             names: HashMap::new(),
             next_assign: 0,
+            validator: None,
+            #[cfg(feature = "std")]
+            expiries: HashMap::new(),
         }
     }
 }
 
-impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
+impl<T: Nums> BetterBoolNamed<T> {
+    /// The maximum number of names, one per bit of `T`: 8 for `BN8` up to 128 for `BN128`.
+    pub const CAP: u8 = BetterBool::<T>::CAP;
+
     /// Creates a new `BetterBoolNamed` instance with a specified initial value.
     ///
     /// # Arguments
@@ -66,7 +132,51 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
             bools,
             names: HashMap::new(),
             next_assign: 0,
+            validator: None,
+            #[cfg(feature = "std")]
+            expiries: HashMap::new(),
+        }
+    }
+    /// Creates a new `BetterBoolNamed` over an existing raw value, naming positions `0..names.len()` in order.
+    ///
+    /// Useful for getting a named view over a value read from elsewhere, such as a hardware register.
+    ///
+    /// # Arguments
+    /// * `value` - The raw numeric value to store the boolean states
+    /// * `names` - The names to assign to positions 0, 1, 2, ...
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = BN8::from_num_with_names(0b0000_0101, &["ready", "busy", "error"])?;
+    /// assert!(bools.get("ready")?);
+    /// assert!(!bools.get("busy")?);
+    /// assert!(bools.get("error")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * There are more names than the collection can store
+    /// * A name appears more than once
+    pub fn from_num_with_names(value: T, names: &[&str]) -> Result<Self, BBoolError> {
+        if names.len() > BetterBool::<T>::CAP as usize {
+            return Err(BBoolError::CollectionCapacityReached);
+        }
+        let mut bools = Self::from_num(value);
+        for (pos, &name) in (0..).zip(names) {
+            if bools.names.insert(name.to_string(), pos).is_some() {
+                return Err(BBoolError::Other(format!("Name already exists: {name}")));
+            }
         }
+        // Able to allow as names.len() was checked against CAP above, which fits in a u8.
+        #[allow(clippy::cast_possible_truncation)]
+        let next_assign = names.len() as u8;
+        bools.next_assign = next_assign;
+        Ok(bools)
     }
     /// Creates a new empty `BetterBoolNamed` instance initialized with zeros.
     ///
@@ -78,6 +188,9 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
     #[must_use] pub fn new() -> Self {
         Self::default()
     }
+}
+
+impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
     /// Set/add many named bools, with the names being dictated by the pattern and the values by the value pattern.
     ///
     /// # Arguments
@@ -109,6 +222,8 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
     /// * The value pattern doesn't contain {r} and the count of bools in it doesn't match or exceed the count.
     /// * The value pattern contains invalid boolean values
     /// * Adding the bools would exceed capacity
+    ///
+    /// All of these are checked before anything is set, so on error the collection is left unchanged.
     pub fn mass_set(
         &mut self,
         count: u8,
@@ -130,7 +245,6 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
             ));
         }
         if !value_pattern.contains("{r}") && value_parts.len() < count.into() {
-            println!("{}, {}", !value_parts.contains(&"{r}"), value_parts.len());
             return Err(BBoolError::InvalidPattern(
                 "Value pattern must be able to fill all set bools".to_string(),
             ));
@@ -147,6 +261,9 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
             })
             .collect::<Result<Vec<bool>>>()?;
 
+        // Validate capacity up front so a failure leaves the collection unchanged
+        self.check_mass_capacity(count, pattern)?;
+
         // Set/add bools
         for i in 0..count {
             let name = pattern.replace("{n}", &i.to_string());
@@ -165,6 +282,90 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
 
         Ok(())
     }
+    /// Like `mass_set`, but without leniency about the length of a non-repeating value pattern.
+    ///
+    /// If the value pattern doesn't contain {r}, it must list exactly `count` values; `mass_set` would
+    /// silently ignore any extras.
+    ///
+    /// # Arguments
+    /// * `count` - Number of bools to set/add
+    /// * `pattern` - Name pattern containing {n} which will be replaced with sequential numbers (0 to count-1)
+    /// * `value_pattern` - Comma-separated list of boolean values with optional {r} suffix to repeat the pattern
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// fn main() -> anyhow::Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.mass_set_strict(3, "val_{n}", "true,false,true")?;
+    /// assert!(bools.mass_set_strict(2, "val_{n}", "true,false,true").is_err());
+    /// // Repeating patterns are unaffected
+    /// bools.mass_set_strict(4, "rep_{n}", "true,false{r}")?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `BBoolError::InvalidPattern` if the value pattern doesn't contain {r} and its length isn't
+    /// exactly `count`, along with every error `mass_set` can return.
+    pub fn mass_set_strict(
+        &mut self,
+        count: u8,
+        pattern: &str,
+        value_pattern: &str,
+    ) -> Result<(), BBoolError> {
+        let value_count = value_pattern.trim().split(',').count();
+        if !value_pattern.contains("{r}") && value_count != usize::from(count) {
+            return Err(BBoolError::InvalidPattern(format!(
+                "Value pattern has {value_count} values, expected exactly {count}"
+            )));
+        }
+        self.mass_set(count, pattern, value_pattern)
+    }
+    /// Set/add many named bools, with the names being dictated by the pattern and the values computed by a closure.
+    ///
+    /// # Arguments
+    /// * `count` - Number of bools to set/add
+    /// * `pattern` - Name pattern containing {n} which will be replaced with sequential numbers (0 to count-1)
+    /// * `f` - Closure called with each index, returning the value for that bool
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// fn main() -> anyhow::Result<()> {
+    /// let mut bools = BN128::new();
+    /// // Creates bool_0=true, bool_1=false, bool_2=true, bool_3=false
+    /// bools.mass_set_fn(4, "bool_{n}", |n| n % 2 == 0)?;
+    /// assert!(bools.get("bool_2")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The pattern doesn't contain {n}
+    /// * Adding the bools would exceed capacity
+    pub fn mass_set_fn<F: FnMut(u128) -> bool>(
+        &mut self,
+        count: u8,
+        pattern: &str,
+        mut f: F,
+    ) -> Result<(), BBoolError> {
+        if !pattern.contains("{n}") {
+            return Err(BBoolError::InvalidPattern(
+                "Pattern must contain {n}".to_string(),
+            ));
+        }
+
+        self.check_mass_capacity(count, pattern)?;
+
+        for i in 0..count {
+            let name = pattern.replace("{n}", &i.to_string());
+            self.set(&name, f(u128::from(i)))?;
+        }
+
+        Ok(())
+    }
     /// Gets multiple boolean values associated with the given names.
     ///
     /// # Arguments
@@ -194,6 +395,36 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
         }
         Ok(out)
     }
+    /// Gets multiple boolean values associated with the given names, keyed by name.
+    ///
+    /// # Arguments
+    /// * names - A slice of string slices containing the names to retrieve
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("test1", true)?;
+    /// bools.add("test2", false)?;
+    /// let values = bools.mass_get_map(&["test1", "test2"])?;
+    /// assert!(values["test1"]);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Any of the names don't exist in the collection
+    /// * Retrieving any value fails
+    pub fn mass_get_map(&self, names: &[&str]) -> Result<HashMap<String, bool>, BBoolError> {
+        let mut out = HashMap::with_capacity(names.len());
+        for &name in names {
+            out.insert(name.to_string(), self.get(name)?);
+        }
+        Ok(out)
+    }
     /// Toggles multiple boolean values associated with the given names.
     ///
     /// # Arguments
@@ -264,18 +495,117 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
     /// # Errors
     /// Returns an error if the sorting operation fails
     pub fn sorted(&self) -> Result<Self, BBoolError> {
-        // Get all name-value pairs and sort them by name
+        self.sorted_by(|(a, _), (b, _)| a.cmp(b))
+    }
+    /// Returns a new `BetterBoolNamed` instance with positions reassigned in the order given by `cmp`.
+    ///
+    /// `cmp` compares `(name, value)` pairs; names that compare equal keep no particular order.
+    ///
+    /// # Arguments
+    /// * `cmp` - The comparator to order the pairs by
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("a", false)?;
+    /// bools.add("b", true)?;
+    /// bools.add("c", false)?;
+    /// // Descending by name
+    /// let sorted = bools.sorted_by(|(a, _), (b, _)| b.cmp(a))?;
+    /// assert_eq!(sorted.name_at_pos(0), Some("c"));
+    /// assert_eq!(sorted.name_at_pos(2), Some("a"));
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if retrieving any boolean value fails
+    pub fn sorted_by<F: Fn(&(String, bool), &(String, bool)) -> Ordering>(
+        &self,
+        cmp: F,
+    ) -> Result<Self, BBoolError> {
         let mut pairs: Vec<_> = self.all()?.into_iter().collect();
-        pairs.sort_by(|(a, _), (b, _)| a.cmp(b));
-
-        // Create new instance
+        pairs.sort_unstable_by(cmp);
+        self.rebuilt_from(pairs)
+    }
+    /// Returns a new `BetterBoolNamed` instance sorted by name, with insertion order as the tiebreaker.
+    ///
+    /// Names are unique, so this orders the same as `sorted`; see `sorted_stable_by` for
+    /// comparators under which different names compare equal.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.add("c", true)?;
+    /// bools.add("a", false)?;
+    /// let sorted = bools.sorted_stable()?;
+    /// assert_eq!(sorted.name_at_pos(0), Some("a"));
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if retrieving any boolean value fails
+    pub fn sorted_stable(&self) -> Result<Self, BBoolError> {
+        self.sorted_stable_by(|(a, _), (b, _)| a.cmp(b))
+    }
+    /// Returns a new `BetterBoolNamed` instance with positions reassigned in the order given by `cmp`,
+    /// keeping pairs that compare equal in the order they were inserted.
+    ///
+    /// Unlike `sorted_by`, the result doesn't depend on the internal map's iteration order.
+    ///
+    /// # Arguments
+    /// * `cmp` - The comparator to order the pairs by
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.add("net.b", true)?;
+    /// bools.add("io.a", false)?;
+    /// bools.add("net.a", false)?;
+    /// // Group by the part before the dot, keeping insertion order within each group
+    /// let group = |name: &str| name.split('.').next().unwrap_or_default().to_string();
+    /// let sorted = bools.sorted_stable_by(|(a, _), (b, _)| group(a).cmp(&group(b)))?;
+    /// assert_eq!(sorted.name_at_pos(0), Some("io.a"));
+    /// assert_eq!(sorted.name_at_pos(1), Some("net.b"));
+    /// assert_eq!(sorted.name_at_pos(2), Some("net.a"));
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if retrieving any boolean value fails
+    pub fn sorted_stable_by<F: Fn(&(String, bool), &(String, bool)) -> Ordering>(
+        &self,
+        cmp: F,
+    ) -> Result<Self, BBoolError> {
+        // Positions are assigned in insertion order, so start from position order
+        let mut positioned: Vec<(u8, &String)> =
+            self.names.iter().map(|(name, &pos)| (pos, name)).collect();
+        positioned.sort_unstable_by_key(|&(pos, _)| pos);
+        let mut pairs = positioned
+            .into_iter()
+            .map(|(pos, name)| Ok((name.clone(), self.bools.get_at_pos(pos)?)))
+            .collect::<Result<Vec<_>, BBoolError>>()?;
+        pairs.sort_by(cmp);
+        self.rebuilt_from(pairs)
+    }
+    /// Builds a collection holding `pairs` at positions 0, 1, 2, ...
+    fn rebuilt_from(&self, pairs: Vec<(String, bool)>) -> Result<Self, BBoolError> {
         let mut sorted = Self::new();
-
-        // Add sorted pairs in order
+        sorted.validator.clone_from(&self.validator);
         for (name, value) in pairs {
             sorted.add(&name, value)?;
         }
-
         Ok(sorted)
     }
     /// Returns all boolean values in the collection as a vector.
@@ -298,6 +628,39 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
     pub fn all_bools(&self) -> Result<Vec<bool>, BBoolError> {
         self.bools.all()
     }
+    /// Renders the names and values as a two-column `NAME`/`VALUE` table, sorted by name.
+    ///
+    /// The name column is as wide as the longest name. An empty collection renders just the header.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.add("verbose", true)?;
+    /// bools.add("dry_run", false)?;
+    /// assert_eq!(bools.to_table(), "NAME     VALUE\ndry_run  false\nverbose  true");
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn to_table(&self) -> String {
+        let mut entries: Vec<_> = self.names.iter().collect();
+        entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        let width = entries
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .fold("NAME".len(), usize::max);
+
+        let mut table = format!("{:<width$}  VALUE", "NAME");
+        for (name, &position) in entries {
+            // Positions in `names` are always within the store
+            let value = self.bools.get_at_pos(position).unwrap_or(false);
+            let _ = write!(table, "\n{name:<width$}  {value}");
+        }
+        table
+    }
     /// Returns a clone of the internal name-to-position mapping.
     ///
     /// # Examples
@@ -320,8 +683,34 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
     pub const fn all_names(&self) -> &HashMap<String, u8> {
         &self.names
     }
+    /// Returns the names in sorted order, borrowed from the collection.
+    ///
+    /// Cheaper than `all_names_cl` or `all_sorted` when only the names are needed, as no
+    /// names are cloned and no values are read.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("beta", true)?;
+    /// bools.add("alpha", false)?;
+    /// assert_eq!(bools.name_list(), vec!["alpha", "beta"]);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn name_list(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.names.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
     /// Returns a mutable reference to the internal name-to-position mapping.
     ///
+    /// After moving names to new positions, call `recompute_next_assign` so `add` doesn't
+    /// reuse a position that is now taken.
+    ///
     /// # Examples
     /// ```
     /// use btypes::named_bools::BN128;
@@ -333,6 +722,8 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
     }
     /// Returns a `HashMap` containing all name-value pairs in the collection.
     ///
+    /// The map iterates in no particular order; use `all_sorted` for a reproducible order.
+    ///
     /// # Examples
     /// ```
     /// use btypes::named_bools::BN128;
@@ -355,11 +746,30 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
         Ok(result)
     }
 
-    /// Sets or adds a boolean value with the given name.
+    /// Returns all name-value pairs in ascending name order.
     ///
-    /// # Arguments
-    /// * `name` - The name to associate with the boolean value
-    /// * `value` - The boolean value to set
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.add("b", true)?;
+    /// bools.add("a", false)?;
+    /// assert_eq!(bools.all_sorted()?, vec![("a".to_string(), false), ("b".to_string(), true)]);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if retrieving any boolean value fails
+    pub fn all_sorted(&self) -> Result<Vec<(String, bool)>, BBoolError> {
+        Ok(self.to_named_map()?.into_iter().collect())
+    }
+
+    /// Returns all name-value pairs as a `BTreeMap`, dropping the internal bit positions.
+    ///
+    /// This is the portable form of the collection, suitable for serialization.
     ///
     /// # Examples
     /// ```
@@ -367,62 +777,658 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
     /// let mut bools = BN128::new();
-    /// bools.set("test", true)?;
+    /// bools.add("b", true)?;
+    /// bools.add("a", false)?;
+    /// let map = bools.to_named_map()?;
+    /// assert_eq!(map.keys().collect::<Vec<_>>(), vec!["a", "b"]);
     /// Ok(())
     /// }
     /// ```
     ///
     /// # Errors
-    /// Returns an error if:
-    /// * Setting the value fails
-    /// * Adding a new value fails
-    pub fn set(&mut self, name: &str, value: bool) -> Result<(), BBoolError> {
-        match self.names.get(name) {
-            Some(&position) => self.bools.set_at_pos(position, value)?,
-            None => self.add(name, value)?,
+    /// Returns an error if retrieving any boolean value fails
+    pub fn to_named_map(&self) -> Result<BTreeMap<String, bool>, BBoolError> {
+        let mut result = BTreeMap::new();
+        for (name, &position) in &self.names {
+            result.insert(name.clone(), self.bools.get_at_pos(position)?);
         }
-        Ok(())
+        Ok(result)
     }
-    /// Toggles the boolean value associated with the given name.
+    /// Creates a new `BetterBoolNamed` from a map of names to values.
+    ///
+    /// Positions are assigned deterministically in the map's (sorted) order.
     ///
     /// # Arguments
-    /// * name - The name of the boolean value to toggle
+    /// * `map` - The name-value pairs to load
     ///
     /// # Examples
     /// ```
     /// use btypes::named_bools::BN128;
+    /// use std::collections::BTreeMap;
     /// use anyhow::Result;
     /// fn main() -> Result<()> {
-    /// let mut bools = BN128::new();
-    /// bools.add("test", true)?;
-    /// bools.toggle("test")?; // Value is now false
+    /// let map = BTreeMap::from([("a".to_string(), true), ("b".to_string(), false)]);
+    /// let bools = BN128::from_named_map(map)?;
+    /// assert!(bools.get("a")?);
     /// Ok(())
     /// }
     /// ```
     ///
     /// # Errors
-    /// Returns an error if:
-    /// * The name doesn't exist in the collection
-    /// * Setting the toggled value fails
-    pub fn toggle(&mut self, name: &str) -> Result<(), BBoolError> {
-        let current = self.get(name)?;
-        self.set(name, !current)?;
-        Ok(())
+    /// Returns an error if the map holds more values than the collection can store
+    pub fn from_named_map(map: BTreeMap<String, bool>) -> Result<Self, BBoolError> {
+        let mut bools = Self::new();
+        for (name, value) in map {
+            bools.add(&name, value)?;
+        }
+        Ok(bools)
     }
-
-    /// Checks if a boolean value with the given name exists in the collection.
+    /// Creates a new `BetterBoolNamed` from semicolon-separated `name:value` entries, e.g. `"a:true; b:false"`.
+    ///
+    /// Whitespace around names and values is trimmed, blank entries are ignored, and positions are
+    /// assigned in the order the entries appear.
     ///
     /// # Arguments
-    /// * `name` - The name to check for existence
+    /// * `s` - The config string to parse
     ///
     /// # Examples
     /// ```
     /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = BN128::from_config("debug: true; verbose:false;")?;
+    /// assert!(bools.get("debug")?);
+    /// assert!(!bools.get("verbose")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * An entry isn't of the form `name:true` or `name:false`
+    /// * A name appears more than once
+    /// * There are more entries than the collection can store
+    pub fn from_config(s: &str) -> Result<Self, BBoolError> {
+        let mut bools = Self::new();
+        for entry in s.split(';').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let malformed = || BBoolError::InvalidPattern(format!("Malformed config entry: {entry:?}"));
+            let (name, value) = entry.split_once(':').ok_or_else(malformed)?;
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(malformed());
+            }
+            let value = match value.trim().to_lowercase().as_str() {
+                "true" => true,
+                "false" => false,
+                _ => return Err(malformed()),
+            };
+            if bools.exists(name) {
+                return Err(BBoolError::Other(format!("Name already exists: {name}")));
+            }
+            bools.add(name, value)?;
+        }
+        Ok(bools)
+    }
+    /// Serializes the collection as a JSON object of names to values, with names in sorted order.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("b", false)?;
+    /// bools.add("a", true)?;
+    /// assert_eq!(bools.to_json()?, r#"{"a":true,"b":false}"#);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if any stored position cannot be read
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Result<String, BBoolError> {
+        Ok(crate::json::write_object(&self.to_named_map()?))
+    }
+    /// Creates a new `BetterBoolNamed` from a JSON object of names to values, as produced by `to_json`.
+    ///
+    /// # Arguments
+    /// * `s` - The JSON text to parse
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let bools = BN128::from_json(r#"{ "ready": true, "busy": false }"#)?;
+    /// assert!(bools.get("ready")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The input is not a JSON object with only boolean values
+    /// * The object holds more values than the collection can store
+    #[cfg(feature = "json")]
+    pub fn from_json(s: &str) -> Result<Self, BBoolError> {
+        Self::from_named_map(crate::json::parse_object(s)?)
+    }
+    /// Serializes the names whose value is `true` as a JSON array, in sorted order.
+    ///
+    /// Names holding `false` are left out, so this is more compact than `to_json` for
+    /// mostly-false collections.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("b", true)?;
+    /// bools.add("c", false)?;
+    /// bools.add("a", true)?;
+    /// assert_eq!(bools.to_json_true_only()?, r#"["a","b"]"#);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if any stored position cannot be read
+    #[cfg(feature = "json")]
+    pub fn to_json_true_only(&self) -> Result<String, BBoolError> {
+        let names = self
+            .to_named_map()?
+            .into_iter()
+            .filter_map(|(name, value)| value.then_some(name))
+            .collect();
+        Ok(crate::json::write_array(&names))
+    }
+    /// Loads a JSON array of names, as produced by `to_json_true_only`.
+    ///
+    /// Every listed name is set to `true`, adding it if needed, and every other existing
+    /// name is set to `false`. If any listed name can't be added, nothing is changed.
+    ///
+    /// # Arguments
+    /// * `s` - The JSON text to parse
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("busy", true)?;
+    /// bools.load_json_true_only(r#"["ready"]"#)?;
+    /// assert!(bools.get("ready")?);
+    /// assert!(!bools.get("busy")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The input is not a JSON array of distinct strings
+    /// * There isn't room for every listed name that isn't already present
+    /// * A listed name that isn't already present is rejected by the name validator
+    #[cfg(feature = "json")]
+    pub fn load_json_true_only(&mut self, s: &str) -> Result<(), BBoolError> {
+        let names = crate::json::parse_array(s)?;
+        // Validate new names up front so a failure leaves the collection unchanged
+        let new_names: Vec<&String> = names.iter().filter(|name| !self.names.contains_key(*name)).collect();
+        if new_names.len() > self.remaining() {
+            return Err(BBoolError::CollectionCapacityReached);
+        }
+        for name in new_names {
+            self.check_name(name)?;
+        }
+        let positions: Vec<u8> = self.names.values().copied().collect();
+        for position in positions {
+            self.bools.set_at_pos(position, false)?;
+        }
+        for name in &names {
+            self.set(name, true)?;
+        }
+        Ok(())
+    }
+
+    /// Sets or adds a boolean value with the given name.
+    ///
+    /// # Arguments
+    /// * `name` - The name to associate with the boolean value
+    /// * `value` - The boolean value to set
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.set("test", true)?;
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Setting the value fails
+    /// * Adding a new value fails
+    pub fn set(&mut self, name: &str, value: bool) -> Result<(), BBoolError> {
+        match self.names.get(name) {
+            Some(&position) => self.bools.set_at_pos(position, value)?,
+            None => self.add(name, value)?,
+        }
+        Ok(())
+    }
+    /// Sets or adds a boolean value with the given name, scheduling it to be reset to `false` after `ttl`.
+    ///
+    /// Expired flags are only reset when `purge_expired` is called; setting the same name
+    /// again with a TTL replaces its previous expiry. A TTL too long to represent as a
+    /// deadline, such as `Duration::MAX`, means the flag never expires.
+    ///
+    /// # Arguments
+    /// * `name` - The name to associate with the boolean value
+    /// * `value` - The boolean value to set
+    /// * `ttl` - How long the value should last before being reset
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use std::time::Duration;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.set_with_ttl("maintenance", true, Duration::from_secs(60))?;
+    /// assert!(bools.purge_expired().is_empty());
+    /// assert!(bools.get("maintenance")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Setting the value fails
+    /// * Adding a new value fails
+    #[cfg(feature = "std")]
+    pub fn set_with_ttl(&mut self, name: &str, value: bool, ttl: Duration) -> Result<(), BBoolError> {
+        let deadline = Instant::now().checked_add(ttl);
+        self.set(name, value)?;
+        match deadline {
+            Some(deadline) => self.expiries.insert(name.to_string(), deadline),
+            None => self.expiries.remove(name),
+        };
+        Ok(())
+    }
+    /// Resets every flag whose TTL has passed to `false`, returning the names that were reset in sorted order.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use std::time::Duration;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.set_with_ttl("burst", true, Duration::ZERO)?;
+    /// assert_eq!(bools.purge_expired(), vec!["burst".to_string()]);
+    /// assert!(!bools.get("burst")?);
+    /// Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn purge_expired(&mut self) -> Vec<String> {
+        let now = Instant::now();
+        let mut expired: Vec<String> = self
+            .expiries
+            .iter()
+            .filter(|(_, &at)| at <= now)
+            .map(|(name, _)| name.clone())
+            .collect();
+        expired.sort();
+        for name in &expired {
+            self.expiries.remove(name);
+            if let Some(&pos) = self.names.get(name) {
+                // Positions in `names` are always within the store
+                let _ = self.bools.set_at_pos(pos, false);
+            }
+        }
+        expired
+    }
+    /// Toggles the boolean value associated with the given name.
+    ///
+    /// # Arguments
+    /// * name - The name of the boolean value to toggle
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("test", true)?;
+    /// bools.toggle("test")?; // Value is now false
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The name doesn't exist in the collection
+    /// * Setting the toggled value fails
+    pub fn toggle(&mut self, name: &str) -> Result<(), BBoolError> {
+        let current = self.get(name)?;
+        self.set(name, !current)?;
+        Ok(())
+    }
+    /// Reads the value of a named flag and resets it to `false`, like `next_b_res` on the positional types.
+    ///
+    /// Useful for one-shot event flags.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the flag to consume
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("clicked", true)?;
+    /// assert!(bools.consume("clicked")?);
+    /// assert!(!bools.consume("clicked")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the name doesn't exist in the collection
+    pub fn consume(&mut self, name: &str) -> Result<bool, BBoolError> {
+        let &position = self
+            .names
+            .get(name)
+            .ok_or_else(|| BBoolError::NotFound(name.to_string()))?;
+        let value = self.bools.get_at_pos(position)?;
+        self.bools.set_at_pos(position, false)?;
+        Ok(value)
+    }
+
+    /// Checks if a boolean value with the given name exists in the collection.
+    ///
+    /// # Arguments
+    /// * `name` - The name to check for existence
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// let mut bools = BN128::new();
+    /// let exists = bools.exists("test");
+    /// ```
+    pub fn exists(&self, name: &str) -> bool {
+        self.names.contains_key(name)
+    }
+    /// Returns the name assigned to the given bit position, if any.
+    ///
+    /// # Arguments
+    /// * `pos` - The bit position to look up
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("a", true)?;
+    /// bools.add("b", false)?;
+    /// assert_eq!(bools.name_at_pos(1), Some("b"));
+    /// assert_eq!(bools.name_at_pos(2), None);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn name_at_pos(&self, pos: u8) -> Option<&str> {
+        self.names
+            .iter()
+            .find(|&(_, &p)| p == pos)
+            .map(|(name, _)| name.as_str())
+    }
+    /// Returns the alphabetically first name whose value is `true`, or `None` if no flag is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("b", true)?;
+    /// bools.add("a", false)?;
+    /// bools.add("c", true)?;
+    /// assert_eq!(bools.first_true(), Some("b".to_string()));
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn first_true(&self) -> Option<String> {
+        self.iter_true().min().map(str::to_string)
+    }
+    /// Returns the alphabetically last name whose value is `true`, or `None` if no flag is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("b", true)?;
+    /// bools.add("a", true)?;
+    /// bools.add("c", false)?;
+    /// assert_eq!(bools.last_true(), Some("b".to_string()));
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn last_true(&self) -> Option<String> {
+        self.iter_true().max().map(str::to_string)
+    }
+    /// Returns an iterator over the names whose value is `true`, in no particular order.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("a", true)?;
+    /// bools.add("b", false)?;
+    /// assert_eq!(bools.iter_true().collect::<Vec<_>>(), vec!["a"]);
+    /// Ok(())
+    /// }
+    /// ```
+    pub fn iter_true(&self) -> impl Iterator<Item = &str> {
+        self.iter_with_value(true)
+    }
+    /// Returns an iterator over the names whose value is `false`, in no particular order.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("a", true)?;
+    /// bools.add("b", false)?;
+    /// assert_eq!(bools.iter_false().collect::<Vec<_>>(), vec!["b"]);
+    /// Ok(())
+    /// }
+    /// ```
+    pub fn iter_false(&self) -> impl Iterator<Item = &str> {
+        self.iter_with_value(false)
+    }
+    /// Returns the set of names whose value is `true`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("a", true)?;
+    /// bools.add("b", false)?;
+    /// assert!(bools.true_set().contains("a"));
+    /// assert_eq!(bools.true_set().len(), 1);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn true_set(&self) -> HashSet<String> {
+        self.iter_true().map(str::to_string).collect()
+    }
+    /// Returns the set of names whose value is `false`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("a", true)?;
+    /// bools.add("b", false)?;
+    /// assert!(bools.false_set().contains("b"));
+    /// assert_eq!(bools.false_set().len(), 1);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn false_set(&self) -> HashSet<String> {
+        self.iter_false().map(str::to_string).collect()
+    }
+    /// Splits the names by value, returning `(true_names, false_names)` with each sorted.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("b", true)?;
+    /// bools.add("c", false)?;
+    /// bools.add("a", true)?;
+    /// let (on, off) = bools.partition()?;
+    /// assert_eq!(on, vec!["a", "b"]);
+    /// assert_eq!(off, vec!["c"]);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if retrieving any boolean value fails
+    pub fn partition(&self) -> Result<(Vec<String>, Vec<String>), BBoolError> {
+        let (mut true_names, mut false_names) = (Vec::new(), Vec::new());
+        for (name, &position) in &self.names {
+            if self.bools.get_at_pos(position)? {
+                true_names.push(name.clone());
+            } else {
+                false_names.push(name.clone());
+            }
+        }
+        true_names.sort_unstable();
+        false_names.sort_unstable();
+        Ok((true_names, false_names))
+    }
+    /// Groups the names by the segment before the first `separator`, counting the
+    /// `(true, false)` values in each group.
+    ///
+    /// A name without the separator forms a group of its own.
+    ///
+    /// # Arguments
+    /// * `separator` - The character separating a name's namespace from the rest
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
     /// let mut bools = BN128::new();
-    /// let exists = bools.exists("test");
+    /// bools.add("system.ready", true)?;
+    /// bools.add("system.init", false)?;
+    /// bools.add("net.up", true)?;
+    /// let counts = bools.count_by_prefix('.');
+    /// assert_eq!(counts["system"], (1, 1));
+    /// assert_eq!(counts["net"], (1, 0));
+    /// Ok(())
+    /// }
     /// ```
-    pub fn exists(&self, name: &str) -> bool {
-        self.names.contains_key(name)
+    #[must_use]
+    pub fn count_by_prefix(&self, separator: char) -> HashMap<String, (usize, usize)> {
+        let mut counts: HashMap<String, (usize, usize)> = HashMap::new();
+        for (name, &position) in &self.names {
+            let prefix = name.split(separator).next().unwrap_or_default();
+            let entry = counts.entry(prefix.to_string()).or_default();
+            if self.bools.get_at_pos(position).unwrap_or(false) {
+                entry.0 += 1;
+            } else {
+                entry.1 += 1;
+            }
+        }
+        counts
+    }
+    /// Compares two collections by name and value, ignoring bit positions.
+    ///
+    /// Meant for test suites: on mismatch, the `Err` lists every differing name, one per
+    /// line and sorted, as `name: left != right`, with `<missing>` for a name that only
+    /// one side has.
+    ///
+    /// # Arguments
+    /// * `a` - The left-hand collection
+    /// * `b` - The right-hand collection
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut a = BN128::new();
+    /// a.add("x", true)?;
+    /// a.add("y", false)?;
+    /// let mut b = BN128::new();
+    /// b.add("y", true)?;
+    /// b.add("x", true)?;
+    /// assert_eq!(BN128::assert_eq_named(&a, &b), Err("y: false != true".to_string()));
+    /// b.set("y", false)?;
+    /// assert!(BN128::assert_eq_named(&a, &b).is_ok());
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a description of the differences if the collections aren't equal, or of
+    /// the failure if retrieving any boolean value fails
+    pub fn assert_eq_named(a: &Self, b: &Self) -> Result<(), String> {
+        let left = a.to_named_map().map_err(|e| e.to_string())?;
+        let right = b.to_named_map().map_err(|e| e.to_string())?;
+        let names: BTreeSet<&String> = left.keys().chain(right.keys()).collect();
+
+        let show =
+            |value: Option<&bool>| value.map_or_else(|| "<missing>".to_string(), bool::to_string);
+        let diffs: Vec<String> = names
+            .into_iter()
+            .filter(|name| left.get(*name) != right.get(*name))
+            .map(|name| {
+                format!("{name}: {} != {}", show(left.get(name)), show(right.get(name)))
+            })
+            .collect();
+        if diffs.is_empty() {
+            Ok(())
+        } else {
+            Err(diffs.join("\n"))
+        }
+    }
+    /// Returns an iterator over the names whose value equals `value`.
+    fn iter_with_value(&self, value: bool) -> impl Iterator<Item = &str> {
+        self.names
+            .iter()
+            .filter(move |(_, &position)| {
+                self.bools.get_at_pos(position).is_ok_and(|v| v == value)
+            })
+            .map(|(name, _)| name.as_str())
     }
     /// Gets an immutable reference to the raw numeric storage.
     ///
@@ -435,6 +1441,89 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
     pub const fn get_raw(&self) -> &T {
         self.bools.get_raw()
     }
+    /// Returns the raw numeric storage with every bit above the highest named position cleared.
+    ///
+    /// Useful for sending the smallest meaningful value over a wire protocol. Names placed with
+    /// `add_at_pos` beyond `used_bits` are kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::from_num(0b1111_0000);
+    /// bools.add("a", true)?;
+    /// bools.add("b", false)?;
+    /// assert_eq!(bools.packed_value(), 0b01);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn packed_value(&self) -> T {
+        let used = self.names.values().max().map_or(0, |&pos| pos + 1);
+        if used >= BetterBool::<T>::CAP {
+            return *self.get_raw();
+        }
+        *self.get_raw() & !(!T::zero() << used)
+    }
+    /// Returns the number of bit positions assigned so far, i.e. the position the next new name will take.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("a", true)?;
+    /// assert_eq!(bools.used_bits(), 1);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn used_bits(&self) -> u8 {
+        self.next_assign
+    }
+    /// Reverses the physical bit layout of the used bits, keeping each name's value.
+    ///
+    /// Each position `p` moves to `used - 1 - p`, where `used` is `used_bits`, or one past the
+    /// highest named position if that's higher. Unnamed bits in that range move along with
+    /// the named ones; bits past it are untouched. Useful before exporting the raw value to a
+    /// consumer that numbers bits the other way.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.add("a", true)?;
+    /// bools.add("b", false)?;
+    /// bools.add("c", false)?;
+    /// bools.reverse_positions()?;
+    /// assert_eq!(*bools.get_raw(), 0b100);
+    /// assert!(bools.get("a")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if reading or writing any bit fails
+    pub fn reverse_positions(&mut self) -> Result<(), BBoolError> {
+        let used = self
+            .names
+            .values()
+            .map(|&pos| pos + 1)
+            .fold(self.next_assign, u8::max);
+        let bits = self.bools.range(0, used)?;
+        for (pos, &value) in (0..used).rev().zip(&bits) {
+            self.bools.set_at_pos(pos, value)?;
+        }
+        for pos in self.names.values_mut() {
+            *pos = used - 1 - *pos;
+        }
+        self.next_assign = used;
+        Ok(())
+    }
     /// Gets a mutable reference to the raw numeric storage.
     ///
     /// # Examples
@@ -465,15 +1554,247 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
     ///
     /// # Errors
     /// Returns an error if:
-    /// * The collection has128 items)
+    /// * The name is rejected by the name validator
+    /// * No free position is left for the name (see `remaining`)
     /// * Setting the value fails
     pub fn add(&mut self, name: &str, value: bool) -> Result<(), BBoolError> {
-        if self.names.len() >= 128 {
+        self.check_name(name)?;
+        if self.remaining() == 0 {
             return Err(BBoolError::CollectionCapacityReached);
         }
         self.names.insert(name.to_string(), self.next_assign);
         self.bools.set_at_pos(self.next_assign, value)?;
         self.next_assign += 1;
+        self.skip_taken_positions();
+        Ok(())
+    }
+    /// Adds a new boolean value with the given name at an explicit position.
+    ///
+    /// Useful when the bit layout has to match an external format, such as a hardware register.
+    ///
+    /// # Arguments
+    /// * `name` - The name to associate with the boolean value
+    /// * `pos` - The bit position to place the value at
+    /// * `value` - The boolean value to add
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.add_at_pos("ready", 7, true)?;
+    /// bools.add("busy", true)?;
+    /// assert_eq!(*bools.get_raw(), 0b1000_0001);
+    /// assert!(bools.add_at_pos("other", 7, false).is_err());
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The position is out of range
+    /// * The position is already taken by another name
+    /// * The name already exists in the collection
+    pub fn add_at_pos(&mut self, name: &str, pos: u8, value: bool) -> Result<(), BBoolError> {
+        if pos >= BetterBool::<T>::CAP {
+            return Err(BBoolError::InvalidPos(pos, Self::CAP));
+        }
+        self.check_name(name)?;
+        if self.names.contains_key(name) {
+            return Err(BBoolError::Other(format!("Name already exists: {name}")));
+        }
+        if self.is_pos_taken(pos) {
+            return Err(BBoolError::Other(format!("Position already taken: {pos}")));
+        }
+        self.bools.set_at_pos(pos, value)?;
+        self.names.insert(name.to_string(), pos);
+        self.skip_taken_positions();
+        Ok(())
+    }
+    /// Returns how many more names `add` can assign before returning `BBoolError::CollectionCapacityReached`.
+    ///
+    /// This counts the free positions from the next one `add` would use up to `CAP`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// assert_eq!(bools.remaining(), 8);
+    /// bools.add("a", true)?;
+    /// bools.add_at_pos("b", 7, true)?;
+    /// assert_eq!(bools.remaining(), 6);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        (self.next_assign..Self::CAP)
+            .filter(|&pos| !self.is_pos_taken(pos))
+            .count()
+    }
+    /// Returns the fraction of `CAP` taken up by names, from `0.0` when empty to `1.0` when full.
+    ///
+    /// Useful for spotting collections that are close to needing a wider backing type.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.add("a", true)?;
+    /// bools.add("b", false)?;
+    /// assert!((bools.utilization() - 0.25).abs() < f64::EPSILON);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn utilization(&self) -> f64 {
+        // Name counts stay far below the 2^52 where f64 loses precision
+        #[allow(clippy::cast_precision_loss)]
+        let used = self.names.len() as f64;
+        used / f64::from(Self::CAP)
+    }
+    /// Sets a predicate that every new name must pass, replacing any previous one.
+    ///
+    /// `add`, `add_at_pos` and `set` then reject names failing it with `BBoolError::InvalidName`.
+    /// Names already in the collection aren't rechecked. By default, every name is allowed.
+    ///
+    /// # Arguments
+    /// * `f` - Returns whether a name is allowed
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.set_name_validator(|name| !name.is_empty() && !name.contains('/'));
+    /// bools.add("ready", true)?;
+    /// assert!(bools.add("a/b", true).is_err());
+    /// Ok(())
+    /// }
+    /// ```
+    pub fn set_name_validator(&mut self, f: impl Fn(&str) -> bool + Send + Sync + 'static) {
+        self.validator = Some(NameValidator(Arc::new(f)));
+    }
+    /// Points the next position `add` assigns at the lowest free position, restoring a
+    /// consistent state after editing the names through `all_names_mut`.
+    ///
+    /// This also lets `add` reuse positions freed by `delete`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.add("a", true)?;
+    /// bools.all_names_mut().insert("a".to_string(), 1);
+    /// bools.recompute_next_assign();
+    /// bools.add("b", false)?;
+    /// assert_eq!(bools.all_names()["b"], 0);
+    /// Ok(())
+    /// }
+    /// ```
+    pub fn recompute_next_assign(&mut self) {
+        self.next_assign = 0;
+        self.skip_taken_positions();
+    }
+    /// Restores a consistent state after the names or raw store were edited through
+    /// `all_names_mut` or `get_raw_mut`, returning what was changed.
+    ///
+    /// In order, this:
+    /// * removes names whose position is outside the capacity
+    /// * keeps only the lexicographically smallest name at each shared position
+    /// * if `clear_unnamed` is true, clears every set position with no name
+    /// * points the next assigned position at the lowest free position, as `recompute_next_assign` does
+    ///
+    /// # Arguments
+    /// * `clear_unnamed` - Whether to clear set bits that no name refers to
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.add("a", true)?;
+    /// bools.all_names_mut().insert("b".to_string(), 0);
+    /// let report = bools.repair(false);
+    /// assert_eq!(report.aliases, vec!["b".to_string()]);
+    /// assert!(bools.repair(false).is_clean());
+    /// Ok(())
+    /// }
+    /// ```
+    pub fn repair(&mut self, clear_unnamed: bool) -> RepairReport {
+        let mut report = RepairReport::default();
+        let mut entries: Vec<(String, u8)> = self
+            .names
+            .iter()
+            .map(|(name, &pos)| (name.clone(), pos))
+            .collect();
+        entries.sort_unstable();
+
+        let mut owners = HashSet::new();
+        for (name, pos) in entries {
+            if pos >= Self::CAP {
+                report.out_of_range.push(name);
+            } else if !owners.insert(pos) {
+                report.aliases.push(name);
+            }
+        }
+        for name in report.out_of_range.iter().chain(&report.aliases) {
+            self.names.remove(name);
+            #[cfg(feature = "std")]
+            self.expiries.remove(name);
+        }
+
+        if clear_unnamed {
+            for pos in (0..Self::CAP).filter(|pos| !owners.contains(pos)) {
+                // Every position below CAP is valid
+                if self.bools.get_at_pos(pos).unwrap_or(false) {
+                    let _ = self.bools.set_at_pos(pos, false);
+                    report.cleared_unnamed.push(pos);
+                }
+            }
+        }
+
+        let before = self.next_assign;
+        self.recompute_next_assign();
+        if self.next_assign != before {
+            report.next_assign = Some((before, self.next_assign));
+        }
+        report
+    }
+    /// Checks a new name against the validator, if one was set.
+    fn check_name(&self, name: &str) -> Result<(), BBoolError> {
+        match &self.validator {
+            Some(NameValidator(f)) if !f(name) => Err(BBoolError::InvalidName(name.to_string())),
+            _ => Ok(()),
+        }
+    }
+    /// Checks whether any name is currently assigned to the given position.
+    fn is_pos_taken(&self, pos: u8) -> bool {
+        self.names.values().any(|&p| p == pos)
+    }
+    /// Advances `next_assign` past any positions already assigned to a name.
+    fn skip_taken_positions(&mut self) {
+        while self.is_pos_taken(self.next_assign) {
+            self.next_assign += 1;
+        }
+    }
+    /// Ensures that naming `count` bools with `pattern` won't run out of free positions midway.
+    fn check_mass_capacity(&self, count: u8, pattern: &str) -> Result<(), BBoolError> {
+        let new_names = (0..count)
+            .filter(|i| !self.names.contains_key(&pattern.replace("{n}", &i.to_string())))
+            .count();
+        if new_names > self.remaining() {
+            return Err(BBoolError::CollectionCapacityReached);
+        }
         Ok(())
     }
 
@@ -528,12 +1849,114 @@ impl<T: BitwiseOpsCopy> BetterBoolNamed<T> {
         if self.names.contains_key(name) {
             self.set(name, false)?;
             self.names.remove(name);
+            #[cfg(feature = "std")]
+            self.expiries.remove(name);
+        }
+        Ok(())
+    }
+    /// Sets every value to `false` except the ones with the given names, keeping all names in the collection.
+    ///
+    /// Names in `keep` that don't exist are ignored.
+    ///
+    /// # Arguments
+    /// * `keep` - The names of the values to leave untouched
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("a", true)?;
+    /// bools.add("b", true)?;
+    /// bools.clear_except(&["b"])?;
+    /// assert!(!bools.get("a")?);
+    /// assert!(bools.get("b")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if setting any value fails
+    pub fn clear_except(&mut self, keep: &[&str]) -> Result<(), BBoolError> {
+        for (name, &position) in &self.names {
+            if !keep.contains(&name.as_str()) {
+                self.bools.set_at_pos(position, false)?;
+            }
+        }
+        Ok(())
+    }
+    /// Replaces each named value with the result of `f`, called with its name and current value.
+    ///
+    /// Positions without a name are left untouched.
+    ///
+    /// # Arguments
+    /// * `f` - Closure returning the new value for each name
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN8;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN8::new();
+    /// bools.add("debug.trace", false)?;
+    /// bools.add("cache", true)?;
+    /// bools.map_values(|name, value| value || name.starts_with("debug."))?;
+    /// assert!(bools.get("debug.trace")?);
+    /// assert!(bools.get("cache")?);
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if reading or writing any value fails
+    pub fn map_values<F: FnMut(&str, bool) -> bool>(&mut self, mut f: F) -> Result<(), BBoolError> {
+        for (name, &position) in &self.names {
+            let value = self.bools.get_at_pos(position)?;
+            self.bools.set_at_pos(position, f(name, value))?;
         }
         Ok(())
     }
+    /// Captures the current values, names, and position assignments as a `NamedSnapshot`.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::named_bools::BN128;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let mut bools = BN128::new();
+    /// bools.add("a", true)?;
+    /// let snap = bools.snapshot();
+    /// bools.set("a", false)?;
+    /// bools.restore(&snap);
+    /// assert!(bools.get("a")?);
+    /// Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> NamedSnapshot<T> {
+        NamedSnapshot {
+            store: *self.get_raw(),
+            names: self.names.clone(),
+            next_assign: self.next_assign,
+        }
+    }
+    /// Replaces the current values, names, and position assignments with those from a `NamedSnapshot`.
+    ///
+    /// The reader head position is left unchanged.
+    ///
+    /// # Arguments
+    /// * `snap` - The snapshot to restore
+    pub fn restore(&mut self, snap: &NamedSnapshot<T>) {
+        *self.get_raw_mut() = snap.store;
+        self.names.clone_from(&snap.names);
+        self.next_assign = snap.next_assign;
+    }
     /// Clears all stored boolean values and associated names.
     pub fn clear(&mut self) {
         self.names.clear();
+        #[cfg(feature = "std")]
+        self.expiries.clear();
         self.bools.clear();
     }
 }
@@ -578,6 +2001,28 @@ impl<T: BitwiseOpsClone> BetterBoolNamed<T> {
     }
 }
 
+/// Accesses the underlying bits by raw position, regardless of which name owns them.
+impl<T: BitwiseOpsCopy> BoolCollection for BetterBoolNamed<T> {
+    type Error = BBoolError;
+
+    fn get_at_pos(&self, pos: usize) -> Result<bool, BBoolError> {
+        BoolCollection::get_at_pos(&self.bools, pos)
+    }
+
+    fn set_at_pos(&mut self, pos: usize, value: bool) -> Result<(), BBoolError> {
+        BoolCollection::set_at_pos(&mut self.bools, pos, value)
+    }
+
+    fn all(&self) -> Result<Vec<bool>, BBoolError> {
+        self.all_bools()
+    }
+
+    /// Clears both the values and the names.
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
+}
+
 impl<T: BitwiseOpsCopy> IntoIterator for BetterBoolNamed<T>
 {
     type Item = (String, bool);
@@ -588,8 +2033,49 @@ impl<T: BitwiseOpsCopy> IntoIterator for BetterBoolNamed<T>
     }   
 }
 
+/// Formats the bools as `name: value` lines, sorted by name.
+///
+/// If the values can't be read, an `<error: ...>` marker is written instead.
 impl<T: BitwiseOpsCopy> Display for BetterBoolNamed<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:#?}", self.all())
+        match self.all() {
+            Ok(all) => {
+                let mut entries: Vec<_> = all.into_iter().collect();
+                entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+                for (i, (name, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{name}: {value}")?;
+                }
+                Ok(())
+            }
+            Err(e) => write!(f, "<error: {e}>"),
+        }
+    }
+}
+
+/// Sets or adds every name-value pair from the iterator.
+///
+/// # Panics
+/// Panics if adding a value would exceed the collection's capacity.
+impl<T: BitwiseOpsCopy> Extend<(String, bool)> for BetterBoolNamed<T> {
+    fn extend<I: IntoIterator<Item = (String, bool)>>(&mut self, iter: I) {
+        for (name, value) in iter {
+            self.set(&name, value)
+                .expect("Failed to add bool, collection capacity exceeded");
+        }
+    }
+}
+
+/// Builds a collection from name-value pairs, assigning positions in iteration order.
+///
+/// # Panics
+/// Panics if the iterator yields more values than the collection can store.
+impl<T: BitwiseOpsCopy> FromIterator<(String, bool)> for BetterBoolNamed<T> {
+    fn from_iter<I: IntoIterator<Item = (String, bool)>>(iter: I) -> Self {
+        let mut bools = Self::new();
+        bools.extend(iter);
+        bools
     }
 }
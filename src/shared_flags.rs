@@ -0,0 +1,113 @@
+use crate::error::BBoolError;
+use crate::named_bools::BN128;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+/// A change notification: the flag's name and its new value
+pub type FlagChange = (String, bool);
+
+/// A thread-safe registry of named flags, backed by a `BetterBoolNamed<u128>`
+///
+/// Cloning a `SharedFlags` gives another handle to the same registry, so it can be
+/// passed to other threads. Subscribers are notified of every change.
+#[derive(Clone, Default)]
+pub struct SharedFlags {
+    /// The flags themselves
+    flags: Arc<RwLock<BN128>>,
+    /// Senders for every subscriber, pruned once their receiver is dropped
+    subscribers: Arc<Mutex<Vec<Sender<FlagChange>>>>,
+}
+
+impl SharedFlags {
+    /// Creates a new, empty `SharedFlags` registry.
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::shared_flags::SharedFlags;
+    /// let flags = SharedFlags::new();
+    /// assert!(!flags.exists("beta"));
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the value of a flag.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the flag to get
+    ///
+    /// # Errors
+    /// Returns an error if the flag doesn't exist
+    pub fn get(&self, name: &str) -> Result<bool, BBoolError> {
+        // A panic mid-update can't leave the bits in an invalid state, so poisoning is ignored
+        self.flags
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)
+    }
+
+    /// Checks if a flag with the given name exists.
+    ///
+    /// # Arguments
+    /// * `name` - The name to check
+    #[must_use]
+    pub fn exists(&self, name: &str) -> bool {
+        self.flags
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .exists(name)
+    }
+
+    /// Sets or adds a flag, notifying subscribers if its value changed.
+    ///
+    /// Adding a new flag counts as a change.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the flag to set
+    /// * `value` - The value to set
+    ///
+    /// # Examples
+    /// ```
+    /// use btypes::shared_flags::SharedFlags;
+    /// use anyhow::Result;
+    /// fn main() -> Result<()> {
+    /// let flags = SharedFlags::new();
+    /// let changes = flags.subscribe();
+    /// flags.set("beta", true)?;
+    /// flags.set("beta", true)?; // Unchanged, so no notification
+    /// assert_eq!(changes.try_recv()?, ("beta".to_string(), true));
+    /// assert!(changes.try_recv().is_err());
+    /// Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the flag is new and the registry is full
+    pub fn set(&self, name: &str, value: bool) -> Result<(), BBoolError> {
+        let mut flags = self.flags.write().unwrap_or_else(PoisonError::into_inner);
+        if flags.get(name).ok() == Some(value) {
+            return Ok(());
+        }
+        flags.set(name, value)?;
+
+        // Notify while still holding the write lock, so subscribers see changes in the order they were made
+        self.subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|tx| tx.send((name.to_string(), value)).is_ok());
+        drop(flags);
+        Ok(())
+    }
+
+    /// Returns a channel that receives `(name, new_value)` for every change made after this call.
+    #[must_use]
+    pub fn subscribe(&self) -> Receiver<FlagChange> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(tx);
+        rx
+    }
+}
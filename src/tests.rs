@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod bool_tests {
-    use crate::bbool::B128;
-    use crate::named_bools::BN128;
+    use crate::bbool::{PopcountOrd, B128, B16, B32, B64, B8};
+    use crate::error::BBoolError;
+    use crate::named_bools::{BetterBoolNamed, BN128, BN16, BN8};
+    use serde::de::value::MapDeserializer;
+    use serde::de::IntoDeserializer;
+    use serde::ser::Impossible;
+    use serde::{Deserialize, Serialize};
 
     #[test]
     fn test_basic_operations() {
@@ -155,298 +160,4310 @@ mod bool_tests {
 
         assert_eq!(bool.reader_head_pos, 128);
     }
-}
-
-/// Example usage and tests for BetterString
-#[cfg(test)]
-mod string_tests {
-    use crate::bstring::BetterString;
-    use std::str::FromStr;
 
     #[test]
-    fn test_basic_operations() {
-        let str1 = BetterString::new("Hello");
-        assert_eq!(str1.len(), 5);
-        assert!(!str1.is_empty());
-        assert_eq!(str1.to_uppercase(), BetterString::from("HELLO"));
-        assert_eq!(str1.to_lowercase(), BetterString::from("hello"));
-    }    
+    fn test_sort_in_place_matches_sorted() {
+        let mut bools = B128::from_num(0b1011_0010_0110);
+        let sorted = bools.sorted().unwrap();
+
+        bools.sort().unwrap();
+        assert_eq!(bools.get_raw(), sorted.get_raw());
+        assert_eq!(bools.all().unwrap(), sorted.all().unwrap());
+        assert_eq!(bools.get_raw().count_ones(), 6);
+    }
 
     #[test]
-    fn test_string_validation() {
-        let email = BetterString::new("test@example.com");
-        let url = BetterString::new("https://www.example.com");
-        let ipv4 = BetterString::new("192.168.1.1");
+    fn test_all_through_shared_ref() {
+        let bools = B8::from_num(0b0000_0101);
+        let shared = &bools;
 
-        assert!(email.is_valid_email());
-        assert!(url.is_valid_url());
-        assert!(ipv4.is_valid_ipv4());
+        let all = shared.all().unwrap();
+        assert_eq!(all, vec![true, false, true, false, false, false, false, false]);
+        assert_eq!(shared.sorted().unwrap().all().unwrap().iter().filter(|&&b| b).count(), 2);
     }
 
     #[test]
-    fn test_pattern_matching() {
-        let text = BetterString::new("Hello, World! Hello");
-        assert!(text.matches_pattern(r"^Hello"));
+    fn test_first_and_last_true() {
+        let mut bools = BN128::new();
+        assert_eq!(bools.first_true(), None);
+        assert_eq!(bools.last_true(), None);
 
-        let matches = text.find_all("Hello");
-        assert_eq!(matches.len(), 2);
+        bools.set("delta", true).unwrap();
+        bools.set("alpha", false).unwrap();
+        bools.set("charlie", true).unwrap();
+        bools.set("bravo", true).unwrap();
+        bools.set("echo", false).unwrap();
 
-        let replaced = text.replace_all("Hello", "Hi");
-        assert_eq!(replaced.to_string(), "Hi, World! Hi");
+        assert_eq!(bools.first_true().as_deref(), Some("bravo"));
+        assert_eq!(bools.last_true().as_deref(), Some("delta"));
+
+        bools.mass_toggle(&["bravo", "charlie", "delta"]).unwrap();
+        assert_eq!(bools.first_true(), None);
     }
 
     #[test]
-    fn test_encoding() {
-        let original = BetterString::new("Test String");
-        let encoded = original.to_base64();
-        let decoded = BetterString::from_base64(&encoded).unwrap();
-        assert_eq!(original, decoded);
+    fn test_add_at_pos() {
+        let mut bools = BN8::new();
+        assert!(bools.add_at_pos("bit1", 1, true).is_ok());
+        assert!(bools.add_at_pos("bit0", 0, true).is_ok());
+        assert_eq!(*bools.get_raw(), 0b11);
 
-        let url_text = BetterString::new("Hello World!");
-        let url_encoded = url_text.to_url_encoded();
-        let url_decoded = BetterString::from_url_encoded(&url_encoded).unwrap();
-        assert_eq!(url_text, url_decoded);
+        // Collisions and invalid positions are rejected
+        assert!(bools.add_at_pos("other", 1, false).is_err());
+        assert!(bools.add_at_pos("bit0", 5, false).is_err());
+        assert!(bools.add_at_pos("too_far", 8, true).is_err());
+
+        // Regular adds skip the taken slots
+        assert!(bools.add("next", true).is_ok());
+        assert_eq!(bools.all_names()["next"], 2);
+        assert!(bools.add_at_pos("bit3", 3, false).is_ok());
+        assert!(bools.add("after", true).is_ok());
+        assert_eq!(bools.all_names()["after"], 4);
     }
 
     #[test]
-    fn test_arithmetic_operations() {
-        let str1 = BetterString::new("Hello");
-        let str2 = BetterString::new(" World");
+    fn test_named_map_round_trip() {
+        let mut bools = BN128::new();
+        bools.set("zeta", true).unwrap();
+        bools.set("alpha", false).unwrap();
+        bools.set("mid", true).unwrap();
 
-        // Addition
-        let combined = str1.clone() + str2.clone();
-        assert_eq!(combined.to_string(), "Hello World");
+        let map = bools.to_named_map().unwrap();
+        let restored = BN128::from_named_map(map.clone()).unwrap();
 
-        // Multiplication
-        let repeated = str1.clone() * 3;
-        assert_eq!(repeated.to_string(), "HelloHelloHello");
+        // Positions are reassigned in sorted order, but values survive
+        assert_eq!(restored.all_names()["alpha"], 0);
+        assert_eq!(restored.all().unwrap(), bools.all().unwrap());
+        assert_eq!(restored.to_named_map().unwrap(), map);
 
-        // Subtraction
-        let str3 = BetterString::new("Hello World");
-        let str4 = BetterString::new("World");
-        let subtracted = str3 - str4;
-        assert_eq!(subtracted.to_string(), "Hello ");
+        let too_many = (0..9).map(|i| (format!("f{i}"), true)).collect();
+        assert!(BN8::from_named_map(too_many).is_err());
     }
 
     #[test]
-    fn test_utility_methods() {
-        let text = BetterString::new("  Hello World  ");
-        assert_eq!(text.trim(), BetterString::from("Hello World"));
+    fn test_clear_except() {
+        let mut bools = BN128::new();
+        bools.mass_set(4, "flag_{n}", "true{r}").unwrap();
+        bools.set("off", false).unwrap();
 
-        let words: Vec<BetterString> = text
-            .split(" ")
-            .into_iter()
-            .filter(|s| !s.is_empty())
-            .collect();
-        assert_eq!(words, vec!["Hello", "World"]);
+        bools.clear_except(&["flag_1", "off", "missing"]).unwrap();
+        assert_eq!(
+            bools.mass_get(&["flag_0", "flag_1", "flag_2", "flag_3", "off"]).unwrap(),
+            vec![false, true, false, false, false]
+        );
+        assert_eq!(bools.all_names().len(), 5);
+    }
 
-        let palindrome = BetterString::new("A man a plan a canal Panama");
-        assert!(palindrome.is_palindrome());
+    #[test]
+    fn test_fits() {
+        const _: () = B128::assert_capacity::<100>();
+
+        assert!(B8::fits(0));
+        assert!(B8::fits(8));
+        assert!(!B8::fits(9));
+        assert!(B128::fits(128));
+        assert!(!B128::fits(129));
     }
 
     #[test]
-    fn test_string_properties() {
-        let numeric = BetterString::new("12345");
-        let alpha = BetterString::new("abcde");
-        let alphanum = BetterString::new("abc123");
-        let whitespace = BetterString::new("   ");
+    fn test_extend_and_from_iter() {
+        let pairs = vec![
+            ("a".to_string(), true),
+            ("b".to_string(), false),
+            ("c".to_string(), true),
+        ];
+        let bools: BN128 = pairs.clone().into_iter().collect();
+        assert_eq!(bools.all().unwrap(), pairs.into_iter().collect());
 
-        assert!(numeric.is_numeric());
-        assert!(alpha.is_alphabetic());
-        assert!(alphanum.is_alphanumeric());
-        assert!(whitespace.is_whitespace());
+        let mut extended = BN8::new();
+        extended.extend(vec![("x".to_string(), true), ("a".to_string(), true)]);
+        extended.extend(vec![("x".to_string(), false)]);
+        assert!(!extended.get("x").unwrap());
+        assert_eq!(extended.all_names().len(), 2);
     }
 
     #[test]
-    fn test_error_handling() {
-        let empty = BetterString::new("");
-        assert!(empty.safe_split(",").is_err());
+    fn test_packed_value() {
+        let mut bools = BN128::from_num(u128::MAX);
+        bools.mass_set(5, "flag_{n}", "true,false{r}").unwrap();
 
-        let invalid_substring = BetterString::new("test");
-        assert!(invalid_substring.substring(5, 10).is_err());
+        assert_eq!(bools.used_bits(), 5);
+        let packed = bools.packed_value();
+        assert_eq!(packed, 0b10101);
+        assert_eq!(packed >> bools.used_bits(), 0);
+
+        let mut full = BN8::from_num(0xFF);
+        full.mass_set(8, "f{n}", "true{r}").unwrap();
+        assert_eq!(full.packed_value(), 0xFF);
     }
 
     #[test]
-    fn test_conversion_traits() {
-        // From String
-        let string = String::from("test");
-        let bstring: BetterString = string.clone().into();
-        assert_eq!(bstring.to_string(), string);
+    fn test_packed_value_keeps_add_at_pos() -> Result<(), BBoolError> {
+        let mut bools = BN8::from_num(0b0100_0000);
+        bools.add_at_pos("hw", 7, true)?;
+        bools.add("a", true)?;
+        assert_eq!(bools.used_bits(), 1);
+        assert_eq!(*bools.get_raw(), 0b1100_0001);
+        // Unnamed position 6 is inside the kept range, since only bits above the top name are cleared
+        assert_eq!(bools.packed_value(), 0b1100_0001);
 
-        // From &str
-        let bstring_from_str: BetterString = "test".into();
-        assert_eq!(bstring_from_str.to_string(), "test");
+        let mut low = BN8::from_num(0b1111_0000);
+        low.add_at_pos("mid", 2, true)?;
+        assert_eq!(low.packed_value(), 0b100);
+        assert_eq!(BN8::from_num(0xFF).packed_value(), 0);
+        Ok(())
+    }
 
-        // FromStr
-        let parsed = BetterString::from_str("test").unwrap();
-        assert_eq!(parsed.to_string(), "test");
+    #[test]
+    fn test_snapshot_restore() {
+        let mut bools = BN128::new();
+        bools.set("a", true).unwrap();
+        bools.set("b", false).unwrap();
+        let original = bools.all().unwrap();
+        let snap = bools.snapshot();
+
+        bools.toggle("a").unwrap();
+        bools.delete("b").unwrap();
+        bools.set("c", true).unwrap();
+
+        bools.restore(&snap);
+        assert_eq!(bools.all().unwrap(), original);
+        assert_eq!(bools.used_bits(), 2);
+
+        // New names continue from the restored position
+        bools.set("d", true).unwrap();
+        assert_eq!(bools.all_names()["d"], 2);
     }
 
     #[test]
-    fn test_iterator_support() {
-        let bstring = BetterString::new("abc");
-        // Test owned iteration
-        let bytes: Vec<u8> = bstring.clone().into_iter().collect();
-        assert_eq!(bytes, vec![b'a', b'b', b'c']);
+    fn test_morton_round_trip() {
+        for (x, y) in [(0, 0), (1, 0), (0, 1), (5, 9), (1234, 5678), (u32::MAX, 0), (u32::MAX, u32::MAX)] {
+            let code = B64::interleave(B32::from_num(x), B32::from_num(y));
+            let (dx, dy) = code.deinterleave();
+            assert_eq!((*dx.get_raw(), *dy.get_raw()), (x, y));
+        }
 
-        // Test reference iteration - corrected version
-        let byte_refs: Vec<&u8> = (&bstring).into_iter().collect();
-        assert_eq!(byte_refs, vec![&b'a', &b'b', &b'c']);
+        // x = 0b10, y = 0b11 -> y1 x1 y0 x0 = 1 1 1 0
+        let code = B64::interleave(B32::from_num(0b10), B32::from_num(0b11));
+        assert_eq!(*code.get_raw(), 0b1110);
+        assert_eq!(*B64::interleave(B32::from_num(u32::MAX), B32::new()).get_raw(), 0x5555_5555_5555_5555);
     }
-}
 
-#[cfg(test)]
-mod inf_named_bools_tests {
-    use crate::error::BBoolError;
-    use crate::inf_named_bools::BNInf;
-    use anyhow::Result;
+    #[test]
+    fn test_shift_drops_out_of_range_bits() {
+        assert_eq!(*(B8::from_num(0b1000_0000) << 1).get_raw(), 0);
+        assert_eq!(*(B16::from_num(0x8000) << 1).get_raw(), 0);
+        assert_eq!(*(B32::from_num(0x8000_0000) << 1).get_raw(), 0);
+        assert_eq!(*(B64::from_num(1 << 63) << 1).get_raw(), 0);
+        assert_eq!(*(B128::from_num(1 << 127) << 1).get_raw(), 0);
+
+        assert_eq!(*(B8::from_num(1) >> 1).get_raw(), 0);
+        assert_eq!(*(B128::from_num(u128::MAX) >> 127).get_raw(), 1);
+
+        // Shifting by the full width or more clears everything instead of overflowing
+        assert_eq!(*(B8::from_num(u8::MAX) << 8).get_raw(), 0);
+        assert_eq!(*(B32::from_num(u32::MAX) >> 200).get_raw(), 0);
+
+        let shifted = B8::from_num(0b0100_0001) << 1;
+        assert_eq!(shifted.all().unwrap(), vec![false, true, false, false, false, false, false, true]);
+    }
 
     #[test]
-    fn test_new_and_default() {
-        let bool = BNInf::new();
-        assert!(bool.bools.store.is_empty());
-        assert!(bool.all_names().is_empty());
+    fn test_mass_set_fn_matches_pattern() {
+        let mut from_fn = BN128::new();
+        let mut from_str = BN128::new();
+        from_fn.mass_set_fn(10, "flag_{n}", |n| n % 2 == 0).unwrap();
+        from_str.mass_set(10, "flag_{n}", "true,false{r}").unwrap();
+
+        assert_eq!(from_fn.all().unwrap(), from_str.all().unwrap());
+        assert_eq!(*from_fn.get_raw(), *from_str.get_raw());
+        assert!(from_fn.mass_set_fn(1, "no_index", |_| true).is_err());
     }
 
     #[test]
-    fn test_from_vec() {
-        let initial = vec![5u8]; // Binary: 00000101
-        let bool = BNInf::from_vec(initial.clone());
-        assert_eq!(bool.bools.store, initial);
-        assert!(bool.all_names().is_empty());
+    fn test_mass_set_over_capacity_is_atomic() {
+        let mut bools = BN8::new();
+        bools.mass_set(5, "a{n}", "true{r}").unwrap();
+        let before = bools.clone();
+
+        // Only 3 slots remain, so 4 new names must be rejected up front
+        assert!(matches!(
+            bools.mass_set(4, "b{n}", "false{r}"),
+            Err(BBoolError::CollectionCapacityReached)
+        ));
+        assert!(bools.mass_set_fn(4, "b{n}", |_| false).is_err());
+        assert_eq!(bools.all().unwrap(), before.all().unwrap());
+        assert_eq!(*bools.get_raw(), *before.get_raw());
+
+        // Existing names don't need new slots
+        bools.mass_set(8, "a{n}", "false{r}").unwrap();
+        assert_eq!(bools.all_names().len(), 8);
     }
 
     #[test]
-    fn test_get_set_operations() -> Result<(), BBoolError> {
-        let mut bool = BNInf::new();
+    fn test_toggle_mask() {
+        let (store, mask) = (0b1100_1010u8, 0b0110_0110u8);
+        let cap_mask = u8::MAX;
+        let mut bools = B8::from_num(store);
+        bools.toggle_mask(mask);
+        assert_eq!(*bools.get_raw(), store ^ (mask & cap_mask));
+        assert_eq!(*bools.get_raw(), 0b1010_1100);
 
-        // Test setting and getting named values
-        bool.set("test1", true)?;
-        assert!(bool.get("test1")?);
+        // Toggling with the same mask twice restores the original value
+        let mut wide = B128::from_num(0xDEAD_BEEF);
+        wide.toggle_mask(u128::MAX);
+        assert_eq!(*wide.get_raw(), !0xDEAD_BEEF);
+        wide.toggle_mask(u128::MAX);
+        assert_eq!(*wide.get_raw(), 0xDEAD_BEEF);
+    }
 
-        bool.set("test2", false)?;
-        assert!(!bool.get("test2")?);
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_round_trip() {
+        let mut bools = BN128::new();
+        bools.set("zeta", true).unwrap();
+        bools.set("al\"pha\n", false).unwrap();
+        bools.set("mid", true).unwrap();
 
-        // Test updating existing value
-        bool.set("test1", false)?;
-        assert!(!bool.get("test1")?);
+        let json = bools.to_json().unwrap();
+        assert_eq!(json, r#"{"al\"pha\n":false,"mid":true,"zeta":true}"#);
+        let restored = BN128::from_json(&json).unwrap();
+        assert_eq!(restored.all().unwrap(), bools.all().unwrap());
+        assert_eq!(restored.to_json().unwrap(), json);
 
-        Ok(())
+        let spaced = BN8::from_json(" { \"a\" : true ,\n\"\\u00e9\": false } ").unwrap();
+        assert!(spaced.get("a").unwrap());
+        assert!(!spaced.get("é").unwrap());
+        assert_eq!(BN8::from_json("{}").unwrap().all_names().len(), 0);
     }
 
+    #[cfg(feature = "json")]
     #[test]
-    fn test_mass_operations() -> Result<()> {
-        let mut bool = BNInf::new();
+    fn test_json_true_only_round_trip() -> Result<(), BBoolError> {
+        let mut bools = BN128::new();
+        bools.set("zeta", true)?;
+        bools.set("alpha", false)?;
+        bools.set("mid", true)?;
 
-        // Test mass_set
-        bool.mass_set(3, "test_{n}", "true,false{r}")?;
-        assert!(bool.get("test_0")?);
-        assert!(!bool.get("test_1")?);
-        assert!(bool.get("test_2")?);
+        let json = bools.to_json_true_only()?;
+        assert_eq!(json, r#"["mid","zeta"]"#);
 
-        // Test mass_get
-        let values = bool.mass_get(&["test_0", "test_1", "test_2"])?;
-        assert_eq!(values, vec![true, false, true]);
+        let mut restored = BN128::new();
+        restored.set("alpha", true)?;
+        restored.load_json_true_only(&json)?;
+        assert_eq!(restored.all()?, bools.all()?);
 
-        // Test mass_toggle
-        bool.mass_toggle(&["test_0", "test_1"])?;
-        assert!(!bool.get("test_0")?);
-        assert!(bool.get("test_1")?);
+        assert!(restored.load_json_true_only(r#"["a","a"]"#).is_err());
+        assert!(restored.load_json_true_only(r#"{"a":true}"#).is_err());
+        Ok(())
+    }
 
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_true_only_failed_load_keeps_values() -> Result<(), BBoolError> {
+        let mut bools = BN8::new();
+        for i in 0..7 {
+            bools.add(&format!("f{i}"), i % 2 == 0)?;
+        }
+        let before = bools.all()?;
+
+        // Only one position is left for two new names
+        assert!(matches!(
+            bools.load_json_true_only(r#"["f1","new_a","new_b"]"#),
+            Err(BBoolError::CollectionCapacityReached)
+        ));
+        assert_eq!(bools.all()?, before);
+
+        bools.set_name_validator(|name| !name.contains('/'));
+        assert!(matches!(
+            bools.load_json_true_only(r#"["f1","bad/name"]"#),
+            Err(BBoolError::InvalidName(_))
+        ));
+        assert_eq!(bools.all()?, before);
+
+        // Existing names don't need room or validation
+        bools.load_json_true_only(r#"["f1","fresh"]"#)?;
+        assert!(bools.get("f1")? && bools.get("fresh")? && !bools.get("f0")?);
         Ok(())
     }
 
+    #[cfg(feature = "json")]
     #[test]
-    fn test_all_and_sorted() -> Result<()> {
-        let mut bool = BNInf::new();
+    fn test_json_malformed() {
+        for bad in [
+            "",
+            "[]",
+            r#"{"a":1}"#,
+            r#"{"a":true,}"#,
+            r#"{"a":true"#,
+            r#"{"a":true} extra"#,
+            r#"{"a":true,"a":false}"#,
+            "{ a: true }",
+            r#"{"a":tru}"#,
+        ] {
+            assert!(
+                matches!(BN128::from_json(bad), Err(BBoolError::Other(_))),
+                "accepted {bad:?}"
+            );
+        }
 
-        // Set up test data
-        bool.set("c", true)?;
-        bool.set("a", false)?;
-        bool.set("b", true)?;
+        let too_many = format!(
+            "{{{}}}",
+            (0..9).map(|i| format!("\"f{i}\":true")).collect::<Vec<_>>().join(",")
+        );
+        assert!(BN8::from_json(&too_many).is_err());
+    }
 
-        // Test all()
-        let all_pairs = bool.all()?;
-        assert_eq!(all_pairs.len(), 3);
-        assert!(!all_pairs["a"]);
-        assert!(all_pairs["b"]);
-        assert!(all_pairs["c"]);
+    #[test]
+    fn test_is_all_false_and_true() {
+        assert!(B8::new().is_all_false() && !B8::new().is_all_true());
+        assert!(B8::from_num(u8::MAX).is_all_true() && !B8::from_num(u8::MAX).is_all_false());
+        assert!(!B8::from_num(0b0100_0000).is_all_false() && !B8::from_num(0b0100_0000).is_all_true());
 
-        // Test sorted()
-        let sorted = bool.sorted()?;
-        let sorted_pairs = sorted.all()?;
-        let mut keys: Vec<_> = sorted_pairs.keys().collect();
-        keys.sort();
-        assert_eq!(keys, vec!["a", "b", "c"]);
+        assert!(B16::from_num(u16::MAX).is_all_true());
+        assert!(!B16::from_num(0x7FFF).is_all_true());
+        assert!(B32::new().is_all_false());
+        assert!(B64::from_num(u64::MAX).is_all_true());
+        assert!(!B64::from_num(1 << 40).is_all_false());
+        assert!(B128::from_num(u128::MAX).is_all_true());
+        assert!(!B128::from_num(u128::MAX >> 1).is_all_true());
+    }
 
-        Ok(())
+    #[test]
+    fn test_iter_true_and_false() {
+        let mut bools = BN128::new();
+        bools.mass_set(6, "f{n}", "true,false,false{r}").unwrap();
+
+        let mut on: Vec<&str> = bools.iter_true().collect();
+        let mut off: Vec<&str> = bools.iter_false().collect();
+        on.sort_unstable();
+        off.sort_unstable();
+        assert_eq!(on, vec!["f0", "f3"]);
+        assert_eq!(off, vec!["f1", "f2", "f4", "f5"]);
+
+        bools.toggle("f1").unwrap();
+        assert_eq!(bools.iter_true().count(), 3);
+        assert_eq!(BN8::new().iter_true().count(), 0);
     }
 
     #[test]
-    fn test_exists_and_delete() -> Result<()> {
-        let mut bool = BNInf::new();
+    fn test_from_num_with_names() {
+        let register: u128 = 0b1010_0110;
+        let names = ["en", "tx", "rx", "irq", "dma", "err", "rst", "pwr"];
+        let bools = BN128::from_num_with_names(register, &names).unwrap();
 
-        bool.set("test", true)?;
-        assert!(bool.exists("test"));
+        for (pos, name) in names.iter().enumerate() {
+            assert_eq!(bools.get(name).unwrap(), register & (1 << pos) != 0, "{name}");
+        }
+        assert_eq!(*bools.get_raw(), register);
 
-        bool.delete("test")?;
-        assert!(!bool.exists("test"));
+        // New names continue after the seeded ones
+        let mut bools = bools;
+        bools.add("extra", true).unwrap();
+        assert_eq!(bools.all_names()["extra"], 8);
 
-        Ok(())
+        let too_many: Vec<String> = (0..9).map(|i| format!("n{i}")).collect();
+        let too_many: Vec<&str> = too_many.iter().map(String::as_str).collect();
+        assert!(matches!(
+            BN8::from_num_with_names(0, &too_many),
+            Err(BBoolError::CollectionCapacityReached)
+        ));
+        assert!(BN8::from_num_with_names(0, &["a", "a"]).is_err());
     }
 
     #[test]
-    fn test_raw_access() {
-        let mut bool = BNInf::from_vec(vec![5]); // Binary: 00000101
+    fn test_mass_get_map() {
+        let mut bools = BN128::new();
+        bools.mass_set(5, "f{n}", "true,false{r}").unwrap();
 
-        assert_eq!(bool.get_raw(), &vec![5]);
+        let map = bools.mass_get_map(&["f0", "f1", "f4"]).unwrap();
+        let mut keys: Vec<&str> = map.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, vec!["f0", "f1", "f4"]);
+        assert!(map["f0"] && !map["f1"] && map["f4"]);
 
-        let raw_mut = bool.get_raw_mut();
-        raw_mut[0] = 3; // Binary: 00000011
-        assert_eq!(bool.get_raw(), &vec![3]);
+        assert!(matches!(
+            bools.mass_get_map(&["f0", "missing"]),
+            Err(BBoolError::NotFound(_))
+        ));
+        assert!(bools.mass_get_map(&[]).unwrap().is_empty());
     }
 
     #[test]
-    fn test_cap() -> Result<()> {
-        let mut bool = BNInf::with_cap(8);
-        bool.set("test1", true)?;
-        bool.set("test2", true)?;
+    fn test_named_mass_set_strict() {
+        let mut bools = BN8::new();
+        bools.mass_set_strict(3, "b{n}", "true,false,true").unwrap();
+        assert_eq!(bools.mass_get(&["b0", "b1", "b2"]).unwrap(), vec![true, false, true]);
 
-        assert!(bool.cap() == 8);
+        assert!(matches!(
+            bools.mass_set_strict(3, "c{n}", "true,false"),
+            Err(BBoolError::InvalidPattern(_))
+        ));
+        assert!(matches!(
+            bools.mass_set_strict(2, "c{n}", "true,false,true"),
+            Err(BBoolError::InvalidPattern(_))
+        ));
+        assert!(!bools.exists("c0"));
 
-        let mut bool2 = BNInf::with_cap(9);
-        bool2.set("test1", true)?;
-        bool2.set("test2", true)?;
+        // The lenient version still ignores the extra value
+        bools.mass_set(2, "c{n}", "true,false,true").unwrap();
+        assert!(bools.exists("c1") && !bools.exists("c2"));
+    }
 
-        assert!(bool.cap() == 8);
+    #[test]
+    fn test_named_name_at_pos() {
+        let mut bools = BN8::new();
+        bools.add("a", true).unwrap();
+        bools.add_at_pos("z", 5, false).unwrap();
+        bools.add("b", false).unwrap();
 
-        Ok(())
+        assert_eq!(bools.name_at_pos(0), Some("a"));
+        assert_eq!(bools.name_at_pos(1), Some("b"));
+        assert_eq!(bools.name_at_pos(5), Some("z"));
+        assert_eq!(bools.name_at_pos(2), None);
+        assert_eq!(bools.name_at_pos(200), None);
+
+        bools.delete("a").unwrap();
+        assert_eq!(bools.name_at_pos(0), None);
     }
 
     #[test]
-    fn test_clear() -> Result<()> {
-        let mut bool = BNInf::new();
-        bool.set("test1", true)?;
-        bool.set("test2", true)?;
+    fn test_named_true_false_sets() {
+        let mut bools = BN8::new();
+        bools.mass_set(6, "f{n}", "true,false,false{r}").unwrap();
 
-        bool.clear();
-        assert!(bool.all_names().is_empty());
-        assert!(bool.bools.store.is_empty());
+        let true_set = bools.true_set();
+        let false_set = bools.false_set();
+        assert_eq!(true_set.len(), 2);
+        assert!(true_set.contains("f0") && true_set.contains("f3"));
+        assert_eq!(false_set.len(), 4);
+        for name in ["f1", "f2", "f4", "f5"] {
+            assert!(false_set.contains(name));
+        }
+        assert!(true_set.is_disjoint(&false_set));
+        assert!(BN8::new().true_set().is_empty());
+    }
 
-        Ok(())
+    #[test]
+    fn test_next_b_wrapping() {
+        let mut bools = B8::from_num(0b0110_0101);
+        let reads: Vec<bool> = (0..B8::CAP + 2).map(|_| bools.next_b_wrapping()).collect();
+        let cap = usize::from(B8::CAP);
+        assert_eq!(reads[..2], reads[cap..]);
+        assert_eq!(
+            reads[..cap],
+            [true, false, true, false, false, true, true, false]
+        );
+        assert_eq!(*bools.ghp(), 2);
+
+        let mut bools = B128::new();
+        for _ in 0..B128::CAP {
+            bools.inc_wrapping();
+        }
+        assert_eq!(*bools.ghp(), 0);
     }
 
     #[test]
-    fn test_error_conditions() -> Result<()> {
-        let mut bool = BNInf::new();
+    #[cfg(feature = "inf_bools")]
+    fn test_transpose_round_trip() {
+        let rows: Vec<B64> = [0u64, 1, 0b1010, u64::MAX, 1 << 63, 0xDEAD_BEEF, 42, 7, 1 << 32]
+            .into_iter()
+            .map(B64::from_num)
+            .collect();
+        let planes = B64::transpose(&rows);
+        assert_eq!(planes.len(), 64);
+        for (bit, plane) in planes.iter().enumerate() {
+            for (i, row) in rows.iter().enumerate() {
+                assert_eq!(
+                    plane.get_at_pos(i).unwrap(),
+                    row.get_at_pos(u8::try_from(bit).unwrap()).unwrap()
+                );
+            }
+        }
 
-        // Test getting non-existent name
-        assert!(bool.get("nonexistent").is_err());
+        let back = B64::detranspose(&planes, rows.len()).unwrap();
+        assert_eq!(
+            back.iter().map(|row| *row.get_raw()).collect::<Vec<_>>(),
+            rows.iter().map(|row| *row.get_raw()).collect::<Vec<_>>()
+        );
+        assert!(B64::detranspose(&vec![crate::inf_bbool::BInf::new(); 65], 1).is_err());
+    }
 
-        // Test invalid pattern in mass_set
-        assert!(bool.mass_set(3, "test", "true,false{r}").is_err()); // Missing {n}
-        assert!(bool.mass_set(3, "test_{n}", "true").is_err()); // Insufficient values
+    #[test]
+    fn test_acquire_release() {
+        let mut slots = B128::new();
+        for expected in 0..B128::CAP {
+            assert_eq!(slots.acquire_first_free(), Some(expected));
+        }
+        assert!(slots.is_all_true());
+        assert_eq!(slots.acquire_first_free(), None);
 
-        Ok(())
+        slots.release(77).unwrap();
+        slots.release(12).unwrap();
+        assert_eq!(slots.acquire_first_free(), Some(12));
+        assert_eq!(slots.acquire_first_free(), Some(77));
+        assert_eq!(slots.acquire_first_free(), None);
+        assert!(matches!(slots.release(128), Err(BBoolError::InvalidPos(128, 128))));
+    }
+
+    #[test]
+    fn test_sort_by_popcount() {
+        let raw = [0b1111u32, 0, 0b1000_0000, 0b11, 0b101, u32::MAX, 0b1, 0b1_0000_0001];
+        let mut masks: Vec<B32> = raw.iter().map(|&n| B32::from_num(n)).collect();
+        masks.sort_by(B32::cmp_by_popcount);
+        let sorted: Vec<u32> = masks.iter().map(|m| *m.get_raw()).collect();
+        assert_eq!(
+            sorted,
+            vec![0, 0b1, 0b1000_0000, 0b11, 0b101, 0b1_0000_0001, 0b1111, u32::MAX]
+        );
+        assert!(sorted.windows(2).all(|w| w[0].count_ones() <= w[1].count_ones()));
+
+        let mut wrapped: Vec<PopcountOrd<u32>> =
+            raw.iter().map(|&n| PopcountOrd(B32::from_num(n))).collect();
+        wrapped.sort();
+        assert_eq!(wrapped.iter().map(|m| *m.0.get_raw()).collect::<Vec<_>>(), sorted);
+    }
+
+    #[test]
+    fn test_copy_bits_from() {
+        let src = B16::from_num(0b1010_1010_1010_1010);
+        let mut dst = B16::from_num(0b0000_1111_0000_1111);
+        let mask = 0b1111_0000_0011_1100;
+        dst.copy_bits_from(&src, mask);
+
+        for pos in 0..B16::CAP {
+            let expected = if (mask >> pos) & 1 == 1 {
+                src.get_at_pos(pos).unwrap()
+            } else {
+                (0b0000_1111_0000_1111u16 >> pos) & 1 == 1
+            };
+            assert_eq!(dst.get_at_pos(pos).unwrap(), expected);
+        }
+        assert_eq!(*dst.get_raw(), 0b1010_1111_0010_1011);
+
+        let before = *dst.get_raw();
+        dst.copy_bits_from(&B16::from_num(u16::MAX), 0);
+        assert_eq!(*dst.get_raw(), before);
+    }
+
+    #[test]
+    fn test_bitwise_assign_ops() {
+        let masks = [0b0000_0011u8, 0b0011_0000, 0b1000_0001, 0b0100_0100].map(B8::from_num);
+
+        let mut acc = B8::new();
+        acc.shp(5).unwrap();
+        for mask in masks {
+            acc |= mask;
+        }
+        let chained = B8::new() | masks[0] | masks[1] | masks[2] | masks[3];
+        assert_eq!(*acc.get_raw(), *chained.get_raw());
+        assert_eq!(*acc.get_raw(), 0b1111_0111);
+        assert_eq!(*acc.ghp(), 5);
+
+        acc &= B8::from_num(0b0101_0101);
+        assert_eq!(*acc.get_raw(), *(chained & B8::from_num(0b0101_0101)).get_raw());
+        acc ^= B8::from_num(0xFF);
+        assert_eq!(*acc.get_raw(), 0b1010_1010);
+        assert_eq!(*acc.ghp(), 5);
+    }
+
+    #[test]
+    fn test_named_from_config() {
+        let bools = BN128::from_config(" a:true ;b : false;; \n c:TRUE;").unwrap();
+        assert_eq!(bools.all_names().len(), 3);
+        assert!(bools.get("a").unwrap());
+        assert!(!bools.get("b").unwrap());
+        assert!(bools.get("c").unwrap());
+        assert_eq!(bools.name_at_pos(1), Some("b"));
+        assert!(BN128::from_config("  ; ").unwrap().all_names().is_empty());
+
+        assert!(matches!(
+            BN128::from_config("a:true;a:false"),
+            Err(BBoolError::Other(_))
+        ));
+        for malformed in ["a", "a:yes", ":true", "a:true;b=false"] {
+            assert!(
+                matches!(BN128::from_config(malformed), Err(BBoolError::InvalidPattern(_))),
+                "{malformed}"
+            );
+        }
+        let too_many = (0..9).map(|i| format!("f{i}:true")).collect::<Vec<_>>().join(";");
+        assert!(BN8::from_config(&too_many).is_err());
+    }
+
+    #[test]
+    fn test_nearest() {
+        let candidates = [
+            B16::from_num(0b1111_0000_1111_0000),
+            B16::from_num(0b0000_0000_1111_1111),
+            B16::from_num(0b1010_1010_1010_1010),
+            B16::from_num(0b0000_0000_1111_0111),
+        ];
+        let query = B16::from_num(0b0000_0000_1111_0011);
+        assert_eq!(query.hamming_distance(&candidates[1]), 2);
+        assert_eq!(query.hamming_distance(&candidates[3]), 1);
+        assert_eq!(B16::nearest(&query, &candidates), Some(3));
+
+        // Exact match wins, and ties go to the lowest index
+        assert_eq!(B16::nearest(&candidates[2], &candidates), Some(2));
+        let tied = [B16::from_num(0b01), B16::from_num(0b10)];
+        assert_eq!(B16::nearest(&B16::new(), &tied), Some(0));
+        assert_eq!(B16::nearest(&query, &[]), None);
+    }
+
+    #[test]
+    fn test_named_consume() {
+        let mut bools = BN8::new();
+        bools.add("event", true).unwrap();
+        bools.add("other", true).unwrap();
+
+        assert!(bools.consume("event").unwrap());
+        assert!(!bools.consume("event").unwrap());
+        assert!(!bools.get("event").unwrap());
+        assert!(bools.get("other").unwrap());
+        assert!(matches!(bools.consume("missing"), Err(BBoolError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_named_partition() {
+        let mut bools = BN128::new();
+        for (name, value) in [("zeta", true), ("alpha", false), ("mid", true), ("beta", false), ("a", true)] {
+            bools.add(name, value).unwrap();
+        }
+        let (on, off) = bools.partition().unwrap();
+        assert_eq!(on, vec!["a", "mid", "zeta"]);
+        assert_eq!(off, vec!["alpha", "beta"]);
+        assert_eq!(on.len() + off.len(), bools.all_names().len());
+        assert!(on.iter().all(|name| !off.contains(name)));
+    }
+
+    #[test]
+    fn test_named_assert_eq_named() -> Result<(), BBoolError> {
+        let mut a = BN8::new();
+        a.add("alpha", true)?;
+        a.add("beta", false)?;
+        a.add("gamma", true)?;
+        let mut b = BN8::new();
+        b.add("gamma", true)?;
+        b.add("alpha", true)?;
+        b.add("beta", false)?;
+        assert_eq!(BN8::assert_eq_named(&a, &b), Ok(()));
+
+        b.set("beta", true)?;
+        let err = BN8::assert_eq_named(&a, &b).unwrap_err();
+        assert!(err.contains("beta"));
+        assert!(!err.contains("alpha") && !err.contains("gamma"));
+
+        b.set("beta", false)?;
+        b.add("delta", false)?;
+        assert_eq!(
+            BN8::assert_eq_named(&a, &b),
+            Err("delta: <missing> != false".to_string())
+        );
+        Ok(())
+    }
+
+    /// A serializer that only accepts bare integers, writing them as JSON numbers.
+    struct JsonNumber;
+
+    macro_rules! json_number {
+        ($($method:ident($ty:ty)),*) => {
+            $(fn $method(self, v: $ty) -> Result<String, Self::Error> {
+                Ok(v.to_string())
+            })*
+        };
+    }
+
+    impl serde::Serializer for JsonNumber {
+        type Ok = String;
+        type Error = serde::de::value::Error;
+        type SerializeSeq = Impossible<String, Self::Error>;
+        type SerializeTuple = Impossible<String, Self::Error>;
+        type SerializeTupleStruct = Impossible<String, Self::Error>;
+        type SerializeTupleVariant = Impossible<String, Self::Error>;
+        type SerializeMap = Impossible<String, Self::Error>;
+        type SerializeStruct = Impossible<String, Self::Error>;
+        type SerializeStructVariant = Impossible<String, Self::Error>;
+
+        json_number!(
+            serialize_u8(u8), serialize_u16(u16), serialize_u32(u32), serialize_u64(u64),
+            serialize_u128(u128), serialize_i8(i8), serialize_i16(i16), serialize_i32(i32),
+            serialize_i64(i64)
+        );
+
+        fn serialize_bool(self, _: bool) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_f32(self, _: f32) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_f64(self, _: f64) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_char(self, _: char) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_str(self, _: &str) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_bytes(self, _: &[u8]) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_none(self) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_unit(self) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_unit_struct(self, _: &'static str) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_unit_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+        ) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            _: &'static str,
+            _: &T,
+        ) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &T,
+        ) -> Result<String, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_tuple_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_tuple_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStruct, Self::Error> {
+            Err(not_a_number())
+        }
+        fn serialize_struct_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStructVariant, Self::Error> {
+            Err(not_a_number())
+        }
+    }
+
+    fn not_a_number() -> serde::de::value::Error {
+        serde::ser::Error::custom("not a bare number")
+    }
+
+    #[test]
+    fn test_serde_bare_number() {
+        let mut bools = B64::from_num(0b1010);
+        bools.shp(3).unwrap();
+        assert_eq!(bools.serialize(JsonNumber).unwrap(), "10");
+        assert_eq!(
+            B128::from_num(u128::MAX).serialize(JsonNumber).unwrap(),
+            u128::MAX.to_string()
+        );
+
+        let read = B64::deserialize(IntoDeserializer::<serde::de::value::Error>::into_deserializer(
+            10u64,
+        ))
+        .unwrap();
+        assert_eq!(*read.get_raw(), 0b1010);
+        assert_eq!(*read.ghp(), 0);
+
+        // An object is rejected
+        let map: MapDeserializer<_, serde::de::value::Error> =
+            MapDeserializer::new(vec![("store", 10u64)].into_iter());
+        assert!(B64::deserialize(map).is_err());
+    }
+
+    #[test]
+    fn test_named_count_by_prefix() -> Result<(), BBoolError> {
+        let mut bools = BN128::new();
+        bools.add("system.ready", true)?;
+        bools.add("system.init", true)?;
+        bools.add("system.halted", false)?;
+        bools.add("net.up", false)?;
+        bools.add("net.ipv6.enabled", true)?;
+        bools.add("debug", false)?;
+
+        let counts = bools.count_by_prefix('.');
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts["system"], (2, 1));
+        assert_eq!(counts["net"], (1, 1));
+        assert_eq!(counts["debug"], (0, 1));
+        assert!(BN128::new().count_by_prefix('.').is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_sorted_by() -> Result<(), BBoolError> {
+        let mut bools = BN8::new();
+        bools.add("d", false)?;
+        bools.add("b", true)?;
+        bools.add("a", false)?;
+        bools.add("c", true)?;
+
+        // true first, then by name
+        let sorted = bools.sorted_by(|(a, va), (b, vb)| vb.cmp(va).then_with(|| a.cmp(b)))?;
+        let names: Vec<_> = (0..4).filter_map(|pos| sorted.name_at_pos(pos)).collect();
+        assert_eq!(names, vec!["b", "c", "a", "d"]);
+        assert_eq!(*sorted.get_raw(), 0b0011);
+
+        let sorted = bools.sorted_by(|(a, _), (b, _)| b.cmp(a))?;
+        let names: Vec<_> = (0..4).filter_map(|pos| sorted.name_at_pos(pos)).collect();
+        assert_eq!(names, vec!["d", "c", "b", "a"]);
+        assert_eq!(*sorted.get_raw(), 0b0110);
+        assert_eq!(BN8::assert_eq_named(&bools, &sorted), Ok(()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_name_validator() -> Result<(), BBoolError> {
+        let mut bools = BN8::new();
+        bools.add("", true)?; // Everything is allowed by default
+
+        let mut bools = BN8::new();
+        bools.set_name_validator(|name| !name.is_empty() && !name.contains('.'));
+        bools.add("ready", true)?;
+        assert!(matches!(
+            bools.add("", true),
+            Err(BBoolError::InvalidName(name)) if name.is_empty()
+        ));
+        assert!(matches!(
+            bools.set("system.ready", true),
+            Err(BBoolError::InvalidName(name)) if name == "system.ready"
+        ));
+        assert!(matches!(
+            bools.add_at_pos("a.b", 5, true),
+            Err(BBoolError::InvalidName(_))
+        ));
+        assert!(!bools.exists("system.ready"));
+        assert_eq!(*bools.get_raw(), 0b1);
+
+        // The validator survives sorting
+        let mut sorted = bools.sorted()?;
+        assert!(sorted.add("x.y", true).is_err());
+        Ok(())
+    }
+
+    fn check_exact_capacity<T: crate::traits::BitwiseOpsCopy>() -> Result<(), BBoolError> {
+        let mut bools = BetterBoolNamed::<T>::new();
+        let cap = usize::from(BetterBoolNamed::<T>::CAP);
+        for i in 0..cap {
+            assert_eq!(bools.remaining(), cap - i);
+            bools.add(&format!("flag{i}"), true)?;
+        }
+        assert_eq!(bools.remaining(), 0);
+        assert!(matches!(
+            bools.add("overflow", true),
+            Err(BBoolError::CollectionCapacityReached)
+        ));
+        assert!(!bools.exists("overflow"));
+        assert_eq!(bools.all()?.len(), cap);
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_exact_capacity() -> Result<(), BBoolError> {
+        assert_eq!(BetterBoolNamed::<u8>::CAP, 8);
+        assert_eq!(BN128::CAP, 128);
+        check_exact_capacity::<u8>()?;
+        check_exact_capacity::<u16>()?;
+        check_exact_capacity::<u32>()?;
+        check_exact_capacity::<u64>()?;
+        check_exact_capacity::<u128>()
+    }
+
+    #[test]
+    fn test_named_recompute_next_assign() -> Result<(), BBoolError> {
+        let mut bools = BN8::new();
+        bools.add("a", true)?;
+        bools.add("b", true)?;
+        bools.add("c", true)?;
+
+        // Move "a" and "b" onto the positions `add` would use next
+        let names = bools.all_names_mut();
+        names.insert("a".to_string(), 3);
+        names.insert("b".to_string(), 4);
+        bools.recompute_next_assign();
+
+        bools.add("d", false)?;
+        bools.add("e", false)?;
+        bools.add("f", false)?;
+        let positions = bools.all_names();
+        assert_eq!(positions["d"], 0);
+        assert_eq!(positions["e"], 1);
+        assert_eq!(positions["f"], 5);
+        let mut used: Vec<u8> = positions.values().copied().collect();
+        used.sort_unstable();
+        used.dedup();
+        assert_eq!(used.len(), positions.len());
+
+        // Positions freed by delete become reusable
+        bools.delete("e")?;
+        bools.recompute_next_assign();
+        bools.add("g", true)?;
+        assert_eq!(bools.all_names()["g"], 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_reverse_positions() -> Result<(), BBoolError> {
+        let mut bools = BN8::from_num(0b1000_0000);
+        bools.add("a", true)?;
+        bools.add("b", true)?;
+        bools.add("c", false)?;
+        bools.add("d", false)?;
+        bools.add("e", true)?;
+        let before = bools.clone();
+        assert_eq!(*bools.get_raw(), 0b1001_0011);
+
+        bools.reverse_positions()?;
+        // The 5 used bits are mirrored; the unnamed top bit stays put
+        assert_eq!(*bools.get_raw(), 0b1001_1001);
+        assert_eq!(bools.all_names()["a"], 4);
+        assert_eq!(bools.all_names()["e"], 0);
+        assert_eq!(BN8::assert_eq_named(&before, &bools), Ok(()));
+
+        bools.reverse_positions()?;
+        assert_eq!(*bools.get_raw(), *before.get_raw());
+        assert_eq!(bools.all_names(), before.all_names());
+
+        // An explicit position past the assigned ones widens the mirrored range
+        let mut bools = BN8::new();
+        bools.add("low", true)?;
+        bools.add_at_pos("high", 3, false)?;
+        bools.reverse_positions()?;
+        assert_eq!(*bools.get_raw(), 0b1000);
+        assert_eq!(bools.all_names()["high"], 0);
+        bools.add("next", true)?;
+        assert_eq!(bools.all_names()["next"], 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_bytes_round_trip() -> Result<(), BBoolError> {
+        let bools = B32::from_num(0xDEAD_BEEF);
+        let parts = bools.split_bytes();
+        assert_eq!(parts.len(), 4);
+        let bytes: Vec<u8> = parts.iter().map(|part| *part.get_raw()).collect();
+        assert_eq!(bytes, vec![0xEF, 0xBE, 0xAD, 0xDE]);
+        assert_eq!(*B32::from_bytes(&parts)?.get_raw(), 0xDEAD_BEEF);
+
+        assert_eq!(B128::from_num(u128::MAX).split_bytes().len(), 16);
+        assert_eq!(*B8::from_bytes(&B8::from_num(0x5A).split_bytes())?.get_raw(), 0x5A);
+        assert_eq!(*B32::from_bytes(&[B8::from_num(1)])?.get_raw(), 1);
+        assert!(matches!(
+            B16::from_bytes(&parts),
+            Err(BBoolError::CollectionCapacityReached)
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_named_ttl_purge() -> Result<(), BBoolError> {
+        use std::time::Duration;
+        let mut bools = BN8::new();
+        bools.set_with_ttl("flash", true, Duration::from_millis(20))?;
+        bools.set_with_ttl("banner", true, Duration::from_secs(30))?;
+        bools.set("steady", true)?;
+        assert!(bools.purge_expired().is_empty());
+        assert!(bools.get("flash")?);
+
+        std::thread::sleep(Duration::from_millis(40));
+        assert_eq!(bools.purge_expired(), vec!["flash".to_string()]);
+        assert!(!bools.get("flash")?);
+        assert!(bools.get("banner")?);
+        assert!(bools.get("steady")?);
+        // Purged entries don't come back
+        assert!(bools.purge_expired().is_empty());
+
+        // A deleted name doesn't carry its expiry over to a later re-add
+        bools.set_with_ttl("gone", true, Duration::ZERO)?;
+        bools.delete("gone")?;
+        bools.set("gone", true)?;
+        assert!(bools.purge_expired().is_empty());
+        assert!(bools.get("gone")?);
+
+        // A TTL past any representable deadline never expires, and replaces an earlier expiry
+        bools.set_with_ttl("forever", true, Duration::MAX)?;
+        bools.set_with_ttl("banner", true, Duration::ZERO)?;
+        bools.set_with_ttl("banner", true, Duration::MAX)?;
+        assert!(bools.purge_expired().is_empty());
+        assert!(bools.get("forever")?);
+        assert!(bools.get("banner")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_ones_in_range() -> Result<(), BBoolError> {
+        let bools = B32::from_num(0xDEAD_BEEF);
+        for (start, end) in [(0, 32), (0, 0), (3, 17), (16, 32), (31, 32), (5, 6), (10, 10)] {
+            let manual = bools.range(start, end)?.into_iter().filter(|&b| b).count();
+            assert_eq!(bools.count_ones_in_range(start, end)? as usize, manual);
+        }
+        assert_eq!(bools.count_ones_in_range(0, 32)?, 0xDEAD_BEEF_u32.count_ones());
+
+        assert!(matches!(bools.count_ones_in_range(32, 32), Err(BBoolError::InvalidPos(32, 32))));
+        assert!(matches!(bools.count_ones_in_range(0, 33), Err(BBoolError::InvalidPos(33, 32))));
+        assert!(matches!(bools.count_ones_in_range(9, 4), Err(BBoolError::InvalidRange(9, 4))));
+
+        // Full-width range on the widest store
+        assert_eq!(B128::from_num(u128::MAX).count_ones_in_range(0, 128)?, 128);
+        assert_eq!(B128::from_num(u128::MAX).count_ones_in_range(100, 128)?, 28);
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_sorted_stable() -> Result<(), BBoolError> {
+        let mut bools = BN128::new();
+        let inserted = ["Beta", "alpha", "ALPHA", "beta", "Alpha", "gamma", "BETA"];
+        for (i, name) in inserted.iter().enumerate() {
+            bools.add(name, i % 2 == 0)?;
+        }
+
+        // Case-insensitively equal names keep their insertion order
+        let sorted = bools.sorted_stable_by(|(a, _), (b, _)| a.to_lowercase().cmp(&b.to_lowercase()))?;
+        let order: Vec<_> = (0..7).map(|pos| sorted.name_at_pos(pos).unwrap()).collect();
+        assert_eq!(order, ["alpha", "ALPHA", "Alpha", "Beta", "beta", "BETA", "gamma"]);
+        for (i, name) in inserted.iter().enumerate() {
+            assert_eq!(sorted.get(name)?, i % 2 == 0);
+        }
+
+        // Ordering only by value keeps each group in insertion order
+        let by_value = bools.sorted_stable_by(|(_, a), (_, b)| b.cmp(a))?;
+        let order: Vec<_> = (0..7).map(|pos| by_value.name_at_pos(pos).unwrap()).collect();
+        assert_eq!(order, ["Beta", "ALPHA", "Alpha", "BETA", "alpha", "beta", "gamma"]);
+
+        let plain = bools.sorted_stable()?;
+        assert_eq!(plain.all_names(), bools.sorted()?.all_names());
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_to_table() -> Result<(), BBoolError> {
+        let mut bools = BN8::new();
+        assert_eq!(bools.to_table(), "NAME  VALUE");
+
+        bools.add("x", true)?;
+        assert_eq!(bools.to_table(), "NAME  VALUE\nx     true");
+
+        bools.add("maintenance_mode", false)?;
+        bools.add("débug", true)?;
+        let table = bools.to_table();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "NAME              VALUE",
+                "débug             true",
+                "maintenance_mode  false",
+                "x                 true",
+            ]
+        );
+        // The value column starts at the same character offset on every line, even with multi-byte names
+        for line in &lines {
+            let chars: Vec<char> = line.chars().collect();
+            assert_eq!(&chars[16..18], [' ', ' ']);
+            assert_ne!(chars[18], ' ');
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_subset_superset() {
+        let required = B16::from_num(0b0000_0000_0010_0110);
+        let held = B16::from_num(0b1000_0001_0110_0111);
+        assert!(required.is_subset_of(&held));
+        assert!(held.is_superset_of(&required));
+        assert!(!held.is_subset_of(&required));
+        assert!(!required.is_superset_of(&held));
+
+        let missing_one = B16::from_num(0b0000_0000_1010_0110);
+        assert!(!missing_one.is_subset_of(&held));
+        assert!(!held.is_superset_of(&missing_one));
+
+        let same = B16::from_num(held.get_raw_cl());
+        assert!(held.is_subset_of(&same) && held.is_superset_of(&same));
+
+        // The empty mask is a subset of everything, and the full mask a superset
+        assert!(B16::from_num(0).is_subset_of(&required));
+        assert!(B16::from_num(u16::MAX).is_superset_of(&held));
+        assert!(B128::from_num(1 << 127).is_subset_of(&B128::from_num(u128::MAX)));
+    }
+
+    #[test]
+    fn test_clone_head_variants() -> Result<(), BBoolError> {
+        let mut bools = B32::from_num(0xF0F0);
+        bools.shp(12)?;
+
+        let mut at_start = bools.clone_at_start();
+        assert_eq!(*at_start.ghp(), 0);
+        assert_eq!(at_start.get_raw_cl(), 0xF0F0);
+        let mut preserved = bools.clone_preserving_head();
+        assert_eq!(*preserved.ghp(), 12);
+        assert_eq!(preserved.get_raw_cl(), 0xF0F0);
+
+        // Each copy moves and changes independently of the original
+        at_start.set(true)?;
+        preserved.set(false)?;
+        preserved.inc()?;
+        assert_eq!(at_start.get_raw_cl(), 0xF0F1);
+        assert_eq!(preserved.get_raw_cl(), 0xE0F0);
+        assert_eq!(bools.get_raw_cl(), 0xF0F0);
+        assert_eq!(*bools.ghp(), 12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_clone_copy_independent() -> Result<(), BBoolError> {
+        fn duplicate<C: Clone>(c: &C) -> C {
+            c.clone()
+        }
+
+        let mut original = B128::from_num(1 << 100);
+        original.shp(100)?;
+        let mut cloned = duplicate(&original);
+        let mut copied = original;
+
+        cloned.set_at_pos(0, true)?;
+        cloned.shp(3)?;
+        copied.set(false)?;
+        assert_eq!(original.get_raw_cl(), 1 << 100);
+        assert_eq!(*original.ghp(), 100);
+        assert_eq!(cloned.get_raw_cl(), (1 << 100) | 1);
+        assert_eq!(*cloned.ghp(), 3);
+        assert_eq!(copied.get_raw_cl(), 0);
+        assert_eq!(*copied.ghp(), 100);
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_complement() -> Result<(), BBoolError> {
+        use crate::bbool::BetterBool;
+        use crate::traits::BitwiseOpsCopy;
+
+        fn check<T: BitwiseOpsCopy + std::fmt::Debug>(value: T) -> Result<(), BBoolError> {
+            let cap = BetterBool::<T>::CAP;
+            let mut bools = BetterBool::<T>::from_num(value);
+            bools.shp(cap - 1)?;
+            let complement = !bools;
+            assert_eq!(*complement.ghp(), 0);
+            // Every in-capacity bit flips, and there are no others
+            let ones = |b: &BetterBool<T>| b.all().map(|all| all.into_iter().filter(|&v| v).count());
+            assert_eq!(complement.all()?.len(), usize::from(cap));
+            assert_eq!(ones(&complement)? + ones(&bools)?, usize::from(cap));
+            assert_eq!(*(!complement).get_raw(), value);
+            assert_eq!(*(bools & complement).get_raw(), T::zero());
+            Ok(())
+        }
+        check(0u8)?;
+        check(0b1010_0000u8)?;
+        check(0u16)?;
+        check(0x00FF_00F0u32)?;
+        check(u64::MAX)?;
+        check(1u128 << 127)?;
+
+        assert_eq!(*(!B8::from_num(0)).get_raw(), u8::MAX);
+        assert_eq!(*(!B16::from_num(0)).get_raw(), u16::MAX);
+        assert_eq!(*(!B128::from_num(0)).get_raw(), u128::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bool_and_slice() -> Result<(), BBoolError> {
+        let single = B8::from(true);
+        assert!(single.get_at_pos(0)?);
+        assert_eq!(*single.get_raw(), 1);
+        assert_eq!(*B8::from(false).get_raw(), 0);
+
+        let bools = B16::try_from(&[true, true, false, true][..])?;
+        assert_eq!(*bools.get_raw(), 0b1011);
+        assert_eq!(*B8::try_from(&[true; 8][..])?.get_raw(), u8::MAX);
+        assert_eq!(*B32::try_from(&[][..])?.get_raw(), 0);
+
+        assert!(matches!(B8::try_from(&[true; 9][..]), Err(BBoolError::InvalidPos(8, 8))));
+        assert!(matches!(B8::try_from(&[false; 300][..]), Err(BBoolError::InvalidPos(8, 8))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_prefix_or() -> Result<(), BBoolError> {
+        let bools = B8::from_num(0b0000_0100);
+        assert_eq!(*bools.prefix_or().get_raw(), 0b1111_1100);
+        assert_eq!(*B8::from_num(0b1000_0000).prefix_or().get_raw(), 0b1000_0000);
+        assert_eq!(*B16::from_num(1).prefix_or().get_raw(), u16::MAX);
+        assert_eq!(*B128::from_num(1 << 64).prefix_or().get_raw(), u128::MAX << 64);
+        assert_eq!(*B32::from_num(0).prefix_or().get_raw(), 0);
+
+        // Matches a running OR over the positions
+        let value = B64::from_num(0x0000_0100_0000_0010);
+        let sticky = value.prefix_or();
+        let mut seen = false;
+        for pos in 0..B64::CAP {
+            seen |= value.get_at_pos(pos)?;
+            assert_eq!(sticky.get_at_pos(pos)?, seen);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_inc_past_u8_max_head() {
+        let mut bools = B8::new();
+        *bools.ghp_mut() = u8::MAX;
+        assert!(matches!(bools.inc(), Err(BBoolError::InvalidHeadPos(u8::MAX, 8))));
+        *bools.ghp_mut() = 7;
+        assert!(matches!(bools.inc(), Err(BBoolError::InvalidHeadPos(7, 8))));
+    }
+
+    #[test]
+    fn test_bytes_iter() {
+        let value = 0x0123_4567_89AB_CDEF_u64;
+        let bytes: Vec<u8> = B64::from_num(value).bytes().collect();
+        assert_eq!(bytes, value.to_le_bytes());
+        assert_eq!(u64::from_le_bytes(bytes.try_into().unwrap()), value);
+
+        let wide = u128::MAX - (0xF0 << 64);
+        let bytes: Vec<u8> = B128::from_num(wide).bytes().collect();
+        assert_eq!(u128::from_le_bytes(bytes.try_into().unwrap()), wide);
+
+        assert_eq!(B8::from_num(0xA5).bytes().collect::<Vec<_>>(), vec![0xA5]);
+        assert_eq!(B16::from_num(0).bytes().count(), 2);
+    }
+
+    #[test]
+    fn test_radix_round_trip() -> Result<(), BBoolError> {
+        let bools = B32::from_num(0xDEAD_00EF);
+        let hex = bools.to_radix_string(16)?;
+        assert_eq!(hex, "dead00ef");
+        assert_eq!(*B32::from_radix_str(&hex, 16)?.get_raw(), 0xDEAD_00EF);
+        assert_eq!(*B32::from_radix_str("DEAD00EF", 16)?.get_raw(), 0xDEAD_00EF);
+
+        let octal = bools.to_radix_string(8)?;
+        assert_eq!(octal, format!("{:011o}", 0xDEAD_00EF_u32));
+        assert_eq!(*B32::from_radix_str(&octal, 8)?.get_raw(), 0xDEAD_00EF);
+
+        let small = B32::from_num(9);
+        assert_eq!(small.to_radix_string(16)?, "00000009");
+        assert_eq!(small.to_radix_string(10)?, "0000000009");
+        assert_eq!(small.to_radix_string(2)?.len(), 32);
+        assert_eq!(*B32::from_radix_str(&small.to_radix_string(2)?, 2)?.get_raw(), 9);
+        assert_eq!(B128::from_num(u128::MAX).to_radix_string(16)?, "f".repeat(32));
+        assert_eq!(*B128::from_radix_str(&"f".repeat(32), 16)?.get_raw(), u128::MAX);
+
+        assert!(matches!(B32::from_radix_str("1_0000_0000", 16), Err(BBoolError::Other(_))));
+        assert!(matches!(B32::from_radix_str("100000000", 16), Err(BBoolError::CollectionCapacityReached)));
+        assert!(matches!(B32::from_radix_str("9", 8), Err(BBoolError::Other(_))));
+        assert!(matches!(B32::from_radix_str("", 16), Err(BBoolError::Other(_))));
+        assert!(matches!(small.to_radix_string(3), Err(BBoolError::Other(_))));
+        assert!(matches!(small.to_radix_string(0), Err(BBoolError::Other(_))));
+        assert!(matches!(B32::from_radix_str("z", 36), Err(BBoolError::Other(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_repair() -> Result<(), BBoolError> {
+        let mut bools = BN8::new();
+        bools.add("alpha", true)?;
+        bools.add("beta", false)?;
+        bools.add("gamma", true)?;
+        assert!(bools.repair(true).is_clean());
+
+        // Aliased and out-of-range positions, a stray raw bit, and a stale next position
+        bools.all_names_mut().insert("alias".to_string(), 1);
+        bools.all_names_mut().insert("zeta".to_string(), 0);
+        bools.all_names_mut().insert("ghost".to_string(), 200);
+        *bools.get_raw_mut() |= 0b1000_0000;
+        bools.delete("gamma")?;
+
+        let report = bools.repair(true);
+        assert_eq!(report.out_of_range, vec!["ghost".to_string()]);
+        assert_eq!(report.aliases, vec!["beta".to_string(), "zeta".to_string()]);
+        assert_eq!(report.cleared_unnamed, vec![7]);
+        assert_eq!(report.next_assign, Some((3, 2)));
+        assert!(!report.is_clean());
+
+        let mut names: Vec<_> = bools.all_names().iter().map(|(n, &p)| (n.clone(), p)).collect();
+        names.sort_unstable();
+        assert_eq!(names, vec![("alias".to_string(), 1), ("alpha".to_string(), 0)]);
+        assert_eq!(*bools.get_raw(), 0b0000_0001);
+        assert!(bools.repair(true).is_clean());
+
+        // The repaired collection assigns fresh positions without clobbering existing ones
+        bools.add("delta", true)?;
+        assert_eq!(bools.all_names()["delta"], 2);
+        assert!(bools.get("alpha")?);
+
+        // Unnamed bits are kept unless asked to clear them
+        *bools.get_raw_mut() |= 0b0100_0000;
+        assert!(bools.repair(false).is_clean());
+        assert_eq!(*bools.get_raw() & 0b0100_0000, 0b0100_0000);
+        assert_eq!(bools.repair(true).cleared_unnamed, vec![6]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_clone_only_store() -> Result<(), BBoolError> {
+        use crate::traits::{BitwiseOpsClone, Nums};
+        use std::ops::{Add, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, Mul, Not, Shl, Shr};
+
+        // A 16-bit store that is Clone but deliberately not Copy
+        #[derive(Clone, Debug, PartialEq)]
+        struct CloneOnly(u16);
+        impl BitAnd for CloneOnly {
+            type Output = Self;
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+        impl BitOr for CloneOnly {
+            type Output = Self;
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+        impl BitXor for CloneOnly {
+            type Output = Self;
+            fn bitxor(self, rhs: Self) -> Self {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+        impl Shl<u8> for CloneOnly {
+            type Output = Self;
+            fn shl(self, rhs: u8) -> Self {
+                Self(self.0 << rhs)
+            }
+        }
+        impl Shr<u8> for CloneOnly {
+            type Output = Self;
+            fn shr(self, rhs: u8) -> Self {
+                Self(self.0 >> rhs)
+            }
+        }
+        impl BitAndAssign for CloneOnly {
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.0 &= rhs.0;
+            }
+        }
+        impl BitOrAssign for CloneOnly {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+        impl Not for CloneOnly {
+            type Output = Self;
+            fn not(self) -> Self {
+                Self(!self.0)
+            }
+        }
+        impl Add for CloneOnly {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+        impl Mul for CloneOnly {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                Self(self.0 * rhs.0)
+            }
+        }
+        impl num_traits::Zero for CloneOnly {
+            fn zero() -> Self {
+                Self(0)
+            }
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+        impl num_traits::One for CloneOnly {
+            fn one() -> Self {
+                Self(1)
+            }
+        }
+        impl From<u8> for CloneOnly {
+            fn from(value: u8) -> Self {
+                Self(u16::from(value))
+            }
+        }
+        impl Nums for CloneOnly {}
+        impl BitwiseOpsClone for CloneOnly {}
+
+        assert_eq!(BetterBoolNamed::<CloneOnly>::CAP, 16);
+        let empty = BetterBoolNamed::<CloneOnly>::new();
+        assert_eq!(empty.get_raw_cl(), CloneOnly(0));
+
+        let bools = BetterBoolNamed::from_num_with_names(CloneOnly(0b1001), &["a", "b", "c", "d"])?;
+        assert!(bools.get_cl("a")?);
+        assert!(!bools.get_cl("b")?);
+        assert!(bools.get_cl("d")?);
+        assert!(matches!(bools.get_cl("e"), Err(BBoolError::NotFound(_))));
+        assert_eq!(BetterBoolNamed::from_num(CloneOnly(7)).get_raw_cl(), CloneOnly(7));
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_map_values() -> Result<(), BBoolError> {
+        let mut bools = BN8::from_num_with_names(0b1010_0000, &["a", "b", "c"])?;
+        bools.set("b", true)?;
+
+        let mut seen = Vec::new();
+        bools.map_values(|name, value| {
+            seen.push((name.to_string(), value));
+            true
+        })?;
+        seen.sort_unstable();
+        assert_eq!(
+            seen,
+            vec![("a".to_string(), false), ("b".to_string(), true), ("c".to_string(), false)]
+        );
+        assert!(bools.get("a")? && bools.get("b")? && bools.get("c")?);
+        // Positions 5 and 7 have no name and keep their bits
+        assert_eq!(*bools.get_raw(), 0b1010_0111);
+
+        bools.map_values(|name, value| value && name != "b")?;
+        assert_eq!(*bools.get_raw(), 0b1010_0101);
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_all_sorted() -> Result<(), BBoolError> {
+        let mut bools = BN128::new();
+        for (i, name) in ["zulu", "alpha", "Mike", "bravo", "alpha2", "_x"].iter().enumerate() {
+            bools.add(name, i % 2 == 0)?;
+        }
+        let sorted = bools.all_sorted()?;
+        assert!(sorted.windows(2).all(|w| w[0].0 < w[1].0));
+        let names: Vec<&str> = sorted.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["Mike", "_x", "alpha", "alpha2", "bravo", "zulu"]);
+        assert_eq!(sorted, bools.all_sorted()?);
+        for (name, value) in &sorted {
+            assert_eq!(bools.get(name)?, *value);
+        }
+        assert!(BN128::new().all_sorted()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_gather_scatter() -> Result<(), BBoolError> {
+        let source = B16::from_num(0b1100_0000_0010_1001);
+        let positions = [15, 3, 0, 5, 14, 1];
+        let gathered = source.gather(&positions)?;
+        assert_eq!(gathered, vec![true, true, true, true, true, false]);
+        assert!(source.gather(&[])?.is_empty());
+
+        // Scattering the gathered bits back into an empty value restores just that subset
+        let mut target = B16::new();
+        target.scatter(&positions, &gathered)?;
+        assert_eq!(*target.get_raw(), 0b1100_0000_0010_1001);
+
+        // Reordering: write the gathered bits to reversed positions
+        let reversed: Vec<u8> = positions.iter().rev().copied().collect();
+        let mut shuffled = B16::new();
+        shuffled.scatter(&reversed, &gathered)?;
+        assert_eq!(shuffled.gather(&reversed)?, gathered);
+
+        assert!(matches!(source.gather(&[1, 16]), Err(BBoolError::InvalidPos(16, 16))));
+        let before = *target.get_raw();
+        assert!(matches!(target.scatter(&[0, 20], &[false, true]), Err(BBoolError::InvalidPos(20, 16))));
+        assert_eq!(*target.get_raw(), before);
+        assert!(matches!(target.scatter(&[0, 1], &[true]), Err(BBoolError::Other(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_utilization() -> Result<(), BBoolError> {
+        let mut bools = BN16::new();
+        assert!(bools.utilization().abs() < f64::EPSILON);
+        for i in 0..8 {
+            bools.add(&format!("flag{i}"), i % 2 == 0)?;
+        }
+        assert!((bools.utilization() - 0.5).abs() < f64::EPSILON);
+        for i in 8..16 {
+            bools.add(&format!("flag{i}"), false)?;
+        }
+        assert!((bools.utilization() - 1.0).abs() < f64::EPSILON);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fold_bits() {
+        let bools = B64::from_num(0xDEAD_BEEF_0BAD_F00D);
+        let ones = bools.fold_bits(0u32, |acc, _, value| acc + u32::from(value));
+        assert_eq!(ones, bools.get_raw().count_ones());
+        assert_eq!(ones, bools.count_ones_in_range(0, 64).unwrap());
+
+        let positions = B8::from_num(0b1000_0101).fold_bits(Vec::new(), |mut acc, pos, value| {
+            if value {
+                acc.push(pos);
+            }
+            acc
+        });
+        assert_eq!(positions, vec![0, 2, 7]);
+    }
+
+    #[test]
+    fn test_decode_field() -> Result<(), BBoolError> {
+        #[derive(Debug, PartialEq)]
+        enum Speed {
+            Slow,
+            Medium,
+            Fast,
+        }
+        impl TryFrom<u32> for Speed {
+            type Error = u32;
+            fn try_from(value: u32) -> Result<Self, u32> {
+                match value {
+                    0 => Ok(Self::Slow),
+                    1 => Ok(Self::Medium),
+                    2 => Ok(Self::Fast),
+                    other => Err(other),
+                }
+            }
+        }
+
+        // Fields at bits 0-1, 2-3, 4-5 and 6-7 hold 2, 0, 1 and the unmapped 3
+        let register = B8::from_num(0b1101_0010);
+        assert_eq!(register.get_field(0, 8)?, 0b1101_0010);
+        assert_eq!(register.decode_field::<Speed>(0, 2)?, Speed::Fast);
+        assert_eq!(register.decode_field::<Speed>(2, 2)?, Speed::Slow);
+        assert_eq!(register.decode_field::<Speed>(4, 2)?, Speed::Medium);
+        assert!(matches!(
+            register.decode_field::<Speed>(6, 2),
+            Err(BBoolError::Other(_))
+        ));
+
+        assert!(matches!(register.get_field(8, 1), Err(BBoolError::InvalidPos(8, 8))));
+        assert!(matches!(register.get_field(7, 2), Err(BBoolError::InvalidRange(7, 9))));
+        assert!(matches!(register.get_field(0, 0), Err(BBoolError::InvalidRange(0, 0))));
+        assert!(B64::from_num(u64::MAX).get_field(0, 33).is_err());
+        assert_eq!(B64::from_num(u64::MAX).get_field(32, 32)?, u32::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_pos_reports_capacity() {
+        let mut bools = B8::new();
+        let err = bools.set_at_pos(8, true).unwrap_err();
+        assert!(matches!(err, BBoolError::InvalidPos(8, 8)));
+        assert_eq!(err.to_string(), "Invalid position: 8 (capacity 8)");
+        assert_eq!(
+            B64::new().get_at_pos(200).unwrap_err().to_string(),
+            "Invalid position: 200 (capacity 64)"
+        );
+
+        *bools.ghp_mut() = 9;
+        assert_eq!(
+            bools.get().unwrap_err().to_string(),
+            "Invalid head position: 9 (capacity 8)"
+        );
+        let mut named = BN8::new();
+        assert_eq!(
+            named.add_at_pos("late", 12, true).unwrap_err().to_string(),
+            "Invalid position: 12 (capacity 8)"
+        );
+    }
+
+    #[test]
+    fn test_name_list() -> Result<(), BBoolError> {
+        let mut bools = BN128::new();
+        for name in ["zeta", "alpha", "mid", "Beta"] {
+            bools.add(name, true)?;
+        }
+        let names = bools.name_list();
+        assert_eq!(names, vec!["Beta", "alpha", "mid", "zeta"]);
+        // Each name points into the collection's own key storage
+        for name in &names {
+            let (key, _) = bools.all_names().get_key_value(*name).unwrap();
+            assert!(std::ptr::eq(key.as_str(), *name));
+        }
+        assert!(BN8::new().name_list().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_permutation_with_same_popcount() -> Result<(), BBoolError> {
+        // All 2-subsets of 4 positions, using the low nibble of a B8
+        let mut bools = B8::from_num(0b11);
+        let mut seen = vec![*bools.get_raw()];
+        while bools.next_permutation_with_same_popcount()? && *bools.get_raw() < 0b1_0000 {
+            seen.push(*bools.get_raw());
+        }
+        assert_eq!(seen, vec![0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]);
+        assert_eq!(seen.len(), 4 * 3 / 2);
+
+        // Across the full width, every step keeps the popcount and the walk ends at the top
+        let mut bools = B8::from_num(0b111);
+        let mut count = 1;
+        while bools.next_permutation_with_same_popcount()? {
+            assert_eq!(bools.get_raw().count_ones(), 3);
+            count += 1;
+        }
+        assert_eq!(count, 8 * 7 * 6 / 6);
+        assert_eq!(*bools.get_raw(), 0b1110_0000);
+
+        let mut zero = B8::new();
+        assert!(!zero.next_permutation_with_same_popcount()?);
+        assert_eq!(*zero.get_raw(), 0);
+        let mut wide = B128::from_num(1 << 126);
+        assert!(wide.next_permutation_with_same_popcount()?);
+        assert_eq!(*wide.get_raw(), 1 << 127);
+        assert!(!wide.next_permutation_with_same_popcount()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_display_sorted() {
+        let mut bools = BN8::new();
+        bools.set("zeta", true).unwrap();
+        bools.set("alpha", false).unwrap();
+        bools.set("mid", true).unwrap();
+
+        assert_eq!(bools.to_string(), "alpha: false\nmid: true\nzeta: true");
+        assert_eq!(BN8::new().to_string(), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity exceeded")]
+    fn test_extend_past_capacity() {
+        let mut bools = BN8::new();
+        bools.extend((0..9).map(|i| (format!("flag_{i}"), true)));
+    }
+}
+
+/// Example usage and tests for BetterString
+#[cfg(test)]
+mod string_tests {
+    use crate::bstring::{
+        BetterString, Conflict, EncodingInfo, Newline, PasswordPolicy, PolicyViolation, RegexFlags,
+    };
+    use crate::error::BStringError;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_basic_operations() {
+        let str1 = BetterString::new("Hello");
+        assert_eq!(str1.len(), 5);
+        assert!(!str1.is_empty());
+        assert_eq!(str1.to_uppercase(), BetterString::from("HELLO"));
+        assert_eq!(str1.to_lowercase(), BetterString::from("hello"));
+    }    
+
+    #[test]
+    fn test_string_validation() {
+        let email = BetterString::new("test@example.com");
+        let url = BetterString::new("https://www.example.com");
+        let ipv4 = BetterString::new("192.168.1.1");
+
+        assert!(email.is_valid_email());
+        assert!(url.is_valid_url());
+        assert!(ipv4.is_valid_ipv4());
+    }
+
+    #[test]
+    fn test_parse_ipv4() {
+        assert_eq!(
+            BetterString::new("10.0.255.1").parse_ipv4(),
+            Some([10, 0, 255, 1])
+        );
+
+        let three_octets = BetterString::new("1.2.3");
+        assert_eq!(three_octets.parse_ipv4(), None);
+        assert!(!three_octets.is_valid_ipv4());
+        assert!(!BetterString::new("1.2.3.4.x").is_valid_ipv4());
+
+        let leading_zero = BetterString::new("192.168.01.1");
+        assert_eq!(leading_zero.parse_ipv4(), None);
+        assert!(!leading_zero.is_valid_ipv4());
+        assert!(!BetterString::new("1.2.3.256").is_valid_ipv4());
+    }
+
+    #[test]
+    fn test_find_all_literal() {
+        let text = BetterString::new("the cat sat on the mat with the hat");
+        let expected: Vec<usize> = text.find_all("the").into_iter().map(|(i, _)| i).collect();
+        assert_eq!(text.find_all_literal("the"), expected);
+
+        // Non-overlapping, matching regex semantics
+        let repeated = BetterString::new("aaaaa");
+        let expected: Vec<usize> = repeated.find_all("aa").into_iter().map(|(i, _)| i).collect();
+        assert_eq!(repeated.find_all_literal("aa"), expected);
+        assert_eq!(repeated.find_all_literal("aa"), vec![0, 2]);
+
+        // Regex metacharacters are matched literally
+        let dotted = BetterString::new("a.c abc");
+        assert_eq!(dotted.find_all_literal("a.c"), vec![0]);
+        assert!(dotted.find_all_literal("").is_empty());
+        assert!(dotted.find_all_literal("longer than the text").is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_contains() {
+        let text = BetterString::new("please restart the server now");
+        assert!(text.fuzzy_contains("restart", 0));
+        // One substitution, one deletion, one insertion
+        assert!(text.fuzzy_contains("restert", 1));
+        assert!(text.fuzzy_contains("restrt", 1));
+        assert!(text.fuzzy_contains("serverr", 1));
+        assert!(!text.fuzzy_contains("rstrtt", 1));
+        assert!(!text.fuzzy_contains("database", 2));
+
+        // Characters, not bytes, are compared
+        assert!(BetterString::new("naïve approach").fuzzy_contains("naive", 1));
+        assert!(text.fuzzy_contains("", 0));
+        assert_eq!(BetterString::new("flaw").levenshtein("lawn"), 2);
+    }
+
+    #[test]
+    fn test_morse_round_trip() {
+        let phrase = BetterString::new("Meet at 10pm");
+        let morse = phrase.to_morse();
+        assert_eq!(morse, "-- . . - / .- - / .---- ----- .--. --");
+        assert_eq!(morse.from_morse().unwrap(), "MEET AT 10PM");
+
+        // Non-alphanumeric characters are skipped, including whole words
+        assert_eq!(BetterString::new("hi - there!").to_morse().from_morse().unwrap(), "HI THERE");
+
+        assert!(matches!(
+            BetterString::new("... ---- ...").from_morse(),
+            Err(BStringError::ConversionError(_))
+        ));
+        assert!(matches!(
+            BetterString::new("..x").from_morse(),
+            Err(BStringError::ConversionError(_))
+        ));
+    }
+
+    #[test]
+    fn test_password_policy() {
+        let strict = PasswordPolicy {
+            min_length: 10,
+            max_length: Some(16),
+            require_uppercase: true,
+            require_lowercase: true,
+            require_digit: true,
+            require_symbol: true,
+            forbid_whitespace: true,
+        };
+        assert!(BetterString::new("C0rrect-Horse").check_password_policy(&strict).is_empty());
+
+        assert_eq!(
+            BetterString::new("Ab1!").check_password_policy(&strict),
+            vec![PolicyViolation::TooShort { min: 10, actual: 4 }]
+        );
+        assert_eq!(
+            BetterString::new("Abcdefgh1!abcdefgh").check_password_policy(&strict),
+            vec![PolicyViolation::TooLong { max: 16, actual: 18 }]
+        );
+        assert_eq!(
+            BetterString::new("lowercase1!").check_password_policy(&strict),
+            vec![PolicyViolation::MissingUppercase]
+        );
+        assert_eq!(
+            BetterString::new("UPPERCASE1!").check_password_policy(&strict),
+            vec![PolicyViolation::MissingLowercase]
+        );
+        assert_eq!(
+            BetterString::new("NoDigitsHere!").check_password_policy(&strict),
+            vec![PolicyViolation::MissingDigit]
+        );
+        assert_eq!(
+            BetterString::new("NoSymbols123").check_password_policy(&strict),
+            vec![PolicyViolation::MissingSymbol]
+        );
+        assert_eq!(
+            BetterString::new("Has Space 1!").check_password_policy(&strict),
+            vec![PolicyViolation::ContainsWhitespace]
+        );
+
+        // Every violation is reported, not just the first
+        assert_eq!(BetterString::new("   ").check_password_policy(&strict).len(), 6);
+    }
+
+    #[test]
+    fn test_constant_time_eq_agrees_with_eq() {
+        let cases = [
+            ("", ""),
+            ("secret", "secret"),
+            ("secret", "secreT"),
+            ("secret", "Secret"),
+            ("secret", "secrets"),
+            ("héllo", "hello"),
+        ];
+        for (a, b) in cases {
+            let (a, b) = (BetterString::new(a), BetterString::new(b));
+            assert_eq!(a.constant_time_eq(&b), a == b, "{a:?} vs {b:?}");
+            assert_eq!(b.constant_time_eq(&a), a == b);
+        }
+    }
+
+    #[test]
+    fn test_match_ranges() {
+        // Adjacent matches, after multibyte characters
+        let text = BetterString::new("日本ababé");
+        assert_eq!(text.match_ranges("ab").unwrap(), vec![(2, 4), (4, 6)]);
+        assert_eq!(text.match_ranges("é").unwrap(), vec![(6, 7)]);
+        assert_eq!(text.match_ranges("[日本]").unwrap(), vec![(0, 1), (1, 2)]);
+        assert!(text.match_ranges("xyz").unwrap().is_empty());
+
+        // Char ranges differ from the byte offsets find_all reports
+        assert_eq!(text.find_all("ab")[0].0, 6);
+
+        assert!(matches!(
+            text.match_ranges("(unclosed"),
+            Err(BStringError::InvalidOperation(_))
+        ));
+    }
+
+    #[test]
+    fn test_truncate_ellipsis() {
+        let text = BetterString::new("héllo");
+        assert_eq!(text.truncate_ellipsis(10), "héllo");
+        assert_eq!(text.truncate_ellipsis(5), "héllo");
+        assert_eq!(text.truncate_ellipsis(4), "hél…");
+        assert_eq!(text.truncate_ellipsis(2), "h…");
+        assert_eq!(text.truncate_ellipsis(1), "…");
+        assert_eq!(text.truncate_ellipsis(0), "");
+
+        let wide = BetterString::new("日本語のテキスト");
+        assert_eq!(wide.truncate_ellipsis(4), "日本語…");
+        assert_eq!(wide.truncate_ellipsis(4).chars().count(), 4);
+    }
+
+    #[test]
+    fn test_indent_and_dedent() {
+        let block = BetterString::new("a\nb\r\nc");
+        assert_eq!(block.indent("> "), "> a\n> b\n> c");
+        let crlf = BetterString::new("a\r\nb\r\n");
+        assert_eq!(crlf.indent("\t"), "\ta\r\n\tb\r\n");
+
+        let code = BetterString::new("    fn f() {\n        body();\n\n    }\n");
+        assert_eq!(code.dedent(), "fn f() {\n    body();\n\n}\n");
+        assert_eq!(code.dedent().indent("    "), "    fn f() {\n        body();\n    \n    }\n");
+
+        // Tabs and spaces don't share a prefix, and blank lines don't count
+        let mixed = BetterString::new("\t  one\n\t    two\n   \n\tthree");
+        assert_eq!(mixed.dedent(), "  one\n    two\n\nthree");
+        let tab_vs_space = BetterString::new("\tone\n    two");
+        assert_eq!(tab_vs_space.dedent(), tab_vs_space);
+        assert_eq!(BetterString::new("").dedent(), "");
+    }
+
+    #[test]
+    fn test_builder_chain() {
+        let chained = BetterString::new("  hello world ")
+            .with_trim()
+            .with_replaced("world", "there")
+            .with_uppercase();
+
+        let step = BetterString::new("  hello world ").trim();
+        let step = step.replace("world", "there");
+        let step = step.to_uppercase();
+
+        assert_eq!(chained, step);
+        assert_eq!(chained.to_string(), "HELLO THERE");
+    }
+
+    #[test]
+    fn test_content_hash() {
+        assert_eq!(BetterString::new("").content_hash(), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(
+            BetterString::new("hello").content_hash(),
+            0xa430_d846_80aa_bd0b
+        );
+        assert_ne!(
+            BetterString::new("hello").content_hash(),
+            BetterString::new("hellp").content_hash()
+        );
+    }
+
+    #[test]
+    fn test_merge3_clean() {
+        let base = BetterString::new("one\ntwo\nthree\nfour\n");
+        let ours = BetterString::new("ONE\ntwo\nthree\nfour\nfive\n");
+        let theirs = BetterString::new("one\ntwo\nfour\n");
+        assert_eq!(
+            BetterString::merge3(&base, &ours, &theirs).unwrap(),
+            "ONE\ntwo\nfour\nfive\n"
+        );
+
+        // The same change on both sides isn't a conflict
+        let both = BetterString::new("one\n2\nthree\nfour\n");
+        assert_eq!(BetterString::merge3(&base, &both, &both).unwrap(), both);
+    }
+
+    #[test]
+    fn test_merge3_conflict() {
+        let base = BetterString::new("a\nb\nc");
+        let ours = BetterString::new("a\nours\nc");
+        let theirs = BetterString::new("a\ntheirs\nc");
+        let conflicts = BetterString::merge3(&base, &ours, &theirs).unwrap_err();
+        assert_eq!(
+            conflicts,
+            vec![Conflict {
+                base_line: 1,
+                base: vec![BetterString::new("b")],
+                ours: vec![BetterString::new("ours")],
+                theirs: vec![BetterString::new("theirs")],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_split_at_char() {
+        let s = BetterString::new("añ日本🦀z");
+        let chars: Vec<char> = s.as_str().chars().collect();
+        for mid in 0..=chars.len() {
+            let (head, tail) = s.split_at_char(mid).unwrap();
+            assert_eq!(head.as_str().chars().count(), mid);
+            assert_eq!(head.as_str(), chars[..mid].iter().collect::<String>());
+            assert_eq!(tail.as_str(), chars[mid..].iter().collect::<String>());
+        }
+        assert!(matches!(
+            s.split_at_char(chars.len() + 1),
+            Err(BStringError::InvalidOperation(_))
+        ));
+
+        let (head, tail) = BetterString::empty().split_at_char(0).unwrap();
+        assert!(head.is_empty() && tail.is_empty());
+    }
+
+    #[test]
+    fn test_checked_and_lossy_str() {
+        let valid = BetterString::new("héllo");
+        assert_eq!(valid.as_str_checked().unwrap(), "héllo");
+        assert!(matches!(valid.to_string_lossy(), std::borrow::Cow::Borrowed("héllo")));
+
+        let invalid = BetterString::from(vec![b'o', b'k', 0xC3, b'!', 0xFF]);
+        assert!(matches!(
+            invalid.as_str_checked(),
+            Err(BStringError::InvalidUtf8(_))
+        ));
+        assert_eq!(invalid.as_str(), "");
+        assert_eq!(invalid.to_string_lossy(), "ok\u{FFFD}!\u{FFFD}");
+    }
+
+    #[test]
+    fn test_try_chars() {
+        let empty = BetterString::empty();
+        let invalid = BetterString::from(vec![0xC3, 0x28]);
+
+        // chars can't tell the two apart
+        assert_eq!(empty.chars().count(), 0);
+        assert_eq!(invalid.chars().count(), 0);
+
+        assert_eq!(empty.try_chars().unwrap().count(), 0);
+        assert!(matches!(
+            invalid.try_chars(),
+            Err(BStringError::InvalidUtf8(_))
+        ));
+        assert_eq!(
+            BetterString::new("añ🦀").try_chars().unwrap().collect::<Vec<_>>(),
+            vec!['a', 'ñ', '🦀']
+        );
+    }
+
+    #[test]
+    fn test_unique_lines() {
+        let csv = BetterString::new("id,name\r\n2,bob\r\n1,alice\r\n2,bob\r\n3,carol\r\n1,alice");
+        assert!(csv.has_duplicate_lines());
+        assert_eq!(
+            csv.unique_lines(),
+            vec!["id,name", "2,bob", "1,alice", "3,carol"]
+        );
+
+        let distinct = BetterString::new("c\nb\na\n");
+        assert!(!distinct.has_duplicate_lines());
+        assert_eq!(distinct.unique_lines(), vec!["c", "b", "a"]);
+
+        assert!(!BetterString::empty().has_duplicate_lines());
+        assert!(BetterString::empty().unique_lines().is_empty());
+    }
+
+    #[test]
+    fn test_regex_flags() {
+        let text = BetterString::new("BEGIN\nkey = One\nkey = two\nEND");
+
+        let default = text.find_all_with_flags("^key", RegexFlags::default()).unwrap();
+        assert!(default.is_empty());
+
+        let multi_line = RegexFlags {
+            multi_line: true,
+            ..RegexFlags::default()
+        };
+        let keys = text.find_all_with_flags("^key = (?:one|two)$", multi_line).unwrap();
+        assert_eq!(keys, vec![(16, BetterString::new("key = two"))]);
+
+        let insensitive = RegexFlags {
+            case_insensitive: true,
+            ..multi_line
+        };
+        let keys = text.find_all_with_flags("^key = (?:one|two)$", insensitive).unwrap();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0], (6, BetterString::new("key = One")));
+
+        let dotall = RegexFlags {
+            dot_matches_new_line: true,
+            ..RegexFlags::default()
+        };
+        assert!(text.find_all_with_flags("BEGIN.*END", RegexFlags::default()).unwrap().is_empty());
+        assert_eq!(text.find_all_with_flags("BEGIN.*END", dotall).unwrap()[0].1, text);
+
+        assert_eq!(
+            text.replace_all_with_flags("^key", "k", insensitive).unwrap(),
+            "BEGIN\nk = One\nk = two\nEND"
+        );
+        assert!(matches!(
+            text.find_all_with_flags("(", insensitive),
+            Err(BStringError::InvalidOperation(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "inf_bools")]
+    fn test_char_mask() {
+        let mask = BetterString::new("a1b2").char_mask(|c| c.is_ascii_digit());
+        assert_eq!(mask.logical_len(), 4);
+        assert!(mask.get_at_pos(1).unwrap());
+        assert!(mask.get_at_pos(3).unwrap());
+        assert_eq!(mask.rank(4), 2);
+
+        // Bits follow characters, not bytes
+        let mask = BetterString::new("é1").char_mask(|c| c.is_ascii_digit());
+        assert_eq!(mask.all().unwrap(), vec![false, true]);
+
+        let invalid = BetterString::from(vec![0xFF, b'1']);
+        assert!(invalid.char_mask(|c| c.is_ascii_digit()).is_empty());
+    }
+
+    #[test]
+    fn test_repeat_to_len() {
+        assert_eq!(BetterString::repeat_to_len("abc", 7), "abcabca");
+        assert_eq!(BetterString::repeat_to_len("abc", 0), "");
+        assert_eq!(BetterString::repeat_to_len("", 3), "");
+
+        // Multi-byte characters are never split
+        let filler = BetterString::repeat_to_len("é-ü", 5);
+        assert_eq!(filler, "é-üé-");
+        assert_eq!(filler.chars().count(), 5);
+        assert!(filler.as_str_checked().is_ok());
+    }
+
+    #[test]
+    fn test_tokenize_words() {
+        let bstr = BetterString::new("  \"Wait...\" -- she said; (DON'T!)  go?!  ");
+        assert_eq!(bstr.tokenize_words(), vec!["wait", "she", "said", "don't", "go"]);
+        assert!(BetterString::new("... !!! ,").tokenize_words().is_empty());
+        assert!(BetterString::empty().tokenize_words().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "inf_bools")]
+    fn test_parse_bits() {
+        let bits = BetterString::new("1011").parse_bits().unwrap();
+        assert_eq!(bits.logical_len(), 4);
+        assert_eq!(bits.get_raw(), &vec![0b1101]);
+
+        let bits = BetterString::new("0000000001").parse_bits().unwrap();
+        assert_eq!(bits.logical_len(), 10);
+        assert!(bits.get_at_pos(9).unwrap());
+        assert!(BetterString::empty().parse_bits().unwrap().is_empty());
+
+        assert!(matches!(
+            BetterString::new("10x1").parse_bits(),
+            Err(BStringError::ConversionError(_))
+        ));
+        assert!(BetterString::new("1 0").parse_bits().is_err());
+    }
+
+    #[test]
+    fn test_split_once() {
+        let (key, value) = BetterString::new("name=btypes").split_once("=").unwrap();
+        assert_eq!(key, "name");
+        assert_eq!(value, "btypes");
+
+        assert!(BetterString::new("name").split_once("=").is_none());
+
+        let (key, value) = BetterString::new("=value").split_once("=").unwrap();
+        assert!(key.is_empty());
+        assert_eq!(value, "value");
+
+        let (key, value) = BetterString::new("a::b::c").split_once("::").unwrap();
+        assert_eq!(key, "a");
+        assert_eq!(value, "b::c");
+    }
+
+    #[test]
+    fn test_split_any() {
+        let bstr = BetterString::new("red,green;;blue  yellow,");
+        let delimiters = [',', ';', ' '];
+        assert_eq!(
+            bstr.split_any(&delimiters, true),
+            vec!["red", "green", "blue", "yellow"]
+        );
+        assert_eq!(
+            bstr.split_any(&delimiters, false),
+            vec!["red", "green", "", "blue", "", "yellow", ""]
+        );
+        assert_eq!(bstr.split_any(&[], true), vec!["red,green;;blue  yellow,"]);
+        assert!(BetterString::new(",;").split_any(&delimiters, true).is_empty());
+    }
+
+    #[test]
+    fn test_occurrences_matches_div() {
+        let bstr = BetterString::new("abcabcab abc");
+        for needle in ["abc", "ab", "c", " ", "x", "abcabc"] {
+            assert_eq!(bstr.occurrences(needle), bstr.clone() / needle);
+        }
+        assert_eq!(bstr.occurrences("abc"), 3);
+        // Non-overlapping
+        assert_eq!(BetterString::new("aaa").occurrences("aa"), 1);
+
+        assert_eq!(bstr.occurrences(""), 0);
+        assert_eq!(bstr / "", 0);
+        assert_eq!(BetterString::empty().occurrences(""), 0);
+    }
+
+    #[test]
+    fn test_find_iter_matches_find_all() {
+        let bstr = BetterString::new("héllo wörld, hello world");
+        for pattern in [r"\w+", "o", "l*", r"\b", "x", "(?i)h.llo"] {
+            let lazy: Vec<(usize, String)> = bstr
+                .find_iter(pattern)
+                .unwrap()
+                .map(|(i, m)| (i, m.to_string()))
+                .collect();
+            let eager: Vec<(usize, String)> = bstr
+                .find_all(pattern)
+                .into_iter()
+                .map(|(i, m)| (i, m.to_string()))
+                .collect();
+            assert_eq!(lazy, eager, "pattern {pattern:?}");
+        }
+
+        let first = bstr.find_iter(r"\w+").unwrap().next();
+        assert_eq!(first, Some((0, "héllo")));
+        assert!(matches!(
+            bstr.find_iter("("),
+            Err(BStringError::InvalidOperation(_))
+        ));
+    }
+
+    #[test]
+    fn test_code_points_round_trip() {
+        let bstr = BetterString::new("aé€😀");
+        let cps = bstr.code_points().unwrap();
+        assert_eq!(cps, vec![0x61, 0xE9, 0x20AC, 0x1F600]);
+        assert_eq!(BetterString::from_code_points(&cps).unwrap(), bstr);
+        assert!(BetterString::from_code_points(&[]).unwrap().is_empty());
+
+        for invalid in [0xD800, 0xDFFF, 0x11_0000] {
+            assert!(matches!(
+                BetterString::from_code_points(&[0x61, invalid]),
+                Err(BStringError::ConversionError(_))
+            ));
+        }
+        assert!(matches!(
+            BetterString::from(vec![0xFF]).code_points(),
+            Err(BStringError::InvalidUtf8(_))
+        ));
+    }
+
+    #[test]
+    fn test_eq_ignore_case() {
+        let eq = |a: &str, b: &str| BetterString::new(a).eq_ignore_case(&BetterString::new(b));
+        assert!(eq("Hello, World", "hELLO, wORLD"));
+        assert!(!eq("Hello", "Hello!"));
+
+        assert!(eq("Straße", "STRASSE"));
+        assert!(eq("straße", "Strasse"));
+        assert!(eq("ÄRGER", "ärger"));
+        assert!(eq("ΣΊΣΥΦΟΣ", "σίσυφος"));
+        assert!(!eq("Äpfel", "Apfel"));
+
+        let invalid = BetterString::from(vec![0xFF, b'A']);
+        assert!(invalid.eq_ignore_case(&invalid));
+        assert!(!invalid.eq_ignore_case(&BetterString::from(vec![0xFF, b'a'])));
+    }
+
+    #[test]
+    fn test_common_prefix_suffix() {
+        let items: Vec<BetterString> = ["config.prod.yaml", "config.dev.yaml", "config.yaml"]
+            .into_iter()
+            .map(BetterString::new)
+            .collect();
+        assert_eq!(BetterString::common_prefix(&items), "config.");
+        assert_eq!(BetterString::common_suffix(&items), ".yaml");
+
+        let disjoint = [BetterString::new("abc"), BetterString::new("xyz")];
+        assert!(BetterString::common_prefix(&disjoint).is_empty());
+        assert!(BetterString::common_suffix(&disjoint).is_empty());
+
+        let single = [BetterString::new("only")];
+        assert_eq!(BetterString::common_prefix(&single), "only");
+        assert_eq!(BetterString::common_suffix(&single), "only");
+
+        assert!(BetterString::common_prefix(&[]).is_empty());
+        assert!(BetterString::common_suffix(&[]).is_empty());
+
+        // Shared bytes within a multi-byte character aren't a shared character
+        let accents = [BetterString::new("né"), BetterString::new("nè")];
+        assert_eq!(BetterString::common_prefix(&accents), "n");
+        let nested = [BetterString::new("über"), BetterString::new("üb")];
+        assert_eq!(BetterString::common_prefix(&nested), "üb");
+        assert_eq!(BetterString::common_suffix(&[BetterString::new("aé"), BetterString::new("bé")]), "é");
+    }
+
+    #[test]
+    fn test_base64_wrapped() {
+        let data = BetterString::new("x".repeat(100));
+        let wrapped = data.to_base64_wrapped(64);
+        let lines: Vec<&str> = wrapped.as_str().split('\n').collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[..2].iter().all(|line| line.len() == 64));
+        assert_eq!(lines[2].len(), 136 - 128);
+        assert!(!wrapped.as_str().ends_with('\n'));
+        assert_eq!(wrapped.as_str().replace('\n', ""), data.to_base64().as_str());
+
+        assert_eq!(BetterString::from_base64(&wrapped).unwrap(), data);
+        // Other whitespace, including CRLF line endings, is tolerated too
+        let crlf = BetterString::new(format!("{}\r\n", wrapped.as_str().replace('\n', "\r\n  ")));
+        assert_eq!(BetterString::from_base64(&crlf).unwrap(), data);
+        assert_eq!(BetterString::decode_all_base64(&[wrapped]).unwrap(), vec![data.clone()]);
+
+        assert_eq!(data.to_base64_wrapped(0), data.to_base64());
+        assert_eq!(BetterString::new("").to_base64_wrapped(64), "");
+        assert!(BetterString::from_base64(&BetterString::new("YQ=\n=!")).is_err());
+    }
+
+    #[test]
+    fn test_for_each_line() {
+        let text = BetterString::new("first\r\nsecond\n\nfourth\n");
+        let mut count = 0;
+        let mut total_len = 0;
+        text.for_each_line(|line| {
+            count += 1;
+            total_len += line.len();
+        });
+        assert_eq!(count, 4);
+        assert_eq!(total_len, "firstsecondfourth".len());
+
+        let mut visited = Vec::new();
+        let result = text.try_for_each_line(|line| {
+            if line.is_empty() {
+                return Err(visited.len());
+            }
+            visited.push(line.to_string());
+            Ok(())
+        });
+        assert_eq!(result, Err(2));
+        assert_eq!(visited, vec!["first", "second"]);
+
+        let mut calls = 0;
+        assert_eq!(
+            BetterString::new("a\nb").try_for_each_line(|_| {
+                calls += 1;
+                Ok::<(), ()>(())
+            }),
+            Ok(())
+        );
+        assert_eq!(calls, 2);
+        BetterString::new("").for_each_line(|_| panic!("no lines expected"));
+    }
+
+    #[test]
+    fn test_escape_debug_round_trip() {
+        let mut bytes = b"col1\tcol2\r\nline two\\end\x1b[0m".to_vec();
+        bytes.push(0xFE);
+        bytes.extend_from_slice("é\u{85}".as_bytes());
+        let raw = BetterString::from(bytes);
+
+        let escaped = raw.escape_debug();
+        assert_eq!(
+            escaped,
+            r"col1\tcol2\r\nline two\\end\x1b[0m\xfeé\u{85}"
+        );
+        assert!(escaped.as_str().chars().all(|c| !c.is_control()));
+        assert_eq!(escaped.unescape().unwrap(), raw);
+
+        assert_eq!(BetterString::new("plain").escape_debug(), "plain");
+        assert_eq!(BetterString::new(r"\0\x41\u{1F600}").unescape().unwrap(), "\0A😀");
+        for bad in [r"\", r"\q", r"\x4", r"\xzz", r"\u{}", r"\u{d800}", r"\u{41", r"\u41"] {
+            assert!(
+                matches!(BetterString::new(bad).unescape(), Err(BStringError::EncodingError(_))),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_canonicalize_newlines() {
+        let mixed = BetterString::new("one\r\ntwo\rthree\nfour\r\r\n");
+        assert_eq!(mixed.canonicalize_newlines(), "one\ntwo\nthree\nfour\n\n");
+        assert_eq!(mixed.canonicalize_newlines().detect_newline(), Newline::Lf);
+        assert_eq!(BetterString::new("plain").canonicalize_newlines(), "plain");
+    }
+
+    #[test]
+    fn test_expand_tabs() {
+        // Tabs at columns 0, 1, 3 and 4 all land on the next multiple of 4
+        let text = BetterString::new("\tx\ta\tabc\td");
+        assert_eq!(text.expand_tabs(4), "    x   a   abc d");
+        assert_eq!(BetterString::new("abcd\te").expand_tabs(4), "abcd    e");
+
+        // Columns restart on every line, whatever its ending
+        let lines = BetterString::new("ab\tc\r\n\td\re\tf");
+        assert_eq!(lines.expand_tabs(8), "ab      c\r\n        d\re       f");
+
+        // Columns count characters, not bytes
+        assert_eq!(BetterString::new("é\tx").expand_tabs(2), "é x");
+        assert_eq!(BetterString::new("a\tb").expand_tabs(0), "ab");
+    }
+
+    #[test]
+    fn test_merge_overlap() {
+        let merge = |a: &str, b: &str| BetterString::new(a).merge_overlap(&BetterString::new(b));
+
+        // Partial overlap
+        assert_eq!(merge("abcdef", "defghi"), "abcdefghi");
+        // The longest overlap wins over shorter ones
+        assert_eq!(merge("abab", "ababc"), "ababc");
+        // Full overlap, either way round
+        assert_eq!(merge("abc", "abc"), "abc");
+        assert_eq!(merge("xabc", "bc"), "xabc");
+        assert_eq!(merge("bc", "bcd"), "bcd");
+        // No overlap concatenates
+        assert_eq!(merge("abc", "xyz"), "abcxyz");
+        assert_eq!(merge("", "xyz"), "xyz");
+        assert_eq!(merge("abc", ""), "abc");
+        // Overlaps only fall on character boundaries
+        assert_eq!(merge("naïve", "ïvety"), "naïvety");
+    }
+
+    #[test]
+    fn test_encoding_info() {
+        let ascii = BetterString::new("hello").encoding_info();
+        assert_eq!(
+            ascii,
+            EncodingInfo { byte_len: 5, char_len: Some(5), is_valid_utf8: true, is_ascii: true }
+        );
+
+        let multibyte = BetterString::new("日本語 ok").encoding_info();
+        assert_eq!(
+            multibyte,
+            EncodingInfo { byte_len: 12, char_len: Some(6), is_valid_utf8: true, is_ascii: false }
+        );
+
+        let invalid = BetterString::from(b"ab\xffc".to_vec()).encoding_info();
+        assert_eq!(
+            invalid,
+            EncodingInfo { byte_len: 4, char_len: None, is_valid_utf8: false, is_ascii: false }
+        );
+
+        let empty = BetterString::empty().encoding_info();
+        assert_eq!(
+            empty,
+            EncodingInfo { byte_len: 0, char_len: Some(0), is_valid_utf8: true, is_ascii: true }
+        );
+    }
+
+    #[test]
+    fn test_compiled_pattern_reuse() {
+        let version = BetterString::compile(r"v(\d+)\.(\d+)").unwrap();
+        assert_eq!(version.as_str(), r"v(\d+)\.(\d+)");
+
+        let inputs = [
+            BetterString::new("release v1.2 and v10.0"),
+            BetterString::new("no versions here"),
+            BetterString::new("v3.14"),
+        ];
+        let matched: Vec<bool> = inputs.iter().map(|s| version.is_match(s)).collect();
+        assert_eq!(matched, vec![true, false, true]);
+
+        assert_eq!(
+            version.find_all(&inputs[0]),
+            vec![(8, BetterString::new("v1.2")), (17, BetterString::new("v10.0"))]
+        );
+        assert!(version.find_all(&inputs[1]).is_empty());
+        // Same results as the per-call methods
+        for s in &inputs {
+            assert_eq!(version.find_all(s), s.find_all(r"v(\d+)\.(\d+)"));
+        }
+
+        assert_eq!(version.replace_all(&inputs[0], "$1-$2"), "release 1-2 and 10-0");
+        assert_eq!(version.replace_all(&inputs[2], "$2"), "14");
+
+        let invalid = BetterString::from(b"v1.2\xff".to_vec());
+        assert!(!version.is_match(&invalid));
+        assert!(version.find_all(&invalid).is_empty());
+        assert_eq!(version.replace_all(&invalid, "x"), invalid);
+
+        assert!(matches!(
+            BetterString::compile("v(1"),
+            Err(BStringError::InvalidOperation(_))
+        ));
+    }
+
+    #[test]
+    fn test_lines_trailing_newline() {
+        let original = BetterString::new("one\ntwo\n");
+        let lines = original.to_owned_lines();
+        assert_eq!(lines, vec!["one", "two"]);
+
+        // The trailing line ending is dropped by the split and not restored by the join
+        let joined = BetterString::join_lines(&lines, original.detect_newline());
+        assert_eq!(joined, "one\ntwo");
+        assert_eq!(BetterString::new("one\ntwo").to_owned_lines(), lines);
+
+        let crlf = BetterString::new("one\r\ntwo\r\n");
+        let mut joined = BetterString::join_lines(&crlf.to_owned_lines(), Newline::CrLf);
+        joined += BetterString::new(Newline::CrLf.as_str());
+        assert_eq!(joined, crlf);
+    }
+
+    #[test]
+    fn test_substr_chars() {
+        let text = BetterString::new("añb€c");
+        assert_eq!(text.substr_chars(1, 4).unwrap(), "ñb€");
+        assert_eq!(text.substr_chars(0, 5).unwrap(), "añb€c");
+        assert_eq!(text.substr_chars(3, 3).unwrap(), "");
+        // The byte-based substring would split the 'ñ' here
+        assert!(text.substring(0, 2).is_err());
+
+        assert!(matches!(
+            text.substr_chars(3, 2),
+            Err(BStringError::InvalidOperation(_))
+        ));
+        assert!(matches!(
+            text.substr_chars(2, 6),
+            Err(BStringError::InvalidOperation(_))
+        ));
+    }
+
+    #[cfg(feature = "bools")]
+    #[test]
+    fn test_ascii_charset() {
+        let set = BetterString::new("hello").ascii_charset();
+        for c in "helo".bytes() {
+            assert!(set.get_at_pos(c).unwrap());
+        }
+        assert!(!set.get_at_pos(b'a').unwrap());
+        assert_eq!(set.get_raw().count_ones(), 4);
+
+        assert!(BetterString::new("hole").contains_only_charset(&set));
+        assert!(!BetterString::new("world").contains_only_charset(&set));
+        assert!(!BetterString::new("hé").contains_only_charset(&set));
+    }
+
+    #[test]
+    fn test_pattern_matching() {
+        let text = BetterString::new("Hello, World! Hello");
+        assert!(text.matches_pattern(r"^Hello"));
+
+        let matches = text.find_all("Hello");
+        assert_eq!(matches.len(), 2);
+
+        let replaced = text.replace_all("Hello", "Hi");
+        assert_eq!(replaced.to_string(), "Hi, World! Hi");
+    }
+
+    #[test]
+    fn test_encoding() {
+        let original = BetterString::new("Test String");
+        let encoded = original.to_base64();
+        let decoded = BetterString::from_base64(&encoded).unwrap();
+        assert_eq!(original, decoded);
+
+        let url_text = BetterString::new("Hello World!");
+        let url_encoded = url_text.to_url_encoded();
+        let url_decoded = BetterString::from_url_encoded(&url_encoded).unwrap();
+        assert_eq!(url_text, url_decoded);
+    }
+
+    #[test]
+    fn test_arithmetic_operations() {
+        let str1 = BetterString::new("Hello");
+        let str2 = BetterString::new(" World");
+
+        // Addition
+        let combined = str1.clone() + str2.clone();
+        assert_eq!(combined.to_string(), "Hello World");
+
+        // Multiplication
+        let repeated = str1.clone() * 3;
+        assert_eq!(repeated.to_string(), "HelloHelloHello");
+
+        // Subtraction
+        let str3 = BetterString::new("Hello World");
+        let str4 = BetterString::new("World");
+        let subtracted = str3 - str4;
+        assert_eq!(subtracted.to_string(), "Hello ");
+    }
+
+    #[test]
+    fn test_utility_methods() {
+        let text = BetterString::new("  Hello World  ");
+        assert_eq!(text.trim(), BetterString::from("Hello World"));
+
+        let words: Vec<BetterString> = text
+            .split(" ")
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect();
+        assert_eq!(words, vec!["Hello", "World"]);
+
+        let palindrome = BetterString::new("A man a plan a canal Panama");
+        assert!(palindrome.is_palindrome());
+    }
+
+    #[test]
+    fn test_string_properties() {
+        let numeric = BetterString::new("12345");
+        let alpha = BetterString::new("abcde");
+        let alphanum = BetterString::new("abc123");
+        let whitespace = BetterString::new("   ");
+
+        assert!(numeric.is_numeric());
+        assert!(alpha.is_alphabetic());
+        assert!(alphanum.is_alphanumeric());
+        assert!(whitespace.is_whitespace());
+    }
+
+    #[test]
+    fn test_error_handling() {
+        let empty = BetterString::new("");
+        assert!(empty.safe_split(",").is_err());
+
+        let invalid_substring = BetterString::new("test");
+        assert!(invalid_substring.substring(5, 10).is_err());
+    }
+
+    #[test]
+    fn test_conversion_traits() {
+        // From String
+        let string = String::from("test");
+        let bstring: BetterString = string.clone().into();
+        assert_eq!(bstring.to_string(), string);
+
+        // From &str
+        let bstring_from_str: BetterString = "test".into();
+        assert_eq!(bstring_from_str.to_string(), "test");
+
+        // FromStr
+        let parsed = BetterString::from_str("test").unwrap();
+        assert_eq!(parsed.to_string(), "test");
+    }
+
+    #[test]
+    fn test_iterator_support() {
+        let bstring = BetterString::new("abc");
+        // Test owned iteration
+        let bytes: Vec<u8> = bstring.clone().into_iter().collect();
+        assert_eq!(bytes, vec![b'a', b'b', b'c']);
+
+        // Test reference iteration - corrected version
+        let byte_refs: Vec<&u8> = (&bstring).into_iter().collect();
+        assert_eq!(byte_refs, vec![&b'a', &b'b', &b'c']);
+    }
+
+    #[test]
+    fn test_split_regex() {
+        let text = BetterString::new("one  two\tthree\nfour");
+        assert_eq!(
+            text.split_regex(r"\s+").unwrap(),
+            vec!["one", "two", "three", "four"]
+        );
+
+        let csv = BetterString::new("a,b;c|d");
+        assert_eq!(csv.split_regex(r"[,;|]").unwrap(), vec!["a", "b", "c", "d"]);
+
+        assert!(matches!(
+            csv.split_regex(r"[unclosed"),
+            Err(BStringError::InvalidOperation(_))
+        ));
+    }
+
+    #[test]
+    fn test_matches_full() {
+        let text = BetterString::new("Hello world");
+        assert!(text.matches_pattern("world"));
+        assert!(!text.matches_full("world").unwrap());
+        assert!(text.matches_full(r"Hello \w+").unwrap());
+
+        // Alternations are anchored as a whole
+        assert!(!text.matches_full("Hello|world").unwrap());
+        assert!(text.matches_full("Hello world|nope").unwrap());
+
+        assert!(text.matches_full("(unclosed").is_err());
+    }
+
+    #[test]
+    fn test_batch_base64() {
+        let items = vec![
+            BetterString::new("first"),
+            BetterString::new("second"),
+            BetterString::new("third"),
+        ];
+        let encoded = BetterString::encode_all_base64(&items);
+        assert_eq!(BetterString::decode_all_base64(&encoded).unwrap(), items);
+
+        let mut broken = encoded;
+        broken[1] = BetterString::new("not base64!");
+        match BetterString::decode_all_base64(&broken) {
+            Err(BStringError::EncodingError(msg)) => assert!(msg.contains("index 1")),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_newline_round_trip() {
+        let lf = BetterString::new("one\ntwo\nthree");
+        let crlf = BetterString::new("one\r\ntwo\r\nthree");
+        let mixed = BetterString::new("one\r\ntwo\nthree");
+        let single = BetterString::new("one");
+
+        assert_eq!(lf.detect_newline(), Newline::Lf);
+        assert_eq!(crlf.detect_newline(), Newline::CrLf);
+        assert_eq!(mixed.detect_newline(), Newline::Mixed);
+        assert_eq!(single.detect_newline(), Newline::None);
+
+        for text in [lf, crlf, single] {
+            let joined = BetterString::join_lines(&text.to_owned_lines(), text.detect_newline());
+            assert_eq!(joined, text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod inf_named_bools_tests {
+    use crate::error::BBoolError;
+    use crate::inf_bbool::BInf;
+    use crate::inf_named_bools::BNInf;
+    use anyhow::Result;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_new_and_default() {
+        let bool = BNInf::new();
+        assert!(bool.bools.store.is_empty());
+        assert!(bool.all_names().is_empty());
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let initial = vec![5u8]; // Binary: 00000101
+        let bool = BNInf::from_vec(initial.clone());
+        assert_eq!(bool.bools.store, initial);
+        assert!(bool.all_names().is_empty());
+    }
+
+    #[test]
+    fn test_get_set_operations() -> Result<(), BBoolError> {
+        let mut bool = BNInf::new();
+
+        // Test setting and getting named values
+        bool.set("test1", true)?;
+        assert!(bool.get("test1")?);
+
+        bool.set("test2", false)?;
+        assert!(!bool.get("test2")?);
+
+        // Test updating existing value
+        bool.set("test1", false)?;
+        assert!(!bool.get("test1")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mass_operations() -> Result<()> {
+        let mut bool = BNInf::new();
+
+        // Test mass_set
+        bool.mass_set(3, "test_{n}", "true,false{r}")?;
+        assert!(bool.get("test_0")?);
+        assert!(!bool.get("test_1")?);
+        assert!(bool.get("test_2")?);
+
+        // Test mass_get
+        let values = bool.mass_get(&["test_0", "test_1", "test_2"])?;
+        assert_eq!(values, vec![true, false, true]);
+
+        // Test mass_toggle
+        bool.mass_toggle(&["test_0", "test_1"])?;
+        assert!(!bool.get("test_0")?);
+        assert!(bool.get("test_1")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_and_sorted() -> Result<()> {
+        let mut bool = BNInf::new();
+
+        // Set up test data
+        bool.set("c", true)?;
+        bool.set("a", false)?;
+        bool.set("b", true)?;
+
+        // Test all()
+        let all_pairs = bool.all()?;
+        assert_eq!(all_pairs.len(), 3);
+        assert!(!all_pairs["a"]);
+        assert!(all_pairs["b"]);
+        assert!(all_pairs["c"]);
+
+        // Test sorted()
+        let sorted = bool.sorted()?;
+        let sorted_pairs = sorted.all()?;
+        let mut keys: Vec<_> = sorted_pairs.keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["a", "b", "c"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_exists_and_delete() -> Result<()> {
+        let mut bool = BNInf::new();
+
+        bool.set("test", true)?;
+        assert!(bool.exists("test"));
+
+        bool.delete("test")?;
+        assert!(!bool.exists("test"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_raw_access() {
+        let mut bool = BNInf::from_vec(vec![5]); // Binary: 00000101
+
+        assert_eq!(bool.get_raw(), &vec![5]);
+
+        let raw_mut = bool.get_raw_mut();
+        raw_mut[0] = 3; // Binary: 00000011
+        assert_eq!(bool.get_raw(), &vec![3]);
+    }
+
+    #[test]
+    fn test_cap() -> Result<()> {
+        let mut bool = BNInf::with_cap(8);
+        bool.set("test1", true)?;
+        bool.set("test2", true)?;
+
+        assert!(bool.cap() == 8);
+
+        let mut bool2 = BNInf::with_cap(9);
+        bool2.set("test1", true)?;
+        bool2.set("test2", true)?;
+
+        assert!(bool.cap() == 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clear() -> Result<()> {
+        let mut bool = BNInf::new();
+        bool.set("test1", true)?;
+        bool.set("test2", true)?;
+
+        bool.clear();
+        assert!(bool.all_names().is_empty());
+        assert!(bool.bools.store.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_conditions() -> Result<()> {
+        let mut bool = BNInf::new();
+
+        // Test getting non-existent name
+        assert!(bool.get("nonexistent").is_err());
+
+        // Test invalid pattern in mass_set
+        assert!(bool.mass_set(3, "test", "true,false{r}").is_err()); // Missing {n}
+        assert!(bool.mass_set(3, "test_{n}", "true").is_err()); // Insufficient values
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clear_except() -> Result<()> {
+        let mut bool = BNInf::new();
+        bool.set("keep", true)?;
+        bool.set("drop", true)?;
+
+        bool.clear_except(&["keep"])?;
+        assert!(bool.get("keep")?);
+        assert!(!bool.get("drop")?);
+        assert!(bool.exists("drop"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reads_through_shared_ref() -> Result<()> {
+        let mut bool = BNInf::new();
+        bool.set("a", true)?;
+        bool.set("b", false)?;
+
+        let shared = &bool;
+        assert!(shared.exists("a"));
+        assert!(!shared.exists("c"));
+        assert!(shared.get("a")?);
+        assert_eq!(shared.mass_get(&["a", "b"])?, vec![true, false]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extend_and_from_iter() -> Result<()> {
+        let pairs = vec![("a".to_string(), true), ("b".to_string(), false)];
+        let mut bool: BNInf = pairs.clone().into_iter().collect();
+        assert_eq!(bool.all()?, pairs.into_iter().collect());
+
+        bool.extend((0..20).map(|i| (format!("extra_{i}"), i % 2 == 0)));
+        assert_eq!(bool.all_names().len(), 22);
+        assert!(bool.get("extra_18")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mass_set_fn_matches_pattern() -> Result<()> {
+        let mut from_fn = BNInf::new();
+        let mut from_str = BNInf::new();
+        from_fn.mass_set_fn(50, "flag_{n}", |n| n % 2 == 0)?;
+        from_str.mass_set(50, "flag_{n}", "true,false{r}")?;
+
+        assert_eq!(from_fn.all()?, from_str.all()?);
+        Ok(())
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_round_trip() -> Result<()> {
+        let mut bool = BNInf::new();
+        bool.mass_set(20, "flag_{n}", "true,false,false{r}")?;
+
+        let restored = BNInf::from_json(&bool.to_json()?)?;
+        assert_eq!(restored.all()?, bool.all()?);
+        assert!(BNInf::from_json(r#"{"a":"true"}"#).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_true_only_round_trip() -> Result<()> {
+        let mut bool = BNInf::new();
+        bool.mass_set(20, "flag_{n}", "true,false,false{r}")?;
+
+        let mut restored = BNInf::new();
+        restored.mass_set(20, "flag_{n}", "true{r}")?;
+        restored.load_json_true_only(&bool.to_json_true_only()?)?;
+        assert_eq!(restored.all()?, bool.all()?);
+        assert_eq!(BNInf::new().to_json_true_only()?, "[]");
+        Ok(())
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_true_only_failed_load_keeps_values() -> Result<()> {
+        let mut bool = BNInf::new();
+        bool.bools = BInf::with_max_bits(4);
+        bool.mass_set(3, "flag_{n}", "true{r}")?;
+        let before = bool.all()?;
+
+        assert!(matches!(
+            bool.load_json_true_only(r#"["flag_0","new_a","new_b"]"#),
+            Err(BBoolError::CollectionCapacityReached)
+        ));
+        assert_eq!(bool.all()?, before);
+
+        bool.load_json_true_only(r#"["flag_0","new_a"]"#)?;
+        assert!(bool.get("new_a")? && !bool.get("flag_1")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_complete() -> Result<()> {
+        let mut bool = BNInf::new();
+        bool.mass_set(30, "net.http.{n}", "true{r}")?;
+        bool.mass_set(5, "net.dns.{n}", "false{r}")?;
+        bool.set("disk.io", true)?;
+        bool.set("net", true)?;
+
+        assert_eq!(
+            bool.complete("net.dns.", 10),
+            vec!["net.dns.0", "net.dns.1", "net.dns.2", "net.dns.3", "net.dns.4"]
+        );
+        assert_eq!(bool.complete("net.http.1", 3), vec!["net.http.1", "net.http.10", "net.http.11"]);
+        assert_eq!(bool.complete("net", 2), vec!["net", "net.dns.0"]);
+        assert_eq!(bool.complete("", 100).len(), 37);
+        assert!(bool.complete("cpu", 10).is_empty());
+        assert!(bool.complete("net", 0).is_empty());
+
+        bool.delete("net.dns.0")?;
+        assert_eq!(bool.complete("net.dns.", 1), vec!["net.dns.1"]);
+        bool.clear();
+        assert!(bool.complete("", 10).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mass_get_map() -> Result<()> {
+        let mut bool = BNInf::new();
+        bool.mass_set(10, "f{n}", "true,false{r}")?;
+
+        let map = bool.mass_get_map(&["f1", "f2"])?;
+        assert_eq!(map.len(), 2);
+        assert_eq!((map["f1"], map["f2"]), (false, true));
+        assert!(bool.mass_get_map(&["f1", "f10"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_named_fixed() -> Result<()> {
+        let mut fixed = crate::named_bools::BN64::new();
+        for i in 0..64 {
+            fixed.add(&format!("flag{i}"), i % 3 == 0)?;
+        }
+
+        let mut inf = BNInf::from(fixed.clone());
+        assert_eq!(inf.all_names().len(), 64);
+        for i in 0..64 {
+            let name = format!("flag{i}");
+            assert_eq!(inf.get(&name)?, fixed.get(&name)?);
+            assert_eq!(inf.all_names()[name.as_str()], usize::from(fixed.all_names()[&name]));
+        }
+
+        // The migrated collection keeps growing past the old capacity
+        inf.add("flag64", true)?;
+        assert_eq!(inf.all_names()["flag64"], 64);
+        assert!(inf.get("flag0")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mass_set_strict() -> Result<()> {
+        let mut bools = BNInf::new();
+        bools.mass_set_strict(3, "b{n}", "true,false,true")?;
+        assert_eq!(bools.mass_get(&["b0", "b1", "b2"])?, vec![true, false, true]);
+
+        assert!(matches!(
+            bools.mass_set_strict(3, "c{n}", "true,false"),
+            Err(BBoolError::InvalidPattern(_))
+        ));
+        assert!(matches!(
+            bools.mass_set_strict(2, "c{n}", "true,false,true"),
+            Err(BBoolError::InvalidPattern(_))
+        ));
+        assert!(!bools.exists("c0"));
+
+        bools.mass_set_strict(5, "r{n}", "false{r}")?;
+        assert!(!bools.get("r4")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_name_at_pos() -> Result<()> {
+        let mut bools = BNInf::new();
+        bools.add("a", true)?;
+        bools.add("b", false)?;
+
+        assert_eq!(bools.name_at_pos(0), Some("a"));
+        assert_eq!(bools.name_at_pos(1), Some("b"));
+        assert_eq!(bools.name_at_pos(2), None);
+        assert_eq!(bools.name_at_pos(10_000), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_true_false_sets() -> Result<()> {
+        let mut bools = BNInf::new();
+        bools.mass_set(20, "f{n}", "true,false{r}")?;
+
+        let true_set = bools.true_set();
+        let false_set = bools.false_set();
+        assert_eq!(true_set.len(), 10);
+        assert_eq!(false_set.len(), 10);
+        for i in 0..20 {
+            let name = format!("f{i}");
+            assert_eq!(true_set.contains(&name), i % 2 == 0);
+            assert_eq!(false_set.contains(&name), i % 2 == 1);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_partition() -> Result<()> {
+        let mut bools = BNInf::new();
+        bools.mass_set(12, "f{n}", "true,false,false{r}")?;
+        let (on, off) = bools.partition()?;
+        assert_eq!(on, vec!["f0", "f3", "f6", "f9"]);
+        assert_eq!(off.len(), 8);
+        assert!(off.windows(2).all(|w| w[0] < w[1]));
+        for name in bools.all_names_cl().keys() {
+            assert_ne!(on.contains(name), off.contains(name));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_bits_sparse() -> Result<()> {
+        // Names far past the end of a two-byte store: only the named positions are read, so
+        // they are reported as unallocated `false`s. Scanning the store could never reach them.
+        let far = usize::MAX - 1;
+        let mut bools = BNInf::from_vec(vec![0; 2]);
+        bools.add("low", true)?;
+        bools.all_names_mut().insert("mid".into(), 1 << 40);
+        bools.all_names_mut().insert("far".into(), far);
+        // Unnamed bits are never reported
+        bools.bools.set_at_pos(9, true)?;
+
+        assert_eq!(
+            bools.named_bits()?,
+            vec![
+                ("low".to_string(), true),
+                ("mid".to_string(), false),
+                ("far".to_string(), false),
+            ]
+        );
+        // Reading didn't grow the store
+        assert_eq!(bools.bools.get_raw().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sorted_by() -> Result<()> {
+        let mut bools = BNInf::new();
+        bools.add("a", true)?;
+        bools.add("c", false)?;
+        bools.add("b", true)?;
+
+        let sorted = bools.sorted_by(|(a, va), (b, vb)| va.cmp(vb).then_with(|| a.cmp(b)))?;
+        let names: Vec<_> = (0..3).filter_map(|pos| sorted.name_at_pos(pos)).collect();
+        assert_eq!(names, vec!["c", "a", "b"]);
+        assert_eq!(sorted.get_raw(), &vec![0b110]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_name_keys_unchanged_behavior() -> Result<()> {
+        let mut bools = BNInf::new();
+        for i in 0..500 {
+            bools.add(&format!("service.region{}.node{i}", i % 7), i % 3 == 0)?;
+        }
+        bools.set("service.region0.node0", false)?;
+        bools.delete("service.region1.node1")?;
+
+        assert_eq!(bools.all_names().len(), 499);
+        assert!(!bools.get("service.region0.node0")?);
+        assert!(bools.get("service.region3.node3")?);
+        assert!(!bools.exists("service.region1.node1"));
+        assert_eq!(
+            bools.complete("service.region2.node1", 3),
+            vec!["service.region2.node100", "service.region2.node107", "service.region2.node114"]
+        );
+        assert_eq!(bools.name_at_pos(9), Some("service.region2.node9"));
+        assert_eq!(bools.all_names_cl()["service.region4.node4"], 4);
+        let (on, off) = bools.partition()?;
+        assert_eq!(on.len() + off.len(), 499);
+
+        // Footprint: count the distinct name allocations held by the mapping and the index
+        let allocations: HashSet<*const u8> = bools
+            .all_names()
+            .keys()
+            .chain(bools.index.iter())
+            .map(|name| name.as_ptr())
+            .collect();
+        if cfg!(feature = "interned_names") {
+            // The mapping and the index share one allocation per name
+            assert_eq!(allocations.len(), 499);
+        } else {
+            assert_eq!(allocations.len(), 2 * 499);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sorted_stable_by() -> Result<()> {
+        let mut bools = BNInf::new();
+        for name in ["db.write", "cache.hit", "db.read", "cache.miss", "api.v2", "db.admin"] {
+            bools.add(name, name.starts_with("db"))?;
+        }
+        let group = |name: &str| name.split('.').next().unwrap_or_default().to_string();
+        let sorted = bools.sorted_stable_by(|(a, _), (b, _)| group(a).cmp(&group(b)))?;
+        let order: Vec<_> = sorted.named_bits()?.into_iter().map(|(name, _)| name).collect();
+        assert_eq!(
+            order,
+            ["api.v2", "cache.hit", "cache.miss", "db.write", "db.read", "db.admin"]
+        );
+        assert!(sorted.get("db.admin")?);
+        assert!(!sorted.get("api.v2")?);
+
+        assert_eq!(bools.sorted_stable()?.name_at_pos(0), Some("api.v2"));
+        assert!(BNInf::new().sorted_stable()?.all_names().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_table() -> Result<()> {
+        let mut bools = BNInf::new();
+        assert_eq!(bools.to_table(), "NAME  VALUE");
+        bools.add("b", false)?;
+        bools.add("a_longer_name", true)?;
+        assert_eq!(
+            bools.to_table(),
+            "NAME           VALUE\na_longer_name  true\nb              false"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_sorted() -> Result<()> {
+        let mut bools = BNInf::new();
+        for name in ["delta", "alpha", "charlie", "bravo"] {
+            bools.add(name, name.len() % 2 == 1)?;
+        }
+        bools.delete("charlie")?;
+        assert_eq!(
+            bools.all_sorted()?,
+            vec![
+                ("alpha".to_string(), true),
+                ("bravo".to_string(), true),
+                ("delta".to_string(), true),
+            ]
+        );
+
+        // Names inserted directly into the mapping are included too
+        bools.all_names_mut().insert("able".into(), 1);
+        assert_eq!(bools.all_sorted()?[..2], [("able".to_string(), true), ("alpha".to_string(), true)]);
+        assert_eq!(bools.all_sorted()?.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_name_list() -> Result<()> {
+        let mut bools = BNInf::new();
+        bools.mass_set(12, "flag_{n}", "false{r}")?;
+        let names = bools.name_list();
+        let mut expected: Vec<String> = (0..12).map(|n| format!("flag_{n}")).collect();
+        expected.sort();
+        assert_eq!(names, expected);
+        for name in &names {
+            let (key, _) = bools.all_names().get_key_value(*name).unwrap();
+            let key: &str = key.as_ref();
+            assert!(std::ptr::eq(key, *name));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_display_sorted() -> Result<()> {
+        let mut bool = BNInf::new();
+        bool.set("b", true)?;
+        bool.set("c", false)?;
+        bool.set("a", true)?;
+
+        assert_eq!(format!("{bool}"), "a: true\nb: true\nc: false");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod inf_bools_tests {
+    use crate::error::BBoolError;
+    use crate::inf_bbool::{BInf, BitOrder, SetPolicy};
+    use anyhow::Result;
+
+    #[test]
+    fn test_remove_range() -> Result<()> {
+        // Bits 0..16: 1010 1010 1111 0000 (least significant first)
+        let mut bools = BInf::new();
+        for (i, value) in [1, 0, 1, 0, 1, 0, 1, 0, 1, 1, 1, 1, 0, 0, 0, 0].iter().enumerate() {
+            bools.set_at_pos(i, *value == 1)?;
+        }
+
+        bools.remove_range(2, 10)?;
+        assert_eq!(
+            bools.all()?,
+            vec![true, false, true, true, false, false, false, false]
+        );
+        assert_eq!(bools.get_raw().len(), 1);
+
+        assert!(bools.remove_range(4, 2).is_err());
+        assert!(bools.remove_range(0, 9).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_at() -> Result<()> {
+        // Alternating pattern: true, false, true, false, ...
+        let mut bools = BInf::from_vec(vec![0b0101_0101]);
+
+        bools.insert_at(3, true)?;
+        assert_eq!(
+            bools.range(0, 9)?,
+            vec![true, false, true, true, false, true, false, true, false]
+        );
+        assert_eq!(bools.get_raw().len(), 2);
+
+        // Inserting past the end pads with false
+        bools.insert_at(20, true)?;
+        assert!(bools.get_at_pos(20)?);
+        assert!(!bools.get_at_pos(19)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_bits_guard() -> Result<()> {
+        let mut bools = BInf::with_max_bits(16);
+        bools.set_at_pos(15, true)?;
+        assert_eq!(bools.get_raw().len(), 2);
+
+        assert!(matches!(
+            bools.set_at_pos(usize::MAX - 1, true),
+            Err(BBoolError::ExceedsConfiguredCap(_, 16))
+        ));
+        bools.shp(16)?;
+        assert!(matches!(
+            bools.set(true),
+            Err(BBoolError::ExceedsConfiguredCap(16, 16))
+        ));
+        // Rejected writes must not have grown the store
+        assert_eq!(bools.get_raw().len(), 2);
+
+        // The default stays unbounded
+        let mut unbounded = BInf::new();
+        unbounded.set_at_pos(1024, true)?;
+        assert!(unbounded.get_at_pos(1024)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_runs() {
+        assert_eq!(BInf::from_vec(vec![0b0101_0101]).count_runs(), 8);
+        assert_eq!(BInf::from_vec(vec![0xAA, 0xAA]).count_runs(), 16);
+
+        assert_eq!(BInf::from_vec(vec![0xFF, 0xFF]).count_runs(), 1);
+        assert_eq!(BInf::from_vec(vec![0, 0, 0]).count_runs(), 1);
+
+        // Bits from position 0: 0011 1111 1000 0000
+        assert_eq!(BInf::from_vec(vec![0b1111_1100, 0b0000_0001]).count_runs(), 3);
+
+        assert_eq!(BInf::new().count_runs(), 0);
+    }
+
+    #[test]
+    fn test_changes_since() {
+        let before = BInf::from_vec(vec![0b1111_0000, 0b0000_1111]);
+        let after = BInf::from_vec(vec![0b1100_0011, 0b0000_1111]);
+        assert_eq!(after.changes_since(&before), (2, 2));
+        assert_eq!(before.changes_since(&after), (2, 2));
+        assert_eq!(after.changes_since(&after), (0, 0));
+
+        // The shorter side is zero-extended
+        let grown = BInf::from_vec(vec![0b1111_0000, 0b0000_1111, 0b1010_0000]);
+        assert_eq!(grown.changes_since(&before), (2, 0));
+        assert_eq!(BInf::new().changes_since(&grown), (0, 10));
+    }
+
+    #[test]
+    fn test_far_write_and_reserve() -> Result<()> {
+        let mut bools = BInf::new();
+        bools.set_at_pos(100_000, true)?;
+        assert_eq!(bools.get_raw().len(), 100_001_usize.div_ceil(8));
+        assert!(bools.get_at_pos(100_000)?);
+        assert_eq!(bools.get_raw().iter().map(|b| b.count_ones()).sum::<u32>(), 1);
+
+        let mut reserved = BInf::new();
+        reserved.reserve_bits(17);
+        assert_eq!(reserved.get_raw(), &vec![0, 0, 0]);
+        reserved.reserve_bits(8);
+        assert_eq!(reserved.get_raw().len(), 3);
+
+        let mut capped = BInf::with_max_bits(16);
+        capped.reserve_bits(1_000_000);
+        assert_eq!(capped.get_raw().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_matches_per_bit_reads() -> Result<()> {
+        // Deterministic xorshift so the store is pseudo-random but reproducible
+        let mut state = 0x2545_F491_4F6C_DD1D_u64;
+        let store: Vec<u8> = (0..4096)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state.to_le_bytes()[0]
+            })
+            .collect();
+        let bools = BInf::from_vec(store.clone());
+
+        let reference: Vec<bool> = (0..store.len() * 8)
+            .map(|i| store[i / 8] & (1 << (i % 8)) != 0)
+            .collect();
+        assert_eq!(bools.all()?, reference);
+        for (i, &value) in reference.iter().enumerate().step_by(97) {
+            assert_eq!(bools.get_at_pos(i)?, value);
+        }
+        assert!(BInf::new().all()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_all_false_and_true() {
+        assert!(BInf::from_vec(vec![0; 4]).is_all_false());
+        assert!(!BInf::from_vec(vec![0; 4]).is_all_true());
+        assert!(BInf::from_vec(vec![0xFF; 4]).is_all_true());
+        assert!(!BInf::from_vec(vec![0xFF; 4]).is_all_false());
+
+        let mixed = BInf::from_vec(vec![0xFF, 0x10]);
+        assert!(!mixed.is_all_false() && !mixed.is_all_true());
+        assert!(BInf::new().is_all_false() && BInf::new().is_all_true());
+    }
+
+    #[test]
+    fn test_find_window() {
+        // Bits from position 0: 1101 0000 0000 1110
+        let bools = BInf::from_vec(vec![0b0000_1011, 0b0111_0000]);
+        assert_eq!(bools.find_window(&[true, true, true]), Some(12));
+        assert_eq!(bools.find_window(&[true, true]), Some(0));
+        assert_eq!(bools.find_window(&[false, true]), Some(2));
+        assert_eq!(bools.find_window(&[true; 4]), None);
+        assert_eq!(bools.find_window(&[false; 17]), None);
+        assert_eq!(bools.find_window(&[]), Some(0));
+        assert_eq!(BInf::new().find_window(&[false]), None);
+    }
+
+    #[test]
+    fn test_map_bits() {
+        let original = vec![0b1011_0110, 0b1111_1111, 0b0100_1001];
+        let mut bools = BInf::from_vec(original.clone());
+        bools.map_bits(|pos, value| pos % 2 == 1 && value);
+
+        for pos in 0..24 {
+            let before = (original[pos / 8] >> (pos % 8)) & 1 == 1;
+            let after = bools.get_at_pos(pos).unwrap();
+            if pos % 2 == 0 {
+                assert!(!after);
+            } else {
+                assert_eq!(after, before);
+            }
+        }
+        assert_eq!(bools.get_raw().len(), 3);
+    }
+
+    #[test]
+    fn test_rank_select() {
+        let bools = BInf::from_vec(vec![0b1001_0110, 0b0000_0000, 0b1111_0001, 0b1000_0000]);
+        let bits = bools.all().unwrap();
+        let set_positions: Vec<usize> = (0..bits.len()).filter(|&i| bits[i]).collect();
+
+        for pos in 0..=bits.len() + 8 {
+            let expected = bits.iter().take(pos).filter(|&&b| b).count();
+            assert_eq!(bools.rank(pos), expected, "rank({pos})");
+        }
+        for (n, &pos) in set_positions.iter().enumerate() {
+            assert_eq!(bools.select(n), Some(pos));
+            assert_eq!(bools.rank(pos), n);
+        }
+        assert_eq!(bools.select(set_positions.len()), None);
+        assert_eq!(BInf::new().rank(10), 0);
+        assert_eq!(BInf::new().select(0), None);
+    }
+
+    #[test]
+    fn test_try_into_iter() -> Result<()> {
+        let bools = BInf::from_vec(vec![0b1001_0110, 0xFF, 0]);
+        let expected = bools.all()?;
+        assert_eq!(bools.clone().try_into_iter()?.collect::<Vec<_>>(), expected);
+        assert_eq!(bools.into_iter().collect::<Vec<_>>(), expected);
+        assert_eq!(BInf::new().try_into_iter()?.count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_grid() -> Result<()> {
+        // A 4x4 ring, rows stored from position 0 upward
+        let bools = BInf::from_vec(vec![0b1001_1111, 0b1111_1001]);
+        assert_eq!(bools.to_grid(4)?, "####\n#..#\n#..#\n####");
+        assert_eq!(bools.to_grid(6)?, "#####.\n.##..#\n####");
+        assert_eq!(BInf::new().to_grid(3)?, "");
+        assert!(bools.to_grid(0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_set_positions() -> Result<()> {
+        let bools = BInf::from_set_positions([0, 7, 64])?;
+        assert_eq!(bools.get_raw().len(), 9);
+        assert_eq!(bools.logical_len(), 65);
+        let bits = bools.all()?;
+        assert_eq!(bits.len(), 65);
+        for (pos, &bit) in bits.iter().enumerate() {
+            assert_eq!(bit, [0, 7, 64].contains(&pos), "position {pos}");
+        }
+        assert_eq!(bools.to_bit_string().len(), 65);
+
+        assert_eq!(BInf::from_set_positions([3, 3])?.get_raw(), &vec![0b1000]);
+        let empty = BInf::from_set_positions(std::iter::empty())?;
+        assert!(empty.get_raw().is_empty());
+        assert_eq!(empty.logical_len(), 0);
+
+        // Too large to allocate
+        assert!(matches!(
+            BInf::from_set_positions([1, usize::MAX]),
+            Err(BBoolError::InvalidPosInf(usize::MAX))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invert_range() -> Result<()> {
+        let original = vec![0b1010_0110, 0b0011_1100, 0b1111_0000];
+        let mut bools = BInf::from_vec(original.clone());
+
+        bools.invert_range(5, 19)?;
+        for pos in 0..24 {
+            let before = (original[pos / 8] >> (pos % 8)) & 1 == 1;
+            assert_eq!(bools.get_at_pos(pos)?, before != (5..19).contains(&pos));
+        }
+        bools.invert_range(5, 19)?;
+        assert_eq!(bools.get_raw(), &original);
+
+        bools.invert_range(30, 34)?;
+        assert_eq!(bools.get_raw().len(), 5);
+        assert_eq!(bools.range(29, 35)?, vec![false, true, true, true, true, false]);
+
+        assert!(matches!(bools.invert_range(4, 3), Err(BBoolError::InvalidRange(4, 3))));
+        let mut capped = BInf::with_max_bits(8);
+        assert!(capped.invert_range(0, 9).is_err());
+        capped.invert_range(0, 8)?;
+        assert!(capped.is_all_true());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bytes_with_len() -> Result<()> {
+        let mut bools = BInf::from_bytes_with_len(vec![0b1111_0101, 0b1111_1110], 10)?;
+        assert_eq!(bools.logical_len(), 10);
+        assert_eq!(
+            bools.all()?,
+            vec![true, false, true, false, true, true, true, true, false, true]
+        );
+        // Padding past the logical length is cleared
+        assert_eq!(bools.get_raw(), &vec![0b1111_0101, 0b0000_0010]);
+        assert_eq!(bools.to_string(), "10101111 01");
+
+        bools.remove_range(0, 2)?;
+        assert_eq!(bools.all()?.len(), 8);
+        bools.insert_at(0, true)?;
+        assert_eq!(bools.all()?.len(), 9);
+        bools.set_at_pos(11, true)?;
+        assert_eq!(bools.all()?.len(), 12);
+
+        assert!(BInf::from_bytes_with_len(vec![0xFF, 0xFF], 10)?.is_all_true());
+        assert_eq!(BInf::from_bytes_with_len(vec![], 0)?.all()?, vec![]);
+        assert!(matches!(
+            BInf::from_bytes_with_len(vec![0xFF], 9),
+            Err(BBoolError::InvalidPosInf(9))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_checksum_round_trip() -> Result<()> {
+        let bools = BInf::from_vec(vec![0b1010_0101, 0, 0xFF, 42]);
+        let bytes = bools.write_with_checksum();
+        assert_eq!(bytes.len(), 8);
+
+        let read = BInf::read_with_checksum(&bytes)?;
+        assert_eq!(read.get_raw(), bools.get_raw());
+        assert_eq!(read.checksum(), bools.checksum());
+
+        let empty = BInf::read_with_checksum(&BInf::new().write_with_checksum())?;
+        assert!(empty.get_raw().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_checksum_detects_corruption() {
+        let mut bytes = BInf::from_vec(vec![0b1010_0101, 0, 0xFF, 42]).write_with_checksum();
+        bytes[1] ^= 0xFF;
+        assert!(matches!(
+            BInf::read_with_checksum(&bytes),
+            Err(BBoolError::Other(_))
+        ));
+        assert!(matches!(
+            BInf::read_with_checksum(&[1, 2, 3]),
+            Err(BBoolError::Other(_))
+        ));
+    }
+
+    #[test]
+    fn test_is_empty_vs_all_false() {
+        let empty = BInf::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.logical_len(), 0);
+        assert!(empty.is_all_false());
+
+        let zeroed = BInf::from_vec(vec![0]);
+        assert!(!zeroed.is_empty());
+        assert_eq!(zeroed.logical_len(), 8);
+        assert!(zeroed.is_all_false());
+
+        let mut cleared = zeroed;
+        cleared.clear();
+        assert!(cleared.is_empty());
+    }
+
+    #[test]
+    fn test_builder_matches_imperative() -> Result<()> {
+        let built = BInf::builder()
+            .set_range(4, 12)
+            .set(3)
+            .set(20)
+            .clear(5)
+            .clear(3)
+            .build();
+
+        let mut imperative = BInf::new();
+        for pos in 4..12 {
+            imperative.set_at_pos(pos, true)?;
+        }
+        imperative.set_at_pos(3, true)?;
+        imperative.set_at_pos(20, true)?;
+        imperative.set_at_pos(5, false)?;
+        imperative.set_at_pos(3, false)?;
+
+        assert_eq!(built.get_raw(), imperative.get_raw());
+        assert_eq!(built.get_raw(), &vec![0b1101_0000, 0b0000_1111, 0b0001_0000]);
+        assert!(BInf::builder().set_range(5, 2).build().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fingerprint_incremental_matches_recompute() -> Result<()> {
+        let mut bools = BInf::from_vec(vec![0b1010_0101, 0, 0xFF]);
+        bools.track_fingerprint();
+        let initial = bools.fingerprint();
+
+        bools.set_at_pos(1, true)?;
+        bools.set_at_pos(16, false)?;
+        bools.set_at_pos(40, true)?;
+        bools.shp(9)?;
+        bools.set(true)?;
+        let recomputed = BInf::from_vec(bools.get_raw().clone()).fingerprint();
+        assert_eq!(bools.fingerprint(), recomputed);
+        assert_ne!(bools.fingerprint(), initial);
+
+        bools.invert_range(3, 20)?;
+        bools.remove_range(0, 5)?;
+        bools.insert_at(2, true)?;
+        bools.map_bits(|pos, value| value ^ (pos % 3 == 0));
+        let recomputed = BInf::from_vec(bools.get_raw().clone()).fingerprint();
+        assert_eq!(bools.fingerprint(), recomputed);
+
+        // Undoing a change restores the fingerprint
+        let before = bools.fingerprint();
+        bools.set_at_pos(7, !bools.get_at_pos(7)?)?;
+        bools.set_at_pos(7, !bools.get_at_pos(7)?)?;
+        assert_eq!(bools.fingerprint(), before);
+
+        bools.clear();
+        assert_eq!(bools.fingerprint(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_patch_round_trip() -> Result<()> {
+        let source = BInf::from_vec(vec![0b1010_0101, 0xFF, 0, 0b0100_0000]);
+        let mut target = BInf::from_vec(vec![0b1010_0100, 0xFF, 0b0001_0000, 0b0100_0000]);
+
+        let patch = target.diff_patch(&source);
+        assert_eq!(patch, vec![(0, true), (20, false)]);
+        target.apply_patch(&patch)?;
+        assert_eq!(target.get_raw(), source.get_raw());
+        assert!(target.diff_patch(&source).is_empty());
+
+        // A longer source grows the target
+        let longer = BInf::from_vec(vec![0, 0, 0, 0, 0b1000_0000]);
+        let mut shorter = BInf::from_vec(vec![1]);
+        shorter.apply_patch(&shorter.diff_patch(&longer))?;
+        assert_eq!(shorter.all()?, longer.all()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlaps() -> Result<()> {
+        let mut a = BInf::from_vec(vec![0b0101_0101, 0, 0b0000_0001]);
+        let mut b = BInf::from_vec(vec![0b1010_1010, 0xFF]);
+        assert!(!a.overlaps(&b));
+        assert!(!b.overlaps(&a));
+        assert!(!a.overlaps(&BInf::new()));
+
+        a.set_at_pos(1000, true)?;
+        b.set_at_pos(1000, true)?;
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_unaligned() -> Result<()> {
+        let first = vec![true, false, true, true, false, false, true, false, true, true];
+        let second = vec![true, true, false, true, false, false, false, true, true, false, true];
+        let mut bools = BInf::from_bytes_with_len(vec![0b0100_1101, 0b11], 10)?;
+        let other = BInf::from_bytes_with_len(vec![0b1000_1011, 0b101], 11)?;
+        assert_eq!(bools.all()?, first);
+        assert_eq!(other.all()?, second);
+
+        bools.append(&other)?;
+        assert_eq!(bools.logical_len(), 21);
+        assert_eq!(bools.all()?, [first.clone(), second].concat());
+
+        // Whole bytes on both sides stay byte-aligned
+        let mut whole = BInf::from_vec(vec![0xAA]);
+        whole.append(&BInf::from_vec(vec![0x55]))?;
+        assert_eq!(whole.get_raw(), &vec![0xAA, 0x55]);
+
+        let mut empty = BInf::new();
+        empty.append(&BInf::from_bytes_with_len(vec![0b0100_1101, 0b11], 10)?)?;
+        assert_eq!(empty.all()?, first);
+
+        let mut capped = BInf::with_max_bits(16);
+        capped.append(&BInf::from_vec(vec![0, 0]))?;
+        assert!(capped.append(&BInf::from_bytes_with_len(vec![1], 1)?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_policy_growth_paths() -> Result<()> {
+        let far = 1 << 40;
+        let tail = BInf::from_vec(vec![0xFF]);
+
+        let mut error = BInf::from_vec(vec![0b0000_0001]);
+        error.set_policy(SetPolicy::Error);
+        assert!(matches!(
+            error.insert_at(far, true),
+            Err(BBoolError::InvalidPosInf(pos)) if pos == far
+        ));
+        // Even an in-range insert needs a ninth bit here
+        assert!(matches!(error.insert_at(0, false), Err(BBoolError::InvalidPosInf(8))));
+        assert!(matches!(error.invert_range(4, 12), Err(BBoolError::InvalidPosInf(11))));
+        assert!(matches!(error.append(&tail), Err(BBoolError::InvalidPosInf(15))));
+        assert_eq!(error.get_raw(), &vec![0b0000_0001]);
+        // Staying within the store is still allowed
+        error.invert_range(0, 8)?;
+        assert_eq!(error.get_raw(), &vec![0b1111_1110]);
+
+        let mut ignore = BInf::from_vec(vec![0b0000_0001]);
+        ignore.set_policy(SetPolicy::Ignore);
+        ignore.insert_at(far, true)?;
+        ignore.insert_at(0, false)?;
+        ignore.invert_range(4, 12)?;
+        ignore.append(&tail)?;
+        assert_eq!(ignore.get_raw(), &vec![0b0000_0001]);
+
+        // Spare bytes past the logical length can be used
+        let mut spare = BInf::from_bytes_with_len(vec![0b0000_0001, 0], 8)?;
+        spare.set_policy(SetPolicy::Error);
+        spare.append(&tail)?;
+        assert_eq!(spare.get_raw(), &vec![0b0000_0001, 0xFF]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_policy() -> Result<()> {
+        let far = 1 << 40;
+
+        let mut grow = BInf::from_vec(vec![0]);
+        assert_eq!(grow.policy(), SetPolicy::Grow);
+        grow.set_at_pos(100, true)?;
+        assert_eq!(grow.get_raw().len(), 13);
+
+        let mut error = BInf::from_vec(vec![0]);
+        error.set_policy(SetPolicy::Error);
+        assert!(matches!(
+            error.set_at_pos(far, true),
+            Err(BBoolError::InvalidPosInf(pos)) if pos == far
+        ));
+        error.shp(far)?;
+        assert!(matches!(error.set(true), Err(BBoolError::InvalidHeadPosInf(_))));
+        error.set_at_pos(7, true)?;
+        assert_eq!(error.get_raw(), &vec![0b1000_0000]);
+
+        let mut ignore = BInf::from_vec(vec![0]);
+        ignore.set_policy(SetPolicy::Ignore);
+        ignore.set_at_pos(far, true)?;
+        assert!(!ignore.get_at_pos(far)?);
+        assert_eq!(ignore.get_raw(), &vec![0]);
+
+        // Reserving up front still allows writes under a non-growing policy
+        ignore.reserve_bits(16);
+        ignore.set_at_pos(15, true)?;
+        assert!(ignore.get_at_pos(15)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_write_bits() -> Result<()> {
+        let mut bools = BInf::new();
+        bools.shp(3)?;
+        bools.write_bits(0b101, 3)?;
+        bools.write_bits(0xABCD, 16)?;
+        bools.write_bits(u64::MAX, 64)?;
+        bools.write_bits(0, 0)?;
+        assert_eq!(*bools.ghp(), 3 + 3 + 16 + 64);
+
+        bools.shp(3)?;
+        assert_eq!(bools.peek_bits(3)?, 0b101);
+        assert_eq!(bools.read_bits(3)?, 0b101);
+        assert_eq!(bools.read_bits(16)?, 0xABCD);
+        assert_eq!(bools.read_bits(64)?, u64::MAX);
+        // Past the end reads as false
+        assert_eq!(bools.read_bits(8)?, 0);
+
+        // Only the low n bits are written
+        let mut bools = BInf::new();
+        bools.write_bits(0xFF, 4)?;
+        assert_eq!(bools.get_raw(), &vec![0x0F]);
+
+        assert!(bools.read_bits(65).is_err());
+        assert!(bools.write_bits(0, 65).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_clone_head_variants() -> Result<()> {
+        let mut bools = BInf::with_max_bits(16);
+        for pos in 0..9 {
+            bools.set_at_pos(pos, true)?;
+        }
+        *bools.ghp_mut() = 8;
+
+        let mut at_start = bools.clone_at_start();
+        assert_eq!(*at_start.ghp(), 0);
+        let preserved = bools.clone_preserving_head();
+        assert_eq!(*preserved.ghp(), 8);
+        assert!(preserved.get()?);
+        assert_eq!(preserved.max_bits(), Some(16));
+
+        at_start.set(false)?;
+        assert_eq!(at_start.get_raw(), &vec![0xFE, 0x01]);
+        assert_eq!(bools.get_raw(), &vec![0xFF, 0x01]);
+        assert_eq!(*bools.ghp(), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_prefix_or() -> Result<()> {
+        let bools = BInf::from_bytes_with_len(vec![0b0000_0100, 0], 12)?;
+        let sticky = bools.prefix_or();
+        assert_eq!(sticky.get_raw(), &vec![0b1111_1100, 0b0000_1111]);
+        assert_eq!(sticky.logical_len(), 12);
+        assert_eq!(bools.get_raw(), &vec![0b0000_0100, 0]);
+
+        assert_eq!(BInf::from_vec(vec![0, 0]).prefix_or().get_raw(), &vec![0, 0]);
+        assert!(BInf::new().prefix_or().get_raw().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_inc_near_max_head() {
+        let mut bools = BInf::new();
+        *bools.ghp_mut() = usize::MAX - 1;
+        assert!(matches!(bools.inc(), Err(BBoolError::InvalidHeadPosInf(p)) if p == usize::MAX - 1));
+        assert_eq!(*bools.ghp(), usize::MAX - 1);
+
+        *bools.ghp_mut() = usize::MAX;
+        assert!(matches!(bools.inc(), Err(BBoolError::InvalidHeadPosInf(usize::MAX))));
+        assert_eq!(*bools.ghp(), usize::MAX);
+
+        *bools.ghp_mut() = usize::MAX - 2;
+        assert!(bools.inc().is_ok());
+        assert_eq!(*bools.ghp(), usize::MAX - 1);
+    }
+
+    #[test]
+    fn test_from_range_spec() -> Result<()> {
+        let bools = BInf::from_range_spec("0-3,7,10-12")?;
+        let set: Vec<usize> = (0..16).filter(|&pos| bools.get_at_pos(pos).unwrap_or(false)).collect();
+        assert_eq!(set, vec![0, 1, 2, 3, 7, 10, 11, 12]);
+
+        // Whitespace, single-position ranges, overlaps and any order are all accepted
+        let loose = BInf::from_range_spec(" 12 , 10-11,5-5, 3 - 4,4 ")?;
+        assert_eq!(loose.get_raw(), &vec![0b0011_1000, 0b0001_1100]);
+        assert!(BInf::from_range_spec("")?.get_raw().is_empty());
+
+        assert!(matches!(BInf::from_range_spec("5-2"), Err(BBoolError::InvalidRange(5, 2))));
+        assert!(matches!(BInf::from_range_spec("x"), Err(BBoolError::InvalidPattern(_))));
+        assert!(matches!(BInf::from_range_spec("1,,2"), Err(BBoolError::InvalidPattern(_))));
+        assert!(matches!(BInf::from_range_spec("1-2-3"), Err(BBoolError::InvalidPattern(_))));
+        assert!(matches!(BInf::from_range_spec("-4"), Err(BBoolError::InvalidPattern(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_utilization() -> Result<()> {
+        let mut bools = BInf::from_vec(vec![0; 4]);
+        assert!(bools.utilization().abs() < f64::EPSILON);
+        bools.set_at_pos(15, true)?;
+        assert!((bools.utilization() - 0.5).abs() < f64::EPSILON);
+        bools.set_at_pos(31, true)?;
+        assert!((bools.utilization() - 1.0).abs() < f64::EPSILON);
+        assert!(BInf::new().utilization().abs() < f64::EPSILON);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bit_order() -> Result<()> {
+        let mut lsb = BInf::new();
+        let mut msb = BInf::with_bit_order(BitOrder::Msb0);
+        assert_eq!(lsb.bit_order(), BitOrder::Lsb0);
+        assert_eq!(msb.bit_order(), BitOrder::Msb0);
+        for pos in [0, 3, 9] {
+            lsb.set_at_pos(pos, true)?;
+            msb.set_at_pos(pos, true)?;
+        }
+
+        assert_eq!(lsb.get_raw(), &vec![0b0000_1001, 0b0000_0010]);
+        assert_eq!(msb.get_raw(), &vec![0b1001_0000, 0b0100_0000]);
+        for pos in 0..16 {
+            assert_eq!(lsb.get_at_pos(pos)?, msb.get_at_pos(pos)?);
+        }
+
+        msb.shp(3)?;
+        msb.set(false)?;
+        assert!(!msb.get()?);
+        assert_eq!(msb.get_raw()[0], 0b1000_0000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rotate() -> Result<()> {
+        // 10 logical bits: positions 0, 1 and 9 set
+        let original = BInf::from_bytes_with_len(vec![0b0000_0011, 0b10], 10)?;
+        let ones = |bools: &BInf| -> Result<Vec<usize>> {
+            Ok((0..10).filter(|&pos| bools.get_at_pos(pos).unwrap_or(false)).collect())
+        };
+
+        let mut left = original.clone();
+        left.rotate_left(3);
+        assert_eq!(ones(&left)?, vec![2, 3, 4]);
+        assert_eq!(left.logical_len(), 10);
+
+        let mut right = original.clone();
+        right.rotate_right(3);
+        assert_eq!(ones(&right)?, vec![6, 7, 8]);
+
+        // Amounts past the length wrap, and a full turn is a no-op
+        let mut wrapped = original.clone();
+        wrapped.rotate_left(23);
+        assert_eq!(ones(&wrapped)?, vec![2, 3, 4]);
+        wrapped.rotate_right(13);
+        assert_eq!(wrapped.get_raw(), original.get_raw());
+        wrapped.rotate_left(10);
+        assert_eq!(wrapped.get_raw(), original.get_raw());
+
+        // Bits beyond the logical length in the last byte stay clear
+        let mut top = BInf::from_bytes_with_len(vec![0, 0b10], 10)?;
+        top.rotate_left(1);
+        assert_eq!(top.get_raw(), &vec![0b1, 0]);
+
+        let mut empty = BInf::new();
+        empty.rotate_left(5);
+        empty.rotate_right(5);
+        assert!(empty.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bit_string_round_trip() -> Result<()> {
+        // 13 logical bits, so the last byte is only partly used
+        let mask = BInf::from_bytes_with_len(vec![0b1010_0110, 0b1_0011], 13)?;
+        let text = mask.to_bit_string();
+        assert_eq!(text, "0110010111001");
+
+        let restored = BInf::from_bit_string(&text)?;
+        assert_eq!(restored.logical_len(), 13);
+        assert_eq!(restored.get_raw(), mask.get_raw());
+        assert_eq!(restored.to_bit_string(), text);
+
+        // Trailing zeros are kept as part of the length
+        assert_eq!(BInf::from_bit_string("100")?.to_bit_string(), "100");
+        assert!(BInf::from_bit_string("")?.is_empty());
+        assert!(matches!(
+            BInf::from_bit_string("10 1"),
+            Err(BBoolError::InvalidPattern(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bit_order_msb0_positional_ops() -> Result<()> {
+        // The reviewer's probe: a single bit at position 0
+        let mut msb = BInf::with_bit_order(BitOrder::Msb0);
+        msb.set_at_pos(0, true)?;
+        assert_eq!(msb.all()?, [vec![true], vec![false; 7]].concat());
+        assert_eq!(msb.to_string(), "10000000");
+        assert_eq!(msb.to_bit_string(), "10000000");
+        assert_eq!((msb.rank(1), msb.select(0)), (1, Some(0)));
+
+        // The same logical bits under both orders must behave identically
+        let pair = |positions: &[usize]| -> Result<(BInf, BInf)> {
+            let mut lsb = BInf::new();
+            let mut msb = BInf::with_bit_order(BitOrder::Msb0);
+            for &pos in positions {
+                lsb.set_at_pos(pos, true)?;
+                msb.set_at_pos(pos, true)?;
+            }
+            Ok((lsb, msb))
+        };
+        let (mut lsb, mut msb) = pair(&[0, 2, 3, 9, 14])?;
+        assert_eq!(msb.all()?, lsb.all()?);
+        assert_eq!(msb.to_string(), lsb.to_string());
+        for n in 0..6 {
+            assert_eq!(msb.select(n), lsb.select(n));
+        }
+        for pos in 0..17 {
+            assert_eq!(msb.rank(pos), lsb.rank(pos));
+        }
+
+        lsb.insert_at(1, false)?;
+        msb.insert_at(1, false)?;
+        assert!(msb.get_at_pos(0)?);
+        assert_eq!(msb.all()?, lsb.all()?);
+
+        lsb.remove_range(2, 5)?;
+        msb.remove_range(2, 5)?;
+        assert_eq!(msb.all()?, lsb.all()?);
+
+        lsb.invert_range(0, 11)?;
+        msb.invert_range(0, 11)?;
+        assert!(!msb.get_at_pos(0)?);
+        assert_eq!(msb.all()?, lsb.all()?);
+
+        // Appending across a partial byte, from either order
+        let (low_first_tail, high_first_tail) = pair(&[0, 1, 5])?;
+        let mut low_first = BInf::from_bit_string("101")?;
+        let mut high_first = BInf::with_bit_order(BitOrder::Msb0);
+        high_first.append(&low_first)?;
+        assert_eq!(high_first.all()?, low_first.all()?);
+        low_first.append(&high_first_tail)?;
+        high_first.append(&low_first_tail)?;
+        assert_eq!(high_first.logical_len(), 11);
+        assert_eq!(high_first.all()?, low_first.all()?);
+        assert_eq!(high_first.to_bit_string(), "10111000100");
+
+        assert_eq!(msb.to_string(), lsb.to_string());
+        assert_eq!(msb.count_runs(), lsb.count_runs());
+        assert_eq!(msb.prefix_or().all()?, lsb.prefix_or().all()?);
+        assert!((msb.utilization() - lsb.utilization()).abs() < f64::EPSILON);
+        assert_eq!(msb.diff_patch(&BInf::new()), lsb.diff_patch(&BInf::new()));
+        assert_eq!(msb.sorted()?.all()?, lsb.sorted()?.all()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_at_respects_max_bits() -> Result<()> {
+        let mut bools = BInf::with_max_bits(24);
+        bools.set_at_pos(14, true)?;
+        assert!(matches!(
+            bools.insert_at(1 << 40, true),
+            Err(BBoolError::ExceedsConfiguredCap(_, 24))
+        ));
+        assert!(matches!(
+            bools.insert_at(24, true),
+            Err(BBoolError::ExceedsConfiguredCap(24, 24))
+        ));
+        assert_eq!(bools.get_raw(), &vec![0, 0b0100_0000]);
+
+        // Inserting shifts bit 14 to 15 and grows the 16 logical bits to 17
+        bools.insert_at(0, true)?;
+        assert_eq!(bools.get_raw(), &vec![1, 0b1000_0000, 0]);
+        // The store now holds all 24 allowed bits, so another insert would need a 25th
+        assert!(matches!(
+            bools.insert_at(3, false),
+            Err(BBoolError::ExceedsConfiguredCap(24, 24))
+        ));
+        assert_eq!(bools.get_raw(), &vec![1, 0b1000_0000, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_spec_large_ranges() -> Result<()> {
+        // Ranges are filled in place, so a wide range doesn't build a list of positions
+        let wide = BInf::from_range_spec("3-1000000")?;
+        assert_eq!(wide.get_raw().len(), 125_001);
+        assert_eq!(wide.get_raw()[0], 0b1111_1000);
+        assert!(wide.get_raw()[1..125_000].iter().all(|&b| b == u8::MAX));
+        assert_eq!(wide.get_raw()[125_000], 0b1);
+        assert_eq!(wide.get_raw().iter().map(|b| b.count_ones()).sum::<u32>(), 1_000_000 - 2);
+
+        // Too large to allocate
+        assert!(matches!(
+            BInf::from_range_spec("0-18446744073709551615"),
+            Err(BBoolError::InvalidRange(0, usize::MAX))
+        ));
+
+        let mut capped = BInf::with_max_bits(1024);
+        assert!(matches!(
+            capped.set_range_spec("0-18446744073709551615"),
+            Err(BBoolError::ExceedsConfiguredCap(usize::MAX, 1024))
+        ));
+        // Nothing is set when any range is rejected
+        assert!(matches!(capped.set_range_spec("0-3, 1000-1024"), Err(BBoolError::ExceedsConfiguredCap(1024, 1024))));
+        assert!(capped.get_raw().is_empty());
+        capped.set_range_spec("0-3, 1000-1023")?;
+        assert_eq!(capped.get_raw().iter().map(|b| b.count_ones()).sum::<u32>(), 28);
+        assert!(capped.get_at_pos(1023)?);
+
+        let mut msb = BInf::with_bit_order(BitOrder::Msb0);
+        msb.set_range_spec("1-9")?;
+        assert_eq!(msb.get_raw(), &vec![0b0111_1111, 0b1100_0000]);
+        assert!((1..=9).all(|pos| msb.get_at_pos(pos).unwrap_or(false)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_display_bits() {
+        assert_eq!(BInf::from_vec(vec![5]).to_string(), "10100000");
+        assert_eq!(
+            BInf::from_vec(vec![5, 0b1000_0000]).to_string(),
+            "10100000 00000001"
+        );
+        assert_eq!(BInf::new().to_string(), "");
+    }
+}
+
+#[cfg(all(test, feature = "named_bools", feature = "inf_named_bools"))]
+mod bool_collection_tests {
+    use crate::bbool::B16;
+    use crate::inf_bbool::BInf;
+    use crate::inf_named_bools::BNInf;
+    use crate::named_bools::BN32;
+    use crate::traits::BoolCollection;
+    use std::fmt::Debug;
+
+    fn clear_and_reset<C: BoolCollection>(bools: &mut C)
+    where
+        C::Error: Debug,
+    {
+        bools.set_at_pos(1, true).unwrap();
+        bools.set_at_pos(5, true).unwrap();
+        bools.clear();
+        assert!(bools.all().unwrap().iter().all(|&b| !b));
+
+        bools.set_at_pos(5, true).unwrap();
+        assert!(bools.get_at_pos(5).unwrap());
+        assert!(!bools.get_at_pos(1).unwrap());
+        assert_eq!(bools.all().unwrap().iter().filter(|&&b| b).count(), 1);
+    }
+
+    #[test]
+    fn test_fixed() {
+        let mut bools = B16::new();
+        clear_and_reset(&mut bools);
+        assert!(BoolCollection::get_at_pos(&bools, 1000).is_err());
+    }
+
+    #[test]
+    fn test_inf() {
+        clear_and_reset(&mut BInf::new());
+    }
+
+    #[test]
+    fn test_named() {
+        let mut bools = BN32::new();
+        bools.add("a", true).unwrap();
+        clear_and_reset(&mut bools);
+        assert!(!bools.exists("a"));
+    }
+
+    #[test]
+    fn test_named_inf() {
+        let mut bools = BNInf::new();
+        bools.add("a", true).unwrap();
+        clear_and_reset(&mut bools);
+        assert!(!bools.exists("a"));
+    }
+}
+
+#[cfg(all(test, feature = "bloom"))]
+mod bloom_tests {
+    use crate::bloom::BloomFilter;
+
+    #[test]
+    fn test_no_false_negatives() {
+        let mut filter = BloomFilter::new(500, 0.01).unwrap();
+        for i in 0..500 {
+            filter.insert(format!("item_{i}").as_bytes());
+        }
+        for i in 0..500 {
+            assert!(filter.contains(format!("item_{i}").as_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_false_positive_rate() {
+        let mut filter = BloomFilter::new(1000, 0.01).unwrap();
+        for i in 0..1000 {
+            filter.insert(format!("present_{i}").as_bytes());
+        }
+
+        let false_positives = (0..10_000)
+            .filter(|i| filter.contains(format!("absent_{i}").as_bytes()))
+            .count();
+        // Allow some slack above the 1% target for a sample of this size
+        assert!(false_positives < 300, "false positives: {false_positives}");
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(BloomFilter::new(0, 0.01).is_err());
+        assert!(BloomFilter::new(10, 0.0).is_err());
+        assert!(BloomFilter::new(10, 1.0).is_err());
+    }
+}
+
+#[cfg(all(test, feature = "shared_flags"))]
+mod shared_flags_tests {
+    use crate::shared_flags::SharedFlags;
+    use std::thread;
+
+    #[test]
+    fn test_set_from_another_thread() {
+        let flags = SharedFlags::new();
+        let changes = flags.subscribe();
+
+        let writer = flags.clone();
+        thread::spawn(move || {
+            writer.set("dark_mode", true).unwrap();
+            writer.set("dark_mode", true).unwrap();
+            writer.set("beta", false).unwrap();
+        })
+        .join()
+        .unwrap();
+
+        let reader = flags.clone();
+        let (dark_mode, beta) = thread::spawn(move || {
+            (reader.get("dark_mode").unwrap(), reader.get("beta").unwrap())
+        })
+        .join()
+        .unwrap();
+        assert!(dark_mode);
+        assert!(!beta);
+
+        let received: Vec<_> = changes.try_iter().collect();
+        assert_eq!(
+            received,
+            vec![("dark_mode".to_string(), true), ("beta".to_string(), false)]
+        );
+        assert!(flags.get("missing").is_err());
+    }
+
+    #[test]
+    fn test_dropped_subscriber() {
+        let flags = SharedFlags::new();
+        drop(flags.subscribe());
+        let kept = flags.subscribe();
+        flags.set("a", true).unwrap();
+        flags.set("a", false).unwrap();
+        assert_eq!(kept.try_iter().count(), 2);
+    }
+}
+
+#[cfg(all(test, feature = "enum_flags"))]
+mod enum_flags_tests {
+    use crate::enum_flags::EnumFlags;
+    use anyhow::Result;
+
+    #[derive(Clone, Copy, Debug)]
+    enum Perm {
+        Read,
+        Write,
+        Exec = 7,
+        Admin = 127,
+        Invalid = 200,
+    }
+
+    impl From<Perm> for u8 {
+        fn from(p: Perm) -> Self {
+            p as Self
+        }
+    }
+
+    #[test]
+    fn test_round_trip_by_variant() -> Result<()> {
+        let mut flags = EnumFlags::new();
+        flags.set(Perm::Read, true)?;
+        flags.set(Perm::Admin, true)?;
+        flags.toggle(Perm::Exec)?;
+
+        assert!(flags.get(Perm::Read)?);
+        assert!(!flags.get(Perm::Write)?);
+        assert!(flags.get(Perm::Exec)?);
+        assert!(flags.get(Perm::Admin)?);
+        assert_eq!(*flags.bits().get_raw(), (1 << 127) | (1 << 7) | 1);
+
+        flags.toggle(Perm::Read)?;
+        assert!(!flags.get(Perm::Read)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_out_of_range_variant() {
+        let mut flags = EnumFlags::new();
+        assert!(flags.set(Perm::Invalid, true).is_err());
+        assert!(flags.get(Perm::Invalid).is_err());
     }
 }
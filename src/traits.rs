@@ -73,6 +73,52 @@ pub trait Nums: One + Zero {}
 /// A simple trait that, if implemented on any type, allows for it to be used entirely with `BetterBool` and `BetterBoolNamed.`
 pub trait BoolSupport: BitwiseOpsClone + BitwiseOpsCopy {}
 
+/// A common positional interface over the bool collections, for code that is generic over the storage.
+///
+/// Implemented for `BetterBool`, `BetterBoolInf`, `BetterBoolNamed` and `BetterBoolNamedInf`. The named
+/// types are accessed by the raw bit position backing each name.
+///
+/// # Examples
+/// ```
+/// use btypes::bbool::B8;
+/// use btypes::inf_bbool::BInf;
+/// use btypes::traits::BoolCollection;
+/// fn set_first<C: BoolCollection>(bools: &mut C) -> Result<(), C::Error> {
+///     bools.set_at_pos(0, true)
+/// }
+/// let mut fixed = B8::new();
+/// let mut inf = BInf::new();
+/// set_first(&mut fixed).unwrap();
+/// set_first(&mut inf).unwrap();
+/// assert!(BoolCollection::get_at_pos(&fixed, 0).unwrap());
+/// assert!(BoolCollection::get_at_pos(&inf, 0).unwrap());
+/// ```
+pub trait BoolCollection {
+    /// The error returned by fallible operations
+    type Error;
+
+    /// Gets the bool at the given position.
+    ///
+    /// # Errors
+    /// Returns an error if the position is invalid
+    fn get_at_pos(&self, pos: usize) -> Result<bool, Self::Error>;
+
+    /// Sets the bool at the given position.
+    ///
+    /// # Errors
+    /// Returns an error if the position is invalid
+    fn set_at_pos(&mut self, pos: usize, value: bool) -> Result<(), Self::Error>;
+
+    /// Returns every bool in position order.
+    ///
+    /// # Errors
+    /// Returns an error if reading any position fails
+    fn all(&self) -> Result<Vec<bool>, Self::Error>;
+
+    /// Clears the collection.
+    fn clear(&mut self);
+}
+
 impl BitwiseOpsCopy for u128 {}
 impl BitwiseOpsCopy for u64 {}
 impl BitwiseOpsCopy for u32 {}